@@ -19,7 +19,7 @@ Uses macroquad as a backend.
 # Examples
 
 ## Hello Window
-```
+```no_run
 use amberwindow::WindowManager;
 use macroquad::prelude::*;
 
@@ -36,7 +36,7 @@ async fn main() {
 
 ## Hello World
 
-```
+```no_run
 use amberwindow::{WindowManager, WindowWidget};
 use macroquad::prelude::*;
 
@@ -47,7 +47,7 @@ async fn main() {
 
     loop {
         if let Some(win) = windows.begin("") {
-            widget.Text(win, "Hello world");
+            widget.Text(win, "Hello world", None);
         }
 
         windows.update_windows();
@@ -60,12 +60,15 @@ async fn main() {
 
 For all of you who love dearimgui's styling, using the custom styling features in AmberWindow can let you "remake" dearimgui.
 
-```
+```no_run
+# use amberwindow::*;
+# use macroquad::prelude::*;
+# let mut windows = WindowManager::new();
 if let Some(win) = windows.begin("") {
     win.name("Debug");
     win.Text("Hello, world 123", None);
     win.Button("Save");
-    win.Slider(0., 100., None, vec2(win.rect.w - 20.0, 15f32));
+    win.Slider_float(0., 100., None, vec2(win.rect.w - 20.0, 15f32));
     win.Checkbox("Auto update", false);
     win.button_style(ButtonStyle{
         font: None,
@@ -90,7 +93,8 @@ if let Some(win) = windows.begin("") {
         title_color: WHITE,
         scale_color: Color::from_hex(0x294a7a),
         minimize_color: WHITE,
-        close_color: WHITE
+        close_color: WHITE,
+        ..Default::default()
     });
     for i in win.widgets.iter_mut() {
         if let Widget::Checkbox(i) = i {
@@ -9,11 +9,11 @@ async fn main() {
 
         if let Some(win) = windows.begin("int") {
             win.name("Integer Slider");
-            win.Slider_int(0, 100, None, vec2(150.,20.)).0;
+            win.Slider_int(0, 100, None, vec2(150.,20.));
         }
         if let Some(win) = windows.begin("float") {
             win.name("Float Slider");
-            win.Slider_float(0., 1., None, vec2(150.,20.)).0;
+            win.Slider_float(0., 1., None, vec2(150.,20.));
         }
         windows.end_windows();
         next_frame().await;
@@ -0,0 +1,233 @@
+use macroquad::prelude::*;
+
+/// Backend > InputSource (Mouse + keyboard state read by windows and widgets).
+///
+/// Mirrors the macroquad input functions, so any input system can drive the UI.
+pub trait InputSource {
+    fn mouse_position(&self) -> Vec2;
+    fn is_mouse_button_down(&self, button: MouseButton) -> bool;
+    fn is_mouse_button_pressed(&self, button: MouseButton) -> bool;
+    fn is_mouse_button_released(&self, button: MouseButton) -> bool;
+    fn mouse_wheel(&self) -> Vec2;
+    fn is_key_down(&self, key: KeyCode) -> bool;
+    fn is_key_pressed(&self, key: KeyCode) -> bool;
+    fn is_key_released(&self, key: KeyCode) -> bool;
+
+    /// Called by `WindowManager::update_windows()` once per frame, before any window reads input
+    /// (does nothing by default). Backends that have to take input out of a queue do it here.
+    fn begin_frame(&mut self) {}
+
+    /// Characters typed since last frame, in order (none by default).
+    fn chars_pressed(&self) -> Vec<char> {
+        vec![]
+    }
+
+    /// Seconds since the program started (for caret blinking and key repeat; `0.0` by default).
+    fn time(&self) -> f64 {
+        0.0
+    }
+
+    /// Text on the system clipboard (none by default).
+    fn clipboard_get(&self) -> Option<String> {
+        None
+    }
+
+    /// Put `text` on the system clipboard (does nothing by default).
+    fn clipboard_set(&self, _text: &str) {}
+}
+
+/// Backend > Painter (Draws and measures everything windows and widgets render).
+///
+/// Mirrors the macroquad shape/text/texture functions.
+/// Windows record into a `DrawList`, which the `WindowManager` submits to the painter.
+pub trait Painter {
+    fn screen_width(&self) -> f32;
+    fn screen_height(&self) -> f32;
+    fn measure_text(
+        &self,
+        text: &str,
+        font: Option<&Font>,
+        font_size: u16,
+        font_scale: f32,
+    ) -> TextDimensions;
+    fn draw_rectangle(&mut self, x: f32, y: f32, w: f32, h: f32, color: Color);
+    fn draw_rectangle_lines(&mut self, x: f32, y: f32, w: f32, h: f32, thickness: f32, color: Color);
+    fn draw_triangle(&mut self, v1: Vec2, v2: Vec2, v3: Vec2, color: Color);
+    fn draw_line(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, thickness: f32, color: Color);
+    /// Defaults to a polygon of triangles.
+    fn draw_circle(&mut self, x: f32, y: f32, radius: f32, color: Color) {
+        let center = vec2(x, y);
+        let points = circle_points(center, radius);
+        for (a, b) in points.iter().zip(points.iter().cycle().skip(1)) {
+            self.draw_triangle(center, *a, *b, color);
+        }
+    }
+    /// Defaults to a polygon of lines.
+    fn draw_circle_lines(&mut self, x: f32, y: f32, radius: f32, thickness: f32, color: Color) {
+        let points = circle_points(vec2(x, y), radius);
+        for (a, b) in points.iter().zip(points.iter().cycle().skip(1)) {
+            self.draw_line(a.x, a.y, b.x, b.y, thickness, color);
+        }
+    }
+    fn draw_text_ex(&mut self, text: &str, x: f32, y: f32, params: TextParams);
+    fn draw_texture_ex(
+        &mut self,
+        texture: &Texture2D,
+        x: f32,
+        y: f32,
+        color: Color,
+        params: DrawTextureParams,
+    );
+    /// Clip everything drawn after this to `clip` (in screen coordinates), or stop clipping with `None`.
+    fn set_clip(&mut self, clip: Option<Rect>);
+}
+
+/// Corners of the polygon the default `Painter::draw_circle`/`draw_circle_lines` draw.
+fn circle_points(center: Vec2, radius: f32) -> Vec<Vec2> {
+    const SIDES: usize = 20;
+    (0..SIDES)
+        .map(|i| center + Vec2::from_angle(i as f32 / SIDES as f32 * std::f32::consts::TAU) * radius)
+        .collect()
+}
+
+/// Backend > Backend (Input + painting, what a `WindowManager` runs on).
+pub trait Backend: InputSource + Painter {}
+
+impl<T: InputSource + Painter> Backend for T {}
+
+/// Backend > MacroquadBackend (Default backend using macroquad's global context).
+#[derive(Clone, Debug, Default)]
+pub struct MacroquadBackend {
+    /// This frame's typed characters, taken out of macroquad's queue by `begin_frame()`.
+    chars: Vec<char>,
+}
+
+impl InputSource for MacroquadBackend {
+    fn mouse_position(&self) -> Vec2 {
+        Vec2::from(mouse_position())
+    }
+
+    fn is_mouse_button_down(&self, button: MouseButton) -> bool {
+        is_mouse_button_down(button)
+    }
+
+    fn is_mouse_button_pressed(&self, button: MouseButton) -> bool {
+        is_mouse_button_pressed(button)
+    }
+
+    fn is_mouse_button_released(&self, button: MouseButton) -> bool {
+        is_mouse_button_released(button)
+    }
+
+    fn mouse_wheel(&self) -> Vec2 {
+        Vec2::from(mouse_wheel())
+    }
+
+    fn is_key_down(&self, key: KeyCode) -> bool {
+        is_key_down(key)
+    }
+
+    fn is_key_pressed(&self, key: KeyCode) -> bool {
+        is_key_pressed(key)
+    }
+
+    fn is_key_released(&self, key: KeyCode) -> bool {
+        is_key_released(key)
+    }
+
+    /// Takes the typed characters out of macroquad's queue (`get_char_pressed()` sees them no more):
+    /// read them with `chars_pressed()`, e.g. on `WindowManager::unconsumed_input()`.
+    fn begin_frame(&mut self) {
+        self.chars = std::iter::from_fn(get_char_pressed).collect();
+        // The queue pops the last typed character first.
+        self.chars.reverse();
+    }
+
+    fn chars_pressed(&self) -> Vec<char> {
+        self.chars.clone()
+    }
+
+    fn time(&self) -> f64 {
+        get_time()
+    }
+
+    fn clipboard_get(&self) -> Option<String> {
+        miniquad::window::clipboard_get()
+    }
+
+    fn clipboard_set(&self, text: &str) {
+        miniquad::window::clipboard_set(text);
+    }
+}
+
+impl Painter for MacroquadBackend {
+    fn screen_width(&self) -> f32 {
+        screen_width()
+    }
+
+    fn screen_height(&self) -> f32 {
+        screen_height()
+    }
+
+    fn measure_text(
+        &self,
+        text: &str,
+        font: Option<&Font>,
+        font_size: u16,
+        font_scale: f32,
+    ) -> TextDimensions {
+        measure_text(text, font, font_size, font_scale)
+    }
+
+    fn draw_rectangle(&mut self, x: f32, y: f32, w: f32, h: f32, color: Color) {
+        draw_rectangle(x, y, w, h, color);
+    }
+
+    fn draw_rectangle_lines(&mut self, x: f32, y: f32, w: f32, h: f32, thickness: f32, color: Color) {
+        draw_rectangle_lines(x, y, w, h, thickness, color);
+    }
+
+    fn draw_triangle(&mut self, v1: Vec2, v2: Vec2, v3: Vec2, color: Color) {
+        draw_triangle(v1, v2, v3, color);
+    }
+
+    fn draw_line(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, thickness: f32, color: Color) {
+        draw_line(x1, y1, x2, y2, thickness, color);
+    }
+
+    fn draw_circle(&mut self, x: f32, y: f32, radius: f32, color: Color) {
+        draw_circle(x, y, radius, color);
+    }
+
+    fn draw_circle_lines(&mut self, x: f32, y: f32, radius: f32, thickness: f32, color: Color) {
+        draw_circle_lines(x, y, radius, thickness, color);
+    }
+
+    fn draw_text_ex(&mut self, text: &str, x: f32, y: f32, params: TextParams) {
+        draw_text_ex(text, x, y, params);
+    }
+
+    fn draw_texture_ex(
+        &mut self,
+        texture: &Texture2D,
+        x: f32,
+        y: f32,
+        color: Color,
+        params: DrawTextureParams,
+    ) {
+        draw_texture_ex(texture, x, y, color, params);
+    }
+
+    fn set_clip(&mut self, clip: Option<Rect>) {
+        let dpi = screen_dpi_scale();
+        let gl = unsafe { get_internal_gl() };
+        gl.quad_gl.scissor(clip.map(|rect| {
+            (
+                (rect.x * dpi) as i32,
+                (rect.y * dpi) as i32,
+                (rect.w * dpi) as i32,
+                (rect.h * dpi) as i32,
+            )
+        }));
+    }
+}
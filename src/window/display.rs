@@ -1,6 +1,9 @@
 #![allow(unused)]
 
 use super::widgets::*;
+use super::*;
+use std::cell::Cell;
+
 use macroquad::{prelude::*, ui};
 
 /// Style > Custom window styling.
@@ -16,6 +19,9 @@ pub struct WindowStyle {
     pub scale_color: Color,
     pub minimize_color: Color,
     pub close_color: Color,
+    /// Ring drawn around the widget with keyboard focus.
+    pub focus_color: Color,
+    pub scrollbar: ScrollbarStyle,
 }
 
 impl Default for WindowStyle {
@@ -36,6 +42,8 @@ impl Default for WindowStyle {
             scale_color: Color::new(1.0, 0.7, 0., 0.25),
             minimize_color: BLACK,
             close_color: BLACK,
+            focus_color: Color::new(1.0, 0.8, 0.2, 0.9),
+            scrollbar: ScrollbarStyle::default(),
         }
         .clone()
     }
@@ -51,6 +59,19 @@ pub struct WindowProperties {
     pub no_title_bar: bool,
 }
 
+/// Layer > WindowLayer (Which band of the z-order a window lives in).
+///
+/// Windows are always drawn above windows of a lower layer; inside a layer the last selected is in front.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum WindowLayer {
+    Background,
+    #[default]
+    Normal,
+    AlwaysOnTop,
+    Overlay,
+    Tooltip,
+}
+
 /// # The base window class.
 #[derive(Clone)]
 pub struct Window {
@@ -65,7 +86,11 @@ pub struct Window {
     pub dragging: bool,
     drag_mpos: (Vec2, Vec2),
     pub selected: bool,
+    /// Whether the window gets the mouse (it is the frontmost window under it, or holding it).
+    /// Set by the `WindowManager` every frame; windows that aren't hovered see no mouse.
+    pub hovered: bool,
     pub properties: WindowProperties,
+    pub layer: WindowLayer,
     pub widgets: Vec<Widget>,
     pub queue_free: bool,
     pub scaling: Option<(Vec2, Vec2)>,
@@ -81,6 +106,21 @@ pub struct Window {
     close_pressed: bool,
     close_hovered: bool,
     pub frame_pushed: Vec<Widget>,
+    pub draw_list: DrawList,
+    title_scale: f32,
+    id_stack: Vec<u64>,
+    pub layout: Layout,
+    pub scroll: Scroll,
+    /// ID of the widget with keyboard focus (it only has it while the window is selected).
+    pub focus: Option<u64>,
+    focus_here: bool,
+    next_align: Option<Align>,
+    /// How far widgets pushed now are indented (by `indent()`/`unindent()`).
+    indent: f32,
+    /// Extra space before the next widget pushed (from `spacing()`).
+    next_space: f32,
+    next_same_line: bool,
+    placements: Vec<Placement>,
 }
 
 // MAIN IMPL
@@ -95,12 +135,12 @@ impl Window {
     ) -> Self {
         Self {
             name: name.to_string(),
-            id: id,
-            uuid: uuid.unwrap_or("".to_owned()),
+            id,
+            uuid: uuid.unwrap_or_default(),
             rect,
             tb_rect: Rect::new(rect.x, rect.y, rect.w, 20.0),
             style: WindowStyle {
-                font: font,
+                font,
                 ..Default::default()
             },
             properties: WindowProperties {
@@ -110,14 +150,16 @@ impl Window {
                 minimizable: true,
                 no_title_bar: false,
             },
+            layer: WindowLayer::Normal,
             selected: false,
+            hovered: true,
             tb_hovered: false,
             tb_pressed: false,
             dragging: false,
             drag_mpos: (Vec2::ZERO, Vec2::ZERO),
             queue_free: false,
             scaling: None,
-            widgets: widgets.unwrap_or(Vec::new()),
+            widgets: widgets.unwrap_or_default(),
             scale_collider: Rect::new(0., 0., 0., 0.),
             scale_triangle_size: 15f32,
             scale_hover: false,
@@ -130,45 +172,86 @@ impl Window {
             close_pressed: false,
             close_hovered: false,
             frame_pushed: vec![],
+            draw_list: DrawList::new(),
+            title_scale: 1.0,
+            id_stack: vec![],
+            layout: Layout::default(),
+            scroll: Scroll::default(),
+            focus: None,
+            focus_here: false,
+            next_align: None,
+            indent: 0.0,
+            next_space: 0.0,
+            next_same_line: false,
+            placements: vec![],
         }
     }
 
+    /// Start a new frame of widget pushes (called by `WindowManager::begin`).
+    pub(crate) fn begin_frame(&mut self) {
+        self.frame_pushed.clear();
+        self.id_stack.clear();
+        self.next_align = None;
+        self.focus_here = false;
+        self.indent = 0.0;
+        self.next_space = 0.0;
+        self.next_same_line = false;
+        self.placements.clear();
+    }
+
     pub fn queue_free(&mut self) {
         self.queue_free = true;
         drop(self)
     }
 
-    pub fn update(&mut self, selected: Option<usize>, mouse_position: &Vec2) {
-        self.update_close_button(mouse_position);
+    pub fn update(&mut self, selected: Option<usize>, backend: &dyn Backend) {
+        let widget_mouse = match self.hovered {
+            true => backend.mouse_position(),
+            false => NOWHERE,
+        };
+        // Over an open popup the mouse only goes to the popup's widget, not to the window itself.
+        let mouse_position = &match self.popup_contains(widget_mouse) {
+            true => NOWHERE,
+            false => widget_mouse,
+        };
+
+        self.update_close_button(mouse_position, backend);
 
-        self.update_mouse_released();
+        self.update_mouse_released(backend);
 
-        self.update_window_scaling(mouse_position);
+        self.update_window_scaling(mouse_position, backend);
 
         if !self.properties.no_title_bar {
             self.update_dragging(mouse_position);
-            self.update_minimise(mouse_position);
-            self.update_top_bar(selected, mouse_position);
+            self.update_minimise(mouse_position, backend);
+            self.update_top_bar(selected, mouse_position, backend);
         }
 
-        self.update_wall_collision();
+        self.update_wall_collision(backend);
+
+        let was_selected = self.selected;
+        self.update_selection(selected, &widget_mouse, backend);
 
-        self.update_selection(selected, mouse_position);
+        self.update_widgets(&widget_mouse, was_selected, backend);
 
-        self.update_widgets(mouse_position);
+        self.update_quit_window(backend);
 
-        self.update_quit_window();
+        self.update_min_size_limit(backend);
 
-        self.update_min_size_limit();
+        self.update_title(backend);
     }
 
+    /// Record this frame's drawing into `draw_list` (submitted by the `WindowManager`).
     pub fn render(&mut self) {
+        let mut draw = std::mem::take(&mut self.draw_list);
+        draw.clear();
+
         // TOP BAR
-        let title_padding = self.render_top_bar();
+        let title_padding = self.render_top_bar(&mut draw);
 
         // MAIN
-        if !self.minimized.is_some() {
-            draw_rectangle(
+        if self.minimized.is_none() {
+            draw.draw_rectangle(
                 self.rect.x,
                 self.rect.y + title_padding,
                 self.rect.w,
@@ -176,19 +259,21 @@ impl Window {
                 self.style.bg_color,
             );
 
-            self.render_widgets(title_padding);
+            self.render_widgets(&mut draw);
 
-            self.render_scale_triangle();
+            self.render_scale_triangle(&mut draw);
         }
 
-        self.render_outline();
+        self.render_outline(&mut draw);
+
+        self.draw_list = draw;
     }
 }
 
 // UPDATE
 impl Window {
-    fn update_mouse_released(&mut self) {
-        if !is_mouse_button_down(MouseButton::Left) {
+    fn update_mouse_released(&mut self, backend: &dyn Backend) {
+        if !backend.is_mouse_button_down(MouseButton::Left) {
             self.tb_pressed = false;
             self.dragging = false;
             self.scaling = None;
@@ -197,7 +282,7 @@ impl Window {
         }
     }
 
-    fn update_window_scaling(&mut self, mouse_position: &Vec2) {
+    fn update_window_scaling(&mut self, mouse_position: &Vec2, backend: &dyn Backend) {
         let bottom_right = vec2(self.rect.x + self.rect.w, self.rect.y + self.rect.h);
         self.scale_collider.x = bottom_right.x - self.scale_triangle_size;
         self.scale_collider.y = bottom_right.y - self.scale_triangle_size;
@@ -206,7 +291,7 @@ impl Window {
 
         if self.properties.scalable && self.scale_collider.contains(*mouse_position) {
             self.scale_hover = true;
-            if is_mouse_button_pressed(MouseButton::Left) {
+            if backend.is_mouse_button_pressed(MouseButton::Left) {
                 self.scaling = Some((vec2(self.rect.w, self.rect.h), *mouse_position));
             }
         } else {
@@ -214,13 +299,13 @@ impl Window {
         }
 
         if let Some((size, position)) = self.scaling {
-            self.rect.w = size.x + (*mouse_position).x - position.x;
-            self.rect.h = size.y + (*mouse_position).y - position.y;
-            if self.rect.x + self.rect.w > screen_width() {
-                self.rect.w = screen_width() - self.rect.x;
+            self.rect.w = size.x + mouse_position.x - position.x;
+            self.rect.h = size.y + mouse_position.y - position.y;
+            if self.rect.x + self.rect.w > backend.screen_width() {
+                self.rect.w = backend.screen_width() - self.rect.x;
             }
-            if self.rect.y + self.rect.h > screen_height() {
-                self.rect.h = screen_height() - self.rect.y;
+            if self.rect.y + self.rect.h > backend.screen_height() {
+                self.rect.h = backend.screen_height() - self.rect.y;
             }
         }
     }
@@ -234,7 +319,7 @@ impl Window {
         }
     }
 
-    fn update_wall_collision(&mut self) {
+    fn update_wall_collision(&mut self, backend: &dyn Backend) {
         if self.properties.wall_collision {
             if self.rect.x < 0.0 {
                 self.rect.x = 0.0
@@ -243,19 +328,19 @@ impl Window {
                 self.rect.y = 0.0
             }
 
-            if self.rect.x > screen_width() - self.rect.w {
-                self.rect.x = screen_width() - self.rect.w
+            if self.rect.x > backend.screen_width() - self.rect.w {
+                self.rect.x = backend.screen_width() - self.rect.w
             }
-            if self.rect.y > screen_height() - self.rect.h {
-                self.rect.y = screen_height() - self.rect.h
+            if self.rect.y > backend.screen_height() - self.rect.h {
+                self.rect.y = backend.screen_height() - self.rect.h
             }
         }
     }
 
-    fn update_selection(&mut self, selected: Option<usize>, mouse_position: &Vec2) {
-        if is_mouse_button_pressed(MouseButton::Left) {
-            if self.rect.contains(*mouse_position) {
-                if selected == None {
+    fn update_selection(&mut self, selected: Option<usize>, mouse_position: &Vec2, backend: &dyn Backend) {
+        if backend.is_mouse_button_pressed(MouseButton::Left) {
+            if self.contains(*mouse_position) {
+                if selected.is_none() {
                     self.selected = true;
                 }
             } else {
@@ -264,24 +349,18 @@ impl Window {
         }
     }
 
-    fn update_top_bar(&mut self, selected: Option<usize>, mouse_position: &Vec2) {
-        match (
-            (self.tb_hovered && !self.minimize_pressed && !self.close_pressed),
-            self.tb_pressed,
-        ) {
-            (true, true) => {
-                if selected == None {
-                    self.selected = true;
-                    self.dragging = true;
-                }
-                self.drag_mpos = (*mouse_position, vec2(self.rect.x, self.rect.y));
+    fn update_top_bar(&mut self, selected: Option<usize>, mouse_position: &Vec2, backend: &dyn Backend) {
+        if self.tb_hovered && !self.minimize_pressed && !self.close_pressed && self.tb_pressed {
+            if selected.is_none() {
+                self.selected = true;
+                self.dragging = true;
             }
-            _ => {}
-        };
+            self.drag_mpos = (*mouse_position, vec2(self.rect.x, self.rect.y));
+        }
 
         if self.tb_rect.contains(*mouse_position) {
             self.tb_hovered = true;
-            if is_mouse_button_pressed(MouseButton::Left) {
+            if backend.is_mouse_button_pressed(MouseButton::Left) {
                 self.tb_pressed = true
             }
         } else {
@@ -293,87 +372,152 @@ impl Window {
         self.tb_rect.w = self.rect.w;
     }
 
-    fn update_widgets(&mut self, mouse_position: &Vec2) {
+    /// Measure the widgets, size the window to them, then give each widget its rect and update it.
+    ///
+    /// Content that doesn't fit in the window is scrolled (by the wheel or the scrollbars).
+    /// Clicking a widget that takes focus focuses it; clicking elsewhere in an already selected window drops focus.
+    fn update_widgets(&mut self, mouse_position: &Vec2, was_selected: bool, backend: &dyn Backend) {
         let title_padding = match self.properties.no_title_bar {
             true => 0.0,
             _ => 20.0,
         };
+        let padding = self.layout.padding;
+        let spacing = self.layout.item_spacing;
+
+        let focus_moved = self.update_focus(backend);
+
+        let scroll_delta = Cell::new(Vec2::ZERO);
+        let focus_request = Cell::new(None);
+        let mut ctx = WidgetContext {
+            backend,
+            selected: self.selected,
+            mouse_position: *mouse_position,
+            mouse_released: backend.is_mouse_button_released(MouseButton::Left),
+            scroll_delta: &scroll_delta,
+            focused: false,
+            focus: self.focus.filter(|_| self.selected),
+            widget_id: 0,
+            focus_request: &focus_request,
+            popup: find_popup(&self.widgets),
+        };
 
-        let mut max_width = 0f32;
-        let mut last_y = 17.0 + title_padding;
-        let padding = 5.0;
-        let padding_left = 7.0;
+        let sizes: Vec<Vec2> = self.widgets.iter_mut().map(|i| i.measure(&ctx)).collect();
+        let content = flow_size(&sizes, &self.placements, spacing) + padding * 2.0;
 
-        let mouse_released = is_mouse_button_released(MouseButton::Left);
+        if self.minimized.is_none() {
+            self.fit_content(content + vec2(0.0, title_padding), backend);
+        }
 
-        for i in self.widgets.iter_mut() {
-            if let Widget::Text(i) = i {
-                i.rect.x = self.rect.x + padding_left;
-                i.rect.y = self.rect.y + last_y;
-                i.update(self.selected);
-
-                last_y += i.rect.h + padding + 1.0;
-                if i.rect.w > max_width {
-                    max_width = i.rect.w;
-                }
-            } else if let Widget::Button(i) = i {
-                i.rect.x = self.rect.x + padding_left;
-                i.rect.y = self.rect.y + last_y;
-                i.update(self.selected, *mouse_position, mouse_released);
-
-                last_y += i.rect.h + padding + 4.0;
-                if i.button_rect.w + 4.0 > max_width {
-                    max_width = i.button_rect.w + 4.0;
-                }
-            } else if let Widget::Slider(i) = i {
-                i.rect.x = self.rect.x + padding_left;
-                i.rect.y = self.rect.y + last_y - 10.0;
-                i.update(self.selected, mouse_position, mouse_released);
-
-                last_y += i.rect.h + padding + 1.0;
-                if i.rect.w + 4.0 > max_width {
-                    max_width = i.rect.w + 4.0;
-                }
-            } else if let Widget::DisplayImage(i) = i {
-                i.rect.x = self.rect.x + padding_left;
-                i.rect.y = self.rect.y + last_y - 10.0;
-                i.update(self.selected);
-
-                last_y += i.rect.h + padding + 1.0;
-                if i.rect.w + 4.0 > max_width {
-                    max_width = i.rect.w + 4.0;
-                }
-            } else if let Widget::WidgetRow(i) = i {
-                i.rect.x = self.rect.x + padding_left;
-                i.rect.y = self.rect.y + last_y;
-                i.update(self.selected, *mouse_position, mouse_released);
-                i.frame_pushed.clear();
-
-                last_y += i.rect.h + padding - 2.0;
-                if i.rect.w > max_width {
-                    max_width = i.rect.w;
-                }
-            } else if let Widget::Checkbox(i) = i {
-                i.rect.x = self.rect.x + padding_left;
-                i.rect.y = self.rect.y + last_y;
-                i.update(self.selected, *mouse_position, mouse_released);
-
-                last_y += i.rect.h + padding + 4.0;
-                if i.rect.w + 4.0 > max_width {
-                    max_width = i.rect.w + 4.0;
-                }
+        let body = Rect::new(
+            self.rect.x,
+            self.rect.y + title_padding,
+            self.rect.w,
+            (self.rect.h - title_padding).max(0.0),
+        );
+        let corner = match self.properties.scalable {
+            true => self.scale_triangle_size,
+            false => 0.0,
+        };
+        self.scroll.layout(body, content, corner, &self.style.scrollbar);
+
+        // Widgets only see the mouse in view, unless one is holding it (e.g. a slider being dragged).
+        let viewport = self.scroll.viewport;
+        let holding = self.widgets.iter().any(|i| i.as_dyn().is_active());
+        let over_popup = ctx.over_popup();
+        let bar_mouse = if over_popup { NOWHERE } else { *mouse_position };
+        let in_view = viewport.contains(*mouse_position) || over_popup;
+        if self.scroll.update(bar_mouse, backend) || !(holding || in_view) {
+            ctx.mouse_position = NOWHERE;
+        }
+        if body.contains(*mouse_position) || over_popup {
+            ctx.scroll_delta.set(wheel_delta(backend, self.layout.scroll_speed));
+        }
+
+        let rects = flow(
+            viewport.point() + padding - self.scroll.offset,
+            &sizes,
+            &self.placements,
+            spacing,
+            viewport.w.max(content.x) - padding.x * 2.0,
+        );
+        for (i, rect) in self.widgets.iter_mut().zip(rects) {
+            i.update(rect, &ctx);
+        }
+
+        // Scrolling widgets (e.g. scroll areas) under the mouse come first.
+        self.scroll.scroll_by(scroll_delta.take());
+
+        if let Some(id) = focus_request.take() {
+            self.focus = Some(id);
+        } else if was_selected && body.contains(*mouse_position) && backend.is_mouse_button_pressed(MouseButton::Left) {
+            self.focus = None;
+        }
+
+        // Keep the widget tabbed to in view.
+        if let Some(focused) = self.focus.filter(|_| focus_moved).and_then(|id| find_id(&self.widgets, id)) {
+            let rect = focused.rect();
+            if rect.bottom() > viewport.bottom() {
+                self.scroll.offset.y += rect.bottom() + padding.y - viewport.bottom();
+            } else if rect.y < viewport.y {
+                self.scroll.offset.y -= viewport.y - rect.y + padding.y;
             }
         }
+    }
+
+    /// Move keyboard focus with Tab/Shift+Tab (Escape drops it). Returns whether it moved.
+    fn update_focus(&mut self, backend: &dyn Backend) -> bool {
+        let mut ids = vec![];
+        focusable_ids(&self.widgets, &mut ids);
+
+        let current = self.focus.and_then(|id| ids.iter().position(|i| *i == id));
+        if current.is_none() {
+            self.focus = None;
+        }
+        if !self.selected || ids.is_empty() {
+            return false;
+        }
 
-        if last_y - 5.0 > self.rect.h {
-            self.rect.h = last_y - 5.0;
+        let wants_tab = current.is_some_and(|idx| {
+            find_id(&self.widgets, ids[idx]).is_some_and(|i| i.as_dyn().wants_tab())
+        });
+        if backend.is_key_pressed(KeyCode::Escape) {
+            self.focus = None;
+        } else if backend.is_key_pressed(KeyCode::Tab) && !wants_tab {
+            let back = shift_down(backend);
+            let next = match (current, back) {
+                (None, false) => 0,
+                (None, true) => ids.len() - 1,
+                (Some(idx), false) => (idx + 1) % ids.len(),
+                (Some(idx), true) => (idx + ids.len() - 1) % ids.len(),
+            };
+            self.focus = Some(ids[next]);
+            return true;
         }
-        if max_width + 4.0 > self.rect.w {
-            self.rect.w = max_width + 4.0
+        false
+    }
+
+    fn fit_content(&mut self, size: Vec2, backend: &dyn Backend) {
+        // Never grow past the screen; what doesn't fit is scrolled instead.
+        let room = vec2(
+            backend.screen_width() - self.rect.x,
+            backend.screen_height() - self.rect.y,
+        );
+        let size = size.min(room);
+
+        match self.layout.auto_size {
+            AutoSize::Off => {}
+            AutoSize::Grow => {
+                self.rect.w = self.rect.w.max(size.x);
+                self.rect.h = self.rect.h.max(size.y);
+            }
+            AutoSize::Fit => {
+                self.rect.w = size.x;
+                self.rect.h = size.y;
+            }
         }
     }
 
-    fn update_minimise(&mut self, mouse_position: &Vec2) {
+    fn update_minimise(&mut self, mouse_position: &Vec2, backend: &dyn Backend) {
         self.minimize_rect.x = self.tb_rect.x + 6.;
         self.minimize_rect.y = self.tb_rect.y + 6.;
         self.minimize_rect.w = 14.0;
@@ -381,7 +525,7 @@ impl Window {
 
         if !self.properties.no_title_bar && self.minimize_rect.contains(*mouse_position) {
             self.minimize_hover = true;
-            if is_mouse_button_pressed(MouseButton::Left) {
+            if backend.is_mouse_button_pressed(MouseButton::Left) {
                 self.minimize_pressed = true;
                 if let Some(height) = self.minimized {
                     self.rect.h = height;
@@ -399,13 +543,13 @@ impl Window {
         }
     }
 
-    fn update_quit_window(&mut self) {
-        if self.selected && is_key_down(KeyCode::LeftControl) && is_key_pressed(KeyCode::Q) {
+    fn update_quit_window(&mut self, backend: &dyn Backend) {
+        if self.selected && backend.is_key_down(KeyCode::LeftControl) && backend.is_key_pressed(KeyCode::Q) {
             self.queue_free = true;
         }
     }
 
-    fn update_close_button(&mut self, mouse_position: &Vec2) {
+    fn update_close_button(&mut self, mouse_position: &Vec2, backend: &dyn Backend) {
         self.close_rect.x = self.tb_rect.x + self.tb_rect.w - 18.;
         self.close_rect.y = self.tb_rect.y + 3.;
         self.close_rect.w = 14.0;
@@ -413,10 +557,10 @@ impl Window {
 
         if !self.properties.no_title_bar && self.close_rect.contains(*mouse_position) {
             self.close_hovered = true;
-            if is_mouse_button_pressed(MouseButton::Left) {
+            if backend.is_mouse_button_pressed(MouseButton::Left) {
                 self.close_pressed = true;
             }
-            if is_mouse_button_released(MouseButton::Left) && self.close_pressed {
+            if backend.is_mouse_button_released(MouseButton::Left) && self.close_pressed {
                 self.queue_free()
             }
         } else {
@@ -428,8 +572,8 @@ impl Window {
         }
     }
 
-    fn update_min_size_limit(&mut self) {
-        let dim = measure_text(&self.name, None, 16, 1f32);
+    fn update_min_size_limit(&mut self, backend: &dyn Backend) {
+        let dim = backend.measure_text(&self.name, None, 16, 1f32);
         let title_width = dim.width * 1.2 + 4.0;
         if !self.properties.no_title_bar {
             if self.rect.w < title_width + 40.0 {
@@ -447,11 +591,18 @@ impl Window {
             }
         }
     }
+
+    fn update_title(&mut self, backend: &dyn Backend) {
+        let dim = backend.measure_text(&self.name, None, 16, 1f32);
+        let dim_some = backend.measure_text(&self.name, self.style.font.as_ref(), 16, 1f32);
+
+        self.title_scale = dim.height / dim_some.height;
+    }
 }
 
 // RENDER
 impl Window {
-    fn render_top_bar(&mut self) -> f32 {
+    fn render_top_bar(&mut self, draw: &mut DrawList) -> f32 {
         self.tb_rect.x = self.rect.x;
         self.tb_rect.y = self.rect.y;
         self.tb_rect.w = self.rect.w;
@@ -469,22 +620,17 @@ impl Window {
         self.close_rect.h = self.tb_rect.h - 6.;
 
         if !self.properties.no_title_bar {
-            self.render_topbar_and_title();
-            self.render_minimise_button();
-            self.render_close_button();
+            self.render_topbar_and_title(draw);
+            self.render_minimise_button(draw);
+            self.render_close_button(draw);
         }
 
         title_padding
     }
 
-    fn render_topbar_and_title(&mut self) {
-        let dim = measure_text(&self.name.to_string(), None, 16, 1f32);
-        let dim_some = measure_text(&self.name.to_string(), self.style.font.as_ref(), 16, 1f32);
-
-        let height_diff = dim.height / dim_some.height;
-
+    fn render_topbar_and_title(&mut self, draw: &mut DrawList) {
         // TOP BAR
-        draw_rectangle(
+        draw.draw_rectangle(
             self.tb_rect.x,
             self.tb_rect.y,
             self.tb_rect.w,
@@ -496,7 +642,7 @@ impl Window {
         );
 
         // WINDOW TITLE
-        draw_text_ex(
+        draw.draw_text_ex(
             &self.name,
             self.tb_rect.x
                 + 5f32
@@ -509,13 +655,13 @@ impl Window {
                 font: self.style.font.as_ref(),
                 font_size: 16,
                 color: self.style.title_color,
-                font_scale: height_diff,
+                font_scale: self.title_scale,
                 ..Default::default()
             },
         );
     }
 
-    fn render_minimise_button(&mut self) {
+    fn render_minimise_button(&mut self, draw: &mut DrawList) {
         self.minimize_rect.x = self.tb_rect.x + 6.;
         self.minimize_rect.y = self.tb_rect.y + 6.;
         self.minimize_rect.w = 14.0;
@@ -524,7 +670,7 @@ impl Window {
         // MINIMIZE TRIANGLE
         match (self.minimized.is_none(), self.properties.minimizable) {
             (true, true) => {
-                draw_triangle(
+                draw.draw_triangle(
                     vec2(self.minimize_rect.x, self.minimize_rect.y),
                     vec2(
                         self.minimize_rect.x + self.minimize_rect.w,
@@ -543,7 +689,7 @@ impl Window {
                 );
             }
             (_, true) => {
-                draw_triangle(
+                draw.draw_triangle(
                     vec2(
                         self.minimize_rect.x,
                         self.minimize_rect.y + self.minimize_rect.h - 2.0,
@@ -568,7 +714,7 @@ impl Window {
         }
     }
 
-    fn render_close_button(&mut self) {
+    fn render_close_button(&mut self, draw: &mut DrawList) {
         // CLOSE BUTTON
         if self.close_hovered {
             self.close_rect.x -= 0.5;
@@ -578,7 +724,7 @@ impl Window {
         }
 
         // CLOSE RECTANGLE
-        draw_rectangle(
+        draw.draw_rectangle(
             self.close_rect.x,
             self.close_rect.y,
             self.close_rect.w,
@@ -591,7 +737,7 @@ impl Window {
 
         // CLOSE 'X'
         let x_thickness = 2f32;
-        draw_line(
+        draw.draw_line(
             self.close_rect.x + 3.,
             self.close_rect.y + 3.,
             self.close_rect.x + self.close_rect.w - 3.,
@@ -600,7 +746,7 @@ impl Window {
             self.style.close_color,
         );
 
-        draw_line(
+        draw.draw_line(
             self.close_rect.x + self.close_rect.w - 3.,
             self.close_rect.y + 3.,
             self.close_rect.x + 3.,
@@ -610,12 +756,12 @@ impl Window {
         )
     }
 
-    fn render_scale_triangle(&self) {
+    fn render_scale_triangle(&self, draw: &mut DrawList) {
         // SCALE TRIANGLE
         let bottom_right = vec2(self.rect.x + self.rect.w, self.rect.y + self.rect.h);
 
         if self.properties.scalable {
-            draw_triangle(
+            draw.draw_triangle(
                 bottom_right,
                 bottom_right - Vec2::X * (self.scale_triangle_size + 2.),
                 bottom_right - Vec2::Y * (self.scale_triangle_size + 2.),
@@ -632,59 +778,33 @@ impl Window {
         }
     }
 
-    fn render_widgets(&mut self, title_padding: f32) {
+    fn render_widgets(&mut self, draw: &mut DrawList) {
         if let Some(style) = &self.button_style {
             self.button_style(style.clone());
         }
 
-        // WIDGETS
-        let mut last_y = 17.0 + title_padding;
-        let padding = 5.0;
-        let padding_left = 7.0;
-
+        // WIDGETS (only those in view, clipped to it)
+        let viewport = self.scroll.viewport;
+        draw.push_clip(viewport);
         for i in self.widgets.iter_mut() {
-            if let Widget::Text(i) = i {
-                i.rect.x = self.rect.x + padding_left;
-                i.rect.y = self.rect.y + last_y;
-                i.render();
-
-                last_y += i.rect.h + padding;
-            } else if let Widget::Button(i) = i {
-                i.rect.x = self.rect.x + padding_left;
-                i.rect.y = self.rect.y + last_y;
-                i.render();
-
-                last_y += i.rect.h + padding + 4.0;
-            } else if let Widget::Slider(i) = i {
-                i.rect.x = self.rect.x + padding_left;
-                i.rect.y = self.rect.y + last_y - 10.0;
-                i.render();
-
-                last_y += i.rect.h + padding;
-            } else if let Widget::DisplayImage(i) = i {
-                i.rect.x = self.rect.x + padding_left;
-                i.rect.y = self.rect.y + last_y - 10.0;
-                i.render();
-
-                last_y += i.rect.h + padding;
-            } else if let Widget::WidgetRow(i) = i {
-                i.rect.x = self.rect.x + padding_left;
-                i.rect.y = self.rect.y + last_y;
-                i.render();
-
-                last_y += i.rect.h + padding;
-            } else if let Widget::Checkbox(i) = i {
-                i.rect.x = self.rect.x + padding_left;
-                i.rect.y = self.rect.y + last_y - 10.0;
-                i.render();
-
-                last_y += i.rect.h + padding;
+            if i.rect().overlaps(&viewport) {
+                i.render(draw);
             }
         }
+
+        // FOCUS RING
+        let focused = self.focus.filter(|_| self.selected).and_then(|id| find_id(&self.widgets, id));
+        if let Some(focused) = focused {
+            let rect = focused.focus_rect();
+            draw.draw_rectangle_lines(rect.x - 2.0, rect.y - 2.0, rect.w + 4.0, rect.h + 4.0, 2.0, self.style.focus_color);
+        }
+        draw.pop_clip();
+
+        self.scroll.render(draw, &self.style.scrollbar);
     }
 
-    fn render_outline(&self) {
-        draw_rectangle_lines(
+    fn render_outline(&self, draw: &mut DrawList) {
+        draw.draw_rectangle_lines(
             self.rect.x,
             self.rect.y,
             self.rect.w,
@@ -729,6 +849,82 @@ impl Window {
         self
     }
 
+    /// Scroll the window's content to `x` (clamped to the content).
+    pub fn set_scroll_x(&mut self, x: f32) -> &mut Self {
+        self.scroll.offset.x = x;
+        self
+    }
+
+    /// Scroll the window's content to `y` (clamped to the content).
+    pub fn set_scroll_y(&mut self, y: f32) -> &mut Self {
+        self.scroll.offset.y = y;
+        self
+    }
+
+    /// Scroll to the bottom of the window's content (e.g. to follow a log as it grows).
+    pub fn scroll_to_bottom(&mut self) -> &mut Self {
+        self.scroll.offset.y = f32::INFINITY;
+        self
+    }
+
+    /// Set the layer (z-order band) of the window.
+    pub fn layer(&mut self, layer: WindowLayer) -> &mut Self {
+        self.layer = layer;
+        self
+    }
+
+    /// Set the layout (spacing, padding, alignment and auto sizing) of the window's widgets.
+    pub fn layout(&mut self, layout: Layout) -> &mut Self {
+        self.layout = layout;
+        self
+    }
+
+    /// Align the next widget pushed (other widgets use `layout.align`).
+    /// ```no_run
+    /// # use amberwindow::*;
+    /// # use macroquad::prelude::*;
+    /// # let mut windows = WindowManager::new();
+    /// # let win = windows.begin("window").unwrap();
+    /// win.align(Align::Center).Text("Game Over", None);
+    /// ```
+    pub fn align(&mut self, align: Align) -> &mut Self {
+        self.next_align = Some(align);
+        self
+    }
+
+    /// Put the next widget pushed to the right of the previous one, instead of under it.
+    /// ```no_run
+    /// # use amberwindow::*;
+    /// # use macroquad::prelude::*;
+    /// # let mut windows = WindowManager::new();
+    /// # let win = windows.begin("window").unwrap();
+    /// win.Text("Volume", None);
+    /// win.same_line();
+    /// win.Slider_float(0., 1., None, vec2(100., 15.));
+    /// ```
+    pub fn same_line(&mut self) -> &mut Self {
+        self.next_same_line = true;
+        self
+    }
+
+    /// Leave `px` of extra space before the next widget pushed (above it, or left of it after `same_line()`).
+    pub fn spacing(&mut self, px: f32) -> &mut Self {
+        self.next_space += px;
+        self
+    }
+
+    /// Move the widgets pushed from now on right by `layout.indent_spacing` (until `unindent()`).
+    pub fn indent(&mut self) -> &mut Self {
+        self.indent += self.layout.indent_spacing;
+        self
+    }
+
+    /// Undo the last `indent()`.
+    pub fn unindent(&mut self) -> &mut Self {
+        self.indent = (self.indent - self.layout.indent_spacing).max(0.0);
+        self
+    }
+
     /// Set the properties of the window.
     pub fn properties(&mut self, properties: WindowProperties) -> &mut Self {
         self.properties = properties;
@@ -744,27 +940,51 @@ impl Window {
     /// Set the window's buttons' styles.
     pub fn button_style(&mut self, style: ButtonStyle) -> &mut Self {
         for i in self.widgets.iter_mut() {
-            if let Widget::Button(i) = i {
-                i.style = style.clone();
-            } else if let Widget::WidgetRow(i) = i {
-                i.button_style(&style);
-            }
+            i.as_dyn_mut().set_button_style(&style);
         }
         self
     }
 
-    /// Set the window's slider' styles.
+    /// Set the window's sliders' styles (progress bars and spinners use them too).
     pub fn slider_style(&mut self, style: SliderStyle) -> &mut Self {
         for i in self.widgets.iter_mut() {
-            if let Widget::Slider(i) = i {
-                i.style = style.clone();
-            } else if let Widget::WidgetRow(i) = i {
-                i.slider_style(&style);
-            }
+            i.as_dyn_mut().set_slider_style(&style);
         }
         self
     }
 
+    /// Whether `point` is over the window (title bar, body or scale triangle) or a popup open in it.
+    pub fn contains(&self, point: Vec2) -> bool {
+        self.rect.contains(point) || self.popup_contains(point)
+    }
+
+    /// Whether `point` is over a popup open in the window (e.g. a `Combo`'s list).
+    pub fn popup_contains(&self, point: Vec2) -> bool {
+        find_popup(&self.widgets).is_some_and(|(_, rect)| rect.contains(point))
+    }
+
+    /// Whether the window holds the mouse (being dragged or scaled, or a widget is being pressed).
+    pub fn is_capturing_mouse(&self) -> bool {
+        self.dragging
+            || self.scaling.is_some()
+            || self.tb_pressed
+            || self.minimize_pressed
+            || self.close_pressed
+            || self.scroll.is_dragging()
+            || self.widgets.iter().any(|i| i.as_dyn().is_active())
+    }
+
+    /// Whether one of the (selected) window's widgets is taking keyboard input, or has keyboard focus.
+    pub fn wants_keyboard_input(&self) -> bool {
+        self.selected && (self.focus.is_some() || self.widgets.iter().any(|i| i.as_dyn().wants_keyboard()))
+    }
+
+    /// Give keyboard focus to the next widget pushed (it has it while the window is selected).
+    pub fn set_keyboard_focus_here(&mut self) -> &mut Self {
+        self.focus_here = true;
+        self
+    }
+
     /// Get a widget by its index (usize/int).
     pub fn get_widget(&mut self, idx: usize) -> &mut Widget {
         &mut self.widgets[idx]
@@ -775,34 +995,47 @@ impl Window {
         &mut self.widgets[idx]
     }
 
-    /// Get a widget using its uuid (str).
+    /// Get a widget using its uuid (str), including widgets inside `WidgetRow`s.
     pub fn get_widget_from_uuid(&mut self, uuid: &str) -> Option<&mut Widget> {
-        for i in self.widgets.iter_mut() {
-            if i.as_text().uuid == uuid {
-                return Some(i);
-            }
-            if i.as_button().uuid == uuid {
-                return Some(i);
-            }
-            if i.as_slider().uuid == uuid {
-                return Some(i);
-            }
-            if i.as_image().uuid == uuid {
-                return Some(i);
-            }
-            if i.as_widget_row().uuid == uuid {
-                return Some(i);
-            }
-        }
-        return None;
+        find_uuid(&mut self.widgets, uuid)
+    }
+
+    /// Get a `Text` using its uuid (`None` if there is no such widget, or it isn't a `Text`).
+    pub fn get_text(&mut self, uuid: &str) -> Option<&mut Text> {
+        self.get_widget_from_uuid(uuid)?.try_as_text()
+    }
+
+    /// Get a `Button` using its uuid (`None` if there is no such widget, or it isn't a `Button`).
+    pub fn get_button(&mut self, uuid: &str) -> Option<&mut Button> {
+        self.get_widget_from_uuid(uuid)?.try_as_button()
+    }
+
+    /// Get a `Slider` using its uuid (`None` if there is no such widget, or it isn't a `Slider`).
+    pub fn get_slider(&mut self, uuid: &str) -> Option<&mut Slider> {
+        self.get_widget_from_uuid(uuid)?.try_as_slider()
+    }
+
+    /// Get a `DisplayImage` using its uuid (`None` if there is no such widget, or it isn't a `DisplayImage`).
+    pub fn get_image(&mut self, uuid: &str) -> Option<&mut DisplayImage> {
+        self.get_widget_from_uuid(uuid)?.try_as_image()
+    }
+
+    /// Get a `Checkbox` using its uuid (`None` if there is no such widget, or it isn't a `Checkbox`).
+    pub fn get_checkbox(&mut self, uuid: &str) -> Option<&mut Checkbox> {
+        self.get_widget_from_uuid(uuid)?.try_as_checkbox()
+    }
+
+    /// Get a `WidgetRow` using its uuid (`None` if there is no such widget, or it isn't a `WidgetRow`).
+    pub fn get_widget_row(&mut self, uuid: &str) -> Option<&mut WidgetRow> {
+        self.get_widget_from_uuid(uuid)?.try_as_widget_row()
     }
 }
 
 // WIDGETS
 impl Window {
     /// Push multiple widgets to the window.
-    pub fn push_widgets(&mut self, widgets: &mut Vec<Widget>) -> &mut Self {
-        if widgets.len() < 1 {
+    pub fn push_widgets(&mut self, widgets: &mut [Widget]) -> &mut Self {
+        if widgets.is_empty() {
             return self;
         }
 
@@ -813,47 +1046,59 @@ impl Window {
         self
     }
 
-    fn convert_widget(&mut self, widget: &mut Widget, idx: usize) {
-        if let Widget::Button(ref mut widget) = widget {
-            let obj = self.get_widget(idx).as_button();
-            widget.pressed = obj.pressed;
-            widget.hovering = obj.hovering;
-            widget.is_just_pressed = obj.is_just_pressed;
-        } else if let Widget::Slider(ref mut widget) = widget {
-            let obj = self.get_widget(idx).as_slider();
-            widget.pressed = obj.pressed;
-            widget.hovering = obj.hovering;
-            widget.value = obj.value;
-        } else if let Widget::Checkbox(ref mut widget) = widget {
-            let obj = self.get_widget(idx).as_checkbox();
-            widget.pressed = obj.pressed;
-            widget.hovering = obj.hovering;
-            widget.value = obj.value;
-            widget.is_just_pressed = obj.is_just_pressed;
+    /// Push a single widget to the window.
+    ///
+    /// The widget's ID comes from its kind + label (within the current `push_id` scope),
+    /// and last frame's state for that ID is carried over to it.
+    pub fn push(&mut self, widget: &mut Widget) -> usize {
+        let seed = self.id_seed();
+        let idx = push_with_id(&mut self.widgets, &self.frame_pushed, seed, widget);
+        self.frame_pushed.push(widget.clone());
+        self.placements.push(Placement {
+            align: self.next_align.take().unwrap_or(self.layout.align),
+            indent: self.indent,
+            space: std::mem::take(&mut self.next_space),
+            same_line: std::mem::take(&mut self.next_same_line),
+        });
+        if std::mem::take(&mut self.focus_here) {
+            self.focus = Some(self.widgets[idx].id());
         }
+
+        idx
     }
 
-    // Push a single widget to the window.
-    pub fn push(&mut self, widget: &mut Widget) -> usize {
-        let mut idx = self.frame_pushed.len();
+    /// Push an ID scope: widgets pushed until the matching `pop_id()` get IDs unique to `id`.
+    ///
+    /// Use this to tell apart widgets with the same label, e.g. inside loops.
+    /// ```no_run
+    /// # use amberwindow::*;
+    /// # use macroquad::prelude::*;
+    /// # let mut windows = WindowManager::new();
+    /// # let win = windows.begin("window").unwrap();
+    /// # let enemies = [(); 3];
+    /// for i in 0..enemies.len() {
+    ///     win.push_id(&i);
+    ///     win.Checkbox("Alive", true);
+    ///     win.pop_id();
+    /// }
+    /// ```
+    pub fn push_id<T: std::hash::Hash + ?Sized>(&mut self, id: &T) -> &mut Self {
+        let seed = hash_id(self.id_seed(), id);
+        self.id_stack.push(seed);
+        self
+    }
 
-        if self.widgets.len() < 1 || self.widgets.len() - 1 < idx {
-            self.widgets.push(widget.clone());
-        } else if matches!(widget.clone(), self_Clone) && widget.equate(&mut self.get_widget(idx)) {
-            self.convert_widget(widget, idx);
+    /// Pop the ID scope pushed by the last `push_id()`.
+    pub fn pop_id(&mut self) -> &mut Self {
+        self.id_stack.pop();
+        self
+    }
 
-            self.widgets[idx] = widget.clone();
-        } else {
-            self.convert_widget(widget, idx);
-            if self.widgets.len() >= idx {
-                self.widgets[idx] = widget.clone();
-            } else {
-                self.widgets.push(widget.clone());
-            }
+    fn id_seed(&self) -> u64 {
+        match self.id_stack.last() {
+            Some(seed) => *seed,
+            None => hash_id(0, &self.id),
         }
-        self.frame_pushed.push(widget.clone());
-
-        idx
     }
 
     /// Push a `Text` widget to the window. Returns the index and a CLONE of the object.
@@ -898,11 +1143,8 @@ impl Window {
 
     /// Push a `Slider_int` widget to the window. Returns the index and a CLONE of the object.
     pub fn Slider_int(&mut self, min: i32, max: i32, default: Option<i32>, size: Vec2) -> (i32, f32) {
-        let mut default_value: Option<f32> = None;
-        if default_value.is_some() {
-            default_value = Some(default_value.unwrap_or(0.0) as f32);
-        }
-        
+        let default_value = default.map(|default| default as f32);
+
         let mut x = Widget::Slider(Slider::new(
             self.style.font.clone(),
             min as f32,
@@ -937,13 +1179,238 @@ impl Window {
 
     /// Push a `WidgetRow` widget to the window. Returns the index and a CLONE of the object.
     pub fn WidgetRow(&mut self) -> Option<&mut WidgetRow> {
-        let mut x = Widget::WidgetRow(WidgetRow::new(self.style.font.clone(), None, self.rect.w));
+        let mut row = WidgetRow::new(self.style.font.clone(), None, self.rect.w);
+        row.spacing = self.layout.item_spacing;
+        let mut x = Widget::WidgetRow(row);
 
         let idx = self.push(&mut x.clone());
         // (self.widgets.len() - 1, Some(self.get(idx).as_widget_row()))
         Some(self.get(idx).as_widget_row())
     }
 
+    /// Push a column (a vertical `WidgetRow`) to the window, e.g. to nest inside a `WidgetRow`.
+    pub fn WidgetColumn(&mut self) -> Option<&mut WidgetRow> {
+        let mut column = WidgetRow::column(self.style.font.clone(), None, self.rect.w);
+        column.spacing = self.layout.item_spacing;
+        let mut x = Widget::WidgetRow(column);
+
+        let idx = self.push(&mut x);
+        Some(self.get(idx).as_widget_row())
+    }
+
+    /// Push a scroll area of `size` to the window: a column that scrolls the widgets that don't fit in it.
+    pub fn ScrollArea(&mut self, size: Vec2) -> Option<&mut WidgetRow> {
+        let mut area = WidgetRow::scroll_area(self.style.font.clone(), None, size.x, size);
+        area.spacing = self.layout.item_spacing;
+        area.scrollbar_style = self.style.scrollbar.clone();
+        let mut x = Widget::WidgetRow(area);
+
+        let idx = self.push(&mut x);
+        Some(self.get(idx).as_widget_row())
+    }
+
+    /// Push a `CollapsingHeader` to the window: a bar that shows the widgets pushed to it while it is open.
+    ///
+    /// Its open state is kept per ID; push the section's widgets to `body()`, which is `None` while it is closed.
+    pub fn CollapsingHeader(&mut self, label: &str) -> &mut WidgetRow {
+        let header = WidgetRow::collapsing_header(label, self.style.font.clone(), None, self.rect.w);
+        self.push_header(header)
+    }
+
+    /// Push a `TreeNode` to the window: a line that shows the widgets pushed to it (indented) while it is open.
+    pub fn TreeNode(&mut self, label: &str) -> &mut WidgetRow {
+        let node = WidgetRow::tree_node(label, self.style.font.clone(), None, self.rect.w);
+        self.push_header(node)
+    }
+
+    /// Push a `TabBar` to the window: a row of tabs, showing the widgets pushed to the selected one under it.
+    ///
+    /// The selected tab (and the order tabs were dragged into) is kept per ID; add tabs with `Tab` / `TabClosable`
+    /// and push each page's widgets to what they return, which is `None` unless that tab is selected.
+    pub fn TabBar(&mut self, label: &str) -> &mut WidgetRow {
+        let bar = WidgetRow::tab_bar(label, self.style.font.clone(), None, self.content_width());
+        self.push_header(bar)
+    }
+
+    fn push_header(&mut self, mut header: WidgetRow) -> &mut WidgetRow {
+        header.spacing = self.layout.item_spacing;
+        let mut x = Widget::WidgetRow(header);
+
+        let idx = self.push(&mut x);
+        self.get(idx).as_widget_row()
+    }
+
+    /// Push a `TextInput` widget to the window, editing `text`. `hint` is shown while it is empty.
+    pub fn TextInput(&mut self, hint: &str, text: &mut String, width: f32) -> &mut TextInput {
+        let mut x = Widget::TextInput(TextInput::new(hint, text, self.style.font.clone(), width, None));
+
+        let idx = self.push(&mut x);
+        let input = self.get(idx).as_text_input();
+        input.sync(text);
+        input
+    }
+
+    /// Push a multi-line `TextEdit` widget of `size` to the window, editing `text`. `hint` is shown while it is empty.
+    pub fn TextEdit(&mut self, hint: &str, text: &mut String, size: Vec2) -> &mut TextEdit {
+        let mut x = Widget::TextEdit(TextEdit::new(hint, text, self.style.font.clone(), size, None));
+
+        let idx = self.push(&mut x);
+        let edit = self.get(idx).as_text_edit();
+        edit.sync(text);
+        edit
+    }
+
+    /// Push a `NumberField` to the window, changing `value` by dragging (or typing, after a double-click).
+    pub fn DragFloat(&mut self, label: &str, value: &mut f32, width: f32) -> &mut NumberField {
+        let field = NumberField::new(label, *value as f64, self.style.font.clone(), width, None);
+
+        let field = self.push_number_field(field);
+        *value = field.sync(*value as f64) as f32;
+        field
+    }
+
+    /// Push a `NumberField` to the window, changing a whole `value` by dragging (or typing, after a double-click).
+    pub fn DragInt(&mut self, label: &str, value: &mut i32, width: f32) -> &mut NumberField {
+        let mut field = NumberField::new(label, *value as f64, self.style.font.clone(), width, None);
+        field.integer(true).speed(1.0);
+
+        let field = self.push_number_field(field);
+        *value = field.sync(*value as f64) as i32;
+        field
+    }
+
+    /// Push a `NumberField` to the window, typing `value` in (with -/+ buttons).
+    pub fn InputFloat(&mut self, label: &str, value: &mut f32, width: f32) -> &mut NumberField {
+        let mut field = NumberField::new(label, *value as f64, self.style.font.clone(), width, None);
+        field.speed(0.0).step(0.1);
+
+        let field = self.push_number_field(field);
+        *value = field.sync(*value as f64) as f32;
+        field
+    }
+
+    /// Push a `NumberField` to the window, typing a whole `value` in (with -/+ buttons).
+    pub fn InputInt(&mut self, label: &str, value: &mut i32, width: f32) -> &mut NumberField {
+        let mut field = NumberField::new(label, *value as f64, self.style.font.clone(), width, None);
+        field.integer(true).speed(0.0).step(1.0);
+
+        let field = self.push_number_field(field);
+        *value = field.sync(*value as f64) as i32;
+        field
+    }
+
+    /// Push a `Combo` of `width` to the window, picking `selected` out of `items`.
+    pub fn Combo(&mut self, label: &str, selected: &mut usize, items: &[impl AsRef<str>], width: f32) -> &mut Combo {
+        let mut x = Widget::Combo(Combo::new(label, items, *selected, self.style.font.clone(), width, None));
+
+        let idx = self.push(&mut x);
+        let combo = self.get(idx).as_combo();
+        combo.sync(selected);
+        combo
+    }
+
+    /// Push a `ColorEdit` of `width` to the window, editing `color` (with a picker popup opened from its swatch).
+    pub fn ColorEdit(&mut self, label: &str, color: &mut Color, width: f32) -> &mut ColorEdit {
+        let mut x = Widget::ColorEdit(ColorEdit::new(label, *color, self.style.font.clone(), width, None));
+
+        let idx = self.push(&mut x);
+        let edit = self.get(idx).as_color_edit();
+        edit.sync(color);
+        edit
+    }
+
+    /// Push a `ListBox` of `size` to the window, showing `items` and selecting into `selection`
+    /// (`Option<usize>` for one item, `Vec<usize>` for several).
+    pub fn ListBox<S: ListSelection>(
+        &mut self,
+        label: &str,
+        selection: &mut S,
+        items: &[impl AsRef<str>],
+        size: Vec2,
+    ) -> &mut ListBox {
+        let items = items.iter().map(|item| item.as_ref().to_owned()).collect();
+        self.push_list_box(label, selection, items, size)
+    }
+
+    /// Push a `ListBox` of `size` to the window, with `count` items labelled by `item(index)`.
+    pub fn ListBox_with<S: ListSelection>(
+        &mut self,
+        label: &str,
+        selection: &mut S,
+        count: usize,
+        size: Vec2,
+        item: impl FnMut(usize) -> String,
+    ) -> &mut ListBox {
+        let items = (0..count).map(item).collect();
+        self.push_list_box(label, selection, items, size)
+    }
+
+    fn push_list_box<S: ListSelection>(
+        &mut self,
+        label: &str,
+        selection: &mut S,
+        items: Vec<String>,
+        size: Vec2,
+    ) -> &mut ListBox {
+        let mut x = Widget::ListBox(ListBox::new(label, items, S::MULTIPLE, self.style.font.clone(), size, None));
+
+        let idx = self.push(&mut x);
+        let list = self.get(idx).as_list_box();
+        list.sync(selection);
+        list
+    }
+
+    /// Push a `ProgressBar` of `size` to the window, filled to `fraction` (0 to 1). A width of 0 fills the window.
+    pub fn ProgressBar(&mut self, fraction: f32, size: Vec2) -> &mut ProgressBar {
+        let size = self.fill_width(size);
+        let mut x = Widget::ProgressBar(ProgressBar::new(fraction, self.style.font.clone(), size, None));
+
+        let idx = self.push(&mut x);
+        self.get(idx).as_progress_bar()
+    }
+
+    /// Push a `Spinner` of `size` to the window. A width of 0 fills the window (the spinner is centred in it).
+    pub fn Spinner(&mut self, size: Vec2) -> &mut Spinner {
+        let mut x = Widget::Spinner(Spinner::new(self.fill_width(size), None));
+
+        let idx = self.push(&mut x);
+        self.get(idx).as_spinner()
+    }
+
+    /// Push a `Separator` to the window: a line across it (minus the indent), with `label` on it if given.
+    pub fn separator(&mut self, label: Option<&str>) -> &mut Separator {
+        let width = (self.content_width() - self.indent).max(0.0);
+        let mut x = Widget::Separator(Separator::new(label, self.style.font.clone(), width, None));
+
+        let idx = self.push(&mut x);
+        self.get(idx).as_separator()
+    }
+
+    /// Push empty space of `size` to the window (a widget that draws nothing).
+    pub fn dummy(&mut self, size: Vec2) -> &mut Self {
+        self.push(&mut Widget::Dummy(Dummy::new(size, None)));
+        self
+    }
+
+    /// Width inside the window's padding, what widgets pushed with a width of 0 fill.
+    pub(crate) fn content_width(&self) -> f32 {
+        self.rect.w - self.layout.padding.x * 2.0
+    }
+
+    fn fill_width(&self, size: Vec2) -> Vec2 {
+        match size.x <= 0.0 {
+            true => vec2(self.content_width(), size.y),
+            false => size,
+        }
+    }
+
+    fn push_number_field(&mut self, field: NumberField) -> &mut NumberField {
+        let mut x = Widget::NumberField(field);
+
+        let idx = self.push(&mut x);
+        self.get(idx).as_number_field()
+    }
+
     /// Push a `Checkbox` widget to the window. Returns the index and a CLONE of the object.
     pub fn Checkbox(&mut self, text: &str, ticked: bool) -> &mut Checkbox {
         let mut x = Widget::Checkbox(Checkbox::new(
@@ -958,4 +1425,45 @@ impl Window {
         // (self.widgets.len() - 1, self.get(idx).as_checkbox().clone())
         self.get(idx).as_checkbox()
     }
+
+    /// Push a `RadioButton` to the window, setting `value` to `option` when picked.
+    pub fn RadioButton<T: PartialEq + Clone>(&mut self, text: &str, value: &mut T, option: T) -> &mut RadioButton {
+        let idx = self.push_radio_button(text, value, &option);
+        self.get(idx).as_radio_button()
+    }
+
+    /// Push a `RadioButton` per `(label, option)` pair to the window, all binding `value`. Returns whether the choice changed.
+    pub fn RadioGroup<T: PartialEq + Clone>(&mut self, value: &mut T, options: &[(impl AsRef<str>, T)]) -> bool {
+        let idxs: Vec<usize> = options
+            .iter()
+            .map(|(text, option)| self.push_radio_button(text.as_ref(), value, option))
+            .collect();
+
+        // The button picked may come after others that showed the old value.
+        let changed = idxs.iter().any(|idx| self.get(*idx).as_radio_button().changed);
+        if changed {
+            for (idx, (_, option)) in idxs.into_iter().zip(options) {
+                self.get(idx).as_radio_button().selected = *value == *option;
+            }
+        }
+        changed
+    }
+
+    fn push_radio_button<T: PartialEq + Clone>(&mut self, text: &str, value: &mut T, option: &T) -> usize {
+        let mut x = Widget::RadioButton(RadioButton::new(text, self.style.font.clone(), *value == *option, None, None));
+
+        let idx = self.push(&mut x);
+        self.get(idx).as_radio_button().sync(value, option);
+        idx
+    }
+
+    /// Push a custom widget (anything implementing `WidgetImpl`) to the window.
+    ///
+    /// It is laid out, updated, rendered and keeps its state across frames like the built-in widgets.
+    pub fn Custom<W: WidgetImpl>(&mut self, widget: W) -> &mut W {
+        let mut x = Widget::Custom(CustomWidget::new(widget, None));
+
+        let idx = self.push(&mut x);
+        self.get(idx).as_custom()
+    }
 }
@@ -0,0 +1,371 @@
+use std::fmt;
+
+use macroquad::prelude::*;
+
+use super::*;
+
+/// Draw > DrawCommand (A single primitive recorded into a `DrawList`).
+#[derive(Clone, Debug)]
+pub enum DrawCommand {
+    Rectangle {
+        rect: Rect,
+        color: Color,
+    },
+    RectangleLines {
+        rect: Rect,
+        thickness: f32,
+        color: Color,
+    },
+    Triangle {
+        v1: Vec2,
+        v2: Vec2,
+        v3: Vec2,
+        color: Color,
+    },
+    Line {
+        start: Vec2,
+        end: Vec2,
+        thickness: f32,
+        color: Color,
+    },
+    Circle {
+        center: Vec2,
+        radius: f32,
+        color: Color,
+    },
+    CircleLines {
+        center: Vec2,
+        radius: f32,
+        thickness: f32,
+        color: Color,
+    },
+    Text {
+        text: String,
+        position: Vec2,
+        font: Option<Font>,
+        font_size: u16,
+        font_scale: f32,
+        color: Color,
+    },
+    Texture {
+        texture: Texture2D,
+        rect: Rect,
+        color: Color,
+    },
+    /// Clip every following command to `Some(rect)` (or stop clipping with `None`).
+    Clip(Option<Rect>),
+}
+
+/// Draw > DrawList (Retained list of primitives a window drew this frame).
+///
+/// Windows and widgets record into a `DrawList` instead of drawing straight away.
+/// The `WindowManager` then submits every window's list to its `Painter` in z-order.
+/// The list can be inspected, reordered or replayed before (or instead of) being submitted.
+#[derive(Clone, Debug, Default)]
+pub struct DrawList {
+    pub commands: Vec<DrawCommand>,
+    /// Clip rects pushed with `push_clip()` and not popped yet (innermost last).
+    clips: Vec<Rect>,
+    /// Drawn above every window (see `overlay()`).
+    overlay: Option<Box<DrawList>>,
+}
+
+impl DrawList {
+    pub fn new() -> Self {
+        Self {
+            commands: vec![],
+            clips: vec![],
+            overlay: None,
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.commands.clear();
+        self.clips.clear();
+        self.overlay = None;
+    }
+
+    /// A list submitted after every window's list, so it is drawn above all windows (e.g. for popups).
+    /// It isn't clipped to the window.
+    pub fn overlay(&mut self) -> &mut DrawList {
+        self.overlay.get_or_insert_with(Default::default)
+    }
+
+    /// What was drawn into `overlay()` this frame, if anything.
+    pub fn overlay_list(&self) -> Option<&DrawList> {
+        self.overlay.as_deref()
+    }
+
+    pub fn push(&mut self, command: DrawCommand) {
+        self.commands.push(command);
+    }
+
+    pub fn draw_rectangle(&mut self, x: f32, y: f32, w: f32, h: f32, color: Color) {
+        self.push(DrawCommand::Rectangle {
+            rect: Rect::new(x, y, w, h),
+            color,
+        });
+    }
+
+    pub fn draw_rectangle_lines(&mut self, x: f32, y: f32, w: f32, h: f32, thickness: f32, color: Color) {
+        self.push(DrawCommand::RectangleLines {
+            rect: Rect::new(x, y, w, h),
+            thickness,
+            color,
+        });
+    }
+
+    pub fn draw_triangle(&mut self, v1: Vec2, v2: Vec2, v3: Vec2, color: Color) {
+        self.push(DrawCommand::Triangle { v1, v2, v3, color });
+    }
+
+    pub fn draw_line(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, thickness: f32, color: Color) {
+        self.push(DrawCommand::Line {
+            start: vec2(x1, y1),
+            end: vec2(x2, y2),
+            thickness,
+            color,
+        });
+    }
+
+    pub fn draw_circle(&mut self, x: f32, y: f32, radius: f32, color: Color) {
+        self.push(DrawCommand::Circle {
+            center: vec2(x, y),
+            radius,
+            color,
+        });
+    }
+
+    pub fn draw_circle_lines(&mut self, x: f32, y: f32, radius: f32, thickness: f32, color: Color) {
+        self.push(DrawCommand::CircleLines {
+            center: vec2(x, y),
+            radius,
+            thickness,
+            color,
+        });
+    }
+
+    pub fn draw_text_ex(&mut self, text: &str, x: f32, y: f32, params: TextParams) {
+        self.push(DrawCommand::Text {
+            text: text.to_owned(),
+            position: vec2(x, y),
+            font: params.font.cloned(),
+            font_size: params.font_size,
+            font_scale: params.font_scale,
+            color: params.color,
+        });
+    }
+
+    pub fn draw_texture_ex(
+        &mut self,
+        texture: &Texture2D,
+        x: f32,
+        y: f32,
+        color: Color,
+        params: DrawTextureParams,
+    ) {
+        let size = params.dest_size.unwrap_or_else(|| texture.size());
+        self.push(DrawCommand::Texture {
+            texture: texture.clone(),
+            rect: Rect::new(x, y, size.x, size.y),
+            color,
+        });
+    }
+
+    pub fn clip(&mut self, rect: Option<Rect>) {
+        self.push(DrawCommand::Clip(rect));
+    }
+
+    /// Clip everything drawn until the matching `pop_clip()` to `rect`, within the current clip.
+    pub fn push_clip(&mut self, rect: Rect) {
+        let rect = match self.clips.last() {
+            Some(parent) => parent
+                .intersect(rect)
+                .unwrap_or(Rect::new(rect.x, rect.y, 0.0, 0.0)),
+            None => rect,
+        };
+        self.clips.push(rect);
+        self.clip(Some(rect));
+    }
+
+    /// Go back to the clip from before the last `push_clip()`.
+    pub fn pop_clip(&mut self) {
+        self.clips.pop();
+        self.clip(self.clips.last().copied());
+    }
+
+    /// Submit every command to `painter`, in order (not the `overlay()`, which is submitted after every window).
+    pub fn submit(&self, painter: &mut dyn Painter) {
+        for command in self.commands.iter() {
+            match command {
+                DrawCommand::Rectangle { rect, color } => {
+                    painter.draw_rectangle(rect.x, rect.y, rect.w, rect.h, *color)
+                }
+                DrawCommand::RectangleLines {
+                    rect,
+                    thickness,
+                    color,
+                } => painter.draw_rectangle_lines(rect.x, rect.y, rect.w, rect.h, *thickness, *color),
+                DrawCommand::Triangle { v1, v2, v3, color } => {
+                    painter.draw_triangle(*v1, *v2, *v3, *color)
+                }
+                DrawCommand::Line {
+                    start,
+                    end,
+                    thickness,
+                    color,
+                } => painter.draw_line(start.x, start.y, end.x, end.y, *thickness, *color),
+                DrawCommand::Circle {
+                    center,
+                    radius,
+                    color,
+                } => painter.draw_circle(center.x, center.y, *radius, *color),
+                DrawCommand::CircleLines {
+                    center,
+                    radius,
+                    thickness,
+                    color,
+                } => painter.draw_circle_lines(center.x, center.y, *radius, *thickness, *color),
+                DrawCommand::Text {
+                    text,
+                    position,
+                    font,
+                    font_size,
+                    font_scale,
+                    color,
+                } => painter.draw_text_ex(
+                    text,
+                    position.x,
+                    position.y,
+                    TextParams {
+                        font: font.as_ref(),
+                        font_size: *font_size,
+                        font_scale: *font_scale,
+                        color: *color,
+                        ..Default::default()
+                    },
+                ),
+                DrawCommand::Texture {
+                    texture,
+                    rect,
+                    color,
+                } => painter.draw_texture_ex(
+                    texture,
+                    rect.x,
+                    rect.y,
+                    *color,
+                    DrawTextureParams {
+                        dest_size: Some(rect.size()),
+                        ..Default::default()
+                    },
+                ),
+                DrawCommand::Clip(rect) => painter.set_clip(*rect),
+            }
+        }
+    }
+}
+
+/// One command per line, in a stable text format (used for snapshot tests).
+impl fmt::Display for DrawList {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for command in self.commands.iter() {
+            writeln!(f, "{}", command)?;
+        }
+        if let Some(overlay) = &self.overlay {
+            writeln!(f, "overlay")?;
+            write!(f, "{}", overlay)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for DrawCommand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let rect = |r: &Rect| format!("{} {} {} {}", r.x, r.y, r.w, r.h);
+        let point = |p: &Vec2| format!("{} {}", p.x, p.y);
+        let color = |c: &Color| {
+            let [r, g, b, a]: [u8; 4] = (*c).into();
+            format!("#{:02x}{:02x}{:02x}{:02x}", r, g, b, a)
+        };
+
+        match self {
+            DrawCommand::Rectangle { rect: r, color: c } => {
+                write!(f, "rect {} {}", rect(r), color(c))
+            }
+            DrawCommand::RectangleLines {
+                rect: r,
+                thickness,
+                color: c,
+            } => write!(f, "rect_lines {} {} {}", rect(r), thickness, color(c)),
+            DrawCommand::Triangle {
+                v1,
+                v2,
+                v3,
+                color: c,
+            } => write!(
+                f,
+                "triangle {} {} {} {}",
+                point(v1),
+                point(v2),
+                point(v3),
+                color(c)
+            ),
+            DrawCommand::Line {
+                start,
+                end,
+                thickness,
+                color: c,
+            } => write!(
+                f,
+                "line {} {} {} {}",
+                point(start),
+                point(end),
+                thickness,
+                color(c)
+            ),
+            DrawCommand::Circle {
+                center,
+                radius,
+                color: c,
+            } => write!(f, "circle {} {} {}", point(center), radius, color(c)),
+            DrawCommand::CircleLines {
+                center,
+                radius,
+                thickness,
+                color: c,
+            } => write!(
+                f,
+                "circle_lines {} {} {} {}",
+                point(center),
+                radius,
+                thickness,
+                color(c)
+            ),
+            DrawCommand::Text {
+                text,
+                position,
+                font,
+                font_size,
+                font_scale,
+                color: c,
+            } => write!(
+                f,
+                "text {:?} {} {} {} {} {}",
+                text,
+                point(position),
+                font_size,
+                font_scale,
+                match font {
+                    Some(_) => "custom_font",
+                    None => "default_font",
+                },
+                color(c)
+            ),
+            DrawCommand::Texture {
+                rect: r, color: c, ..
+            } => write!(f, "texture {} {}", rect(r), color(c)),
+            DrawCommand::Clip(Some(r)) => write!(f, "clip {}", rect(r)),
+            DrawCommand::Clip(None) => write!(f, "clip none"),
+        }
+    }
+}
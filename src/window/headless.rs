@@ -0,0 +1,262 @@
+use std::cell::RefCell;
+use std::collections::HashSet;
+
+use macroquad::prelude::*;
+
+use super::*;
+
+/// Backend > HeadlessBackend (Scripted input + recorded rendering, for tests).
+///
+/// Needs no window, GL context or GPU, so a `WindowManager` running on it works under `cargo test`.
+/// Input is scripted frame by frame; `next_frame()` clears the "pressed"/"released" events
+/// and typed characters, and moves the clock on by one frame (1/60 s).
+/// Everything painted is recorded into `drawn` (also cleared by `next_frame()`).
+///
+/// # Example
+///
+/// ```
+/// use amberwindow::*;
+/// use macroquad::prelude::*;
+///
+/// let mut windows = WindowManager::with_backend(HeadlessBackend::new(800., 600.));
+/// let frame = |windows: &mut WindowManager<HeadlessBackend>| {
+///     windows.begin("window");
+///     windows.end_windows();
+///     windows.backend.next_frame();
+/// };
+///
+/// // Drag the window by its title bar.
+/// windows.backend.move_mouse(vec2(100., 10.));
+/// windows.backend.press_mouse(MouseButton::Left);
+/// frame(&mut windows);
+/// frame(&mut windows);
+/// windows.backend.move_mouse(vec2(150., 60.));
+/// frame(&mut windows);
+///
+/// assert_eq!(windows.windows[0].rect.x, 50.);
+/// ```
+#[derive(Clone, Debug)]
+pub struct HeadlessBackend {
+    pub screen_size: Vec2,
+    pub drawn: DrawList,
+    mouse_position: Vec2,
+    mouse_wheel: Vec2,
+    mouse_down: HashSet<MouseButton>,
+    mouse_pressed: HashSet<MouseButton>,
+    mouse_released: HashSet<MouseButton>,
+    keys_down: HashSet<KeyCode>,
+    keys_pressed: HashSet<KeyCode>,
+    keys_released: HashSet<KeyCode>,
+    chars_pressed: Vec<char>,
+    time: f64,
+    clipboard: RefCell<Option<String>>,
+}
+
+impl HeadlessBackend {
+    /// Create a headless backend with a virtual screen of `width` x `height`.
+    pub fn new(width: f32, height: f32) -> Self {
+        Self {
+            screen_size: vec2(width, height),
+            drawn: DrawList::new(),
+            mouse_position: Vec2::ZERO,
+            mouse_wheel: Vec2::ZERO,
+            mouse_down: HashSet::new(),
+            mouse_pressed: HashSet::new(),
+            mouse_released: HashSet::new(),
+            keys_down: HashSet::new(),
+            keys_pressed: HashSet::new(),
+            keys_released: HashSet::new(),
+            chars_pressed: vec![],
+            time: 0.0,
+            clipboard: RefCell::new(None),
+        }
+    }
+
+    /// Clear this frame's pressed/released events, typing, wheel movement and drawing (held buttons/keys stay down).
+    /// The clock moves on by one frame.
+    pub fn next_frame(&mut self) {
+        self.time += 1.0 / 60.0;
+        self.chars_pressed.clear();
+        self.drawn.clear();
+        self.mouse_wheel = Vec2::ZERO;
+        self.mouse_pressed.clear();
+        self.mouse_released.clear();
+        self.keys_pressed.clear();
+        self.keys_released.clear();
+    }
+
+    /// Move the mouse to `position`.
+    pub fn move_mouse(&mut self, position: Vec2) -> &mut Self {
+        self.mouse_position = position;
+        self
+    }
+
+    /// Press (and hold) a mouse button.
+    pub fn press_mouse(&mut self, button: MouseButton) -> &mut Self {
+        if self.mouse_down.insert(button) {
+            self.mouse_pressed.insert(button);
+        }
+        self
+    }
+
+    /// Release a held mouse button.
+    pub fn release_mouse(&mut self, button: MouseButton) -> &mut Self {
+        if self.mouse_down.remove(&button) {
+            self.mouse_released.insert(button);
+        }
+        self
+    }
+
+    /// Scroll the mouse wheel by `delta` this frame.
+    pub fn scroll(&mut self, delta: Vec2) -> &mut Self {
+        self.mouse_wheel += delta;
+        self
+    }
+
+    /// Type `text` this frame (as characters, see `InputSource::chars_pressed`).
+    pub fn type_text(&mut self, text: &str) -> &mut Self {
+        self.chars_pressed.extend(text.chars());
+        self
+    }
+
+    /// Move the clock on by `seconds`.
+    pub fn advance_time(&mut self, seconds: f64) -> &mut Self {
+        self.time += seconds;
+        self
+    }
+
+    /// Press (and hold) a key.
+    pub fn press_key(&mut self, key: KeyCode) -> &mut Self {
+        if self.keys_down.insert(key) {
+            self.keys_pressed.insert(key);
+        }
+        self
+    }
+
+    /// Release a held key.
+    pub fn release_key(&mut self, key: KeyCode) -> &mut Self {
+        if self.keys_down.remove(&key) {
+            self.keys_released.insert(key);
+        }
+        self
+    }
+}
+
+impl InputSource for HeadlessBackend {
+    fn mouse_position(&self) -> Vec2 {
+        self.mouse_position
+    }
+
+    fn is_mouse_button_down(&self, button: MouseButton) -> bool {
+        self.mouse_down.contains(&button)
+    }
+
+    fn is_mouse_button_pressed(&self, button: MouseButton) -> bool {
+        self.mouse_pressed.contains(&button)
+    }
+
+    fn is_mouse_button_released(&self, button: MouseButton) -> bool {
+        self.mouse_released.contains(&button)
+    }
+
+    fn mouse_wheel(&self) -> Vec2 {
+        self.mouse_wheel
+    }
+
+    fn is_key_down(&self, key: KeyCode) -> bool {
+        self.keys_down.contains(&key)
+    }
+
+    fn is_key_pressed(&self, key: KeyCode) -> bool {
+        self.keys_pressed.contains(&key)
+    }
+
+    fn is_key_released(&self, key: KeyCode) -> bool {
+        self.keys_released.contains(&key)
+    }
+
+    fn chars_pressed(&self) -> Vec<char> {
+        self.chars_pressed.clone()
+    }
+
+    fn time(&self) -> f64 {
+        self.time
+    }
+
+    fn clipboard_get(&self) -> Option<String> {
+        self.clipboard.borrow().clone()
+    }
+
+    fn clipboard_set(&self, text: &str) {
+        *self.clipboard.borrow_mut() = Some(text.to_owned());
+    }
+}
+
+impl Painter for HeadlessBackend {
+    fn screen_width(&self) -> f32 {
+        self.screen_size.x
+    }
+
+    fn screen_height(&self) -> f32 {
+        self.screen_size.y
+    }
+
+    /// Fixed-width metrics: every character is `font_size / 2` wide, whatever the font.
+    fn measure_text(
+        &self,
+        text: &str,
+        _font: Option<&Font>,
+        font_size: u16,
+        font_scale: f32,
+    ) -> TextDimensions {
+        let size = font_size as f32 * font_scale;
+        TextDimensions {
+            width: text.chars().count() as f32 * size / 2.0,
+            height: size * 0.75,
+            offset_y: size * 0.75,
+        }
+    }
+
+    fn draw_rectangle(&mut self, x: f32, y: f32, w: f32, h: f32, color: Color) {
+        self.drawn.draw_rectangle(x, y, w, h, color);
+    }
+
+    fn draw_rectangle_lines(&mut self, x: f32, y: f32, w: f32, h: f32, thickness: f32, color: Color) {
+        self.drawn.draw_rectangle_lines(x, y, w, h, thickness, color);
+    }
+
+    fn draw_triangle(&mut self, v1: Vec2, v2: Vec2, v3: Vec2, color: Color) {
+        self.drawn.draw_triangle(v1, v2, v3, color);
+    }
+
+    fn draw_line(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, thickness: f32, color: Color) {
+        self.drawn.draw_line(x1, y1, x2, y2, thickness, color);
+    }
+
+    fn draw_circle(&mut self, x: f32, y: f32, radius: f32, color: Color) {
+        self.drawn.draw_circle(x, y, radius, color);
+    }
+
+    fn draw_circle_lines(&mut self, x: f32, y: f32, radius: f32, thickness: f32, color: Color) {
+        self.drawn.draw_circle_lines(x, y, radius, thickness, color);
+    }
+
+    fn draw_text_ex(&mut self, text: &str, x: f32, y: f32, params: TextParams) {
+        self.drawn.draw_text_ex(text, x, y, params);
+    }
+
+    fn draw_texture_ex(
+        &mut self,
+        texture: &Texture2D,
+        x: f32,
+        y: f32,
+        color: Color,
+        params: DrawTextureParams,
+    ) {
+        self.drawn.draw_texture_ex(texture, x, y, color, params);
+    }
+
+    fn set_clip(&mut self, clip: Option<Rect>) {
+        self.drawn.clip(clip);
+    }
+}
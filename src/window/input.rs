@@ -0,0 +1,138 @@
+use macroquad::prelude::*;
+
+use super::*;
+
+/// Mouse position seen by windows and widgets that don't get the mouse (nothing contains it).
+pub(crate) const NOWHERE: Vec2 = Vec2::splat(f32::NEG_INFINITY);
+
+/// Seconds a key is held before it repeats, then seconds between repeats.
+const REPEAT_DELAY: f64 = 0.4;
+const REPEAT_RATE: f64 = 0.05;
+
+/// Whether either shift key is down.
+pub(crate) fn shift_down(input: &dyn InputSource) -> bool {
+    input.is_key_down(KeyCode::LeftShift) || input.is_key_down(KeyCode::RightShift)
+}
+
+/// Whether either control key (or command key) is down.
+pub(crate) fn ctrl_down(input: &dyn InputSource) -> bool {
+    [KeyCode::LeftControl, KeyCode::RightControl, KeyCode::LeftSuper, KeyCode::RightSuper]
+        .into_iter()
+        .any(|key| input.is_key_down(key))
+}
+
+/// Whether either alt key is down (with control, that's AltGr on Windows: it types characters).
+pub(crate) fn alt_down(input: &dyn InputSource) -> bool {
+    input.is_key_down(KeyCode::LeftAlt) || input.is_key_down(KeyCode::RightAlt)
+}
+
+/// Input > KeyRepeat (Repeats a held key, like typing does, for keys such as Backspace or the arrows).
+#[derive(Clone, Copy, Debug, Default)]
+pub(crate) struct KeyRepeat {
+    /// The key last pressed, and when it next repeats.
+    held: Option<(KeyCode, f64)>,
+}
+
+impl KeyRepeat {
+    /// Whether `key` was pressed this frame, or has been held long enough to repeat.
+    pub(crate) fn pressed(&mut self, input: &dyn InputSource, key: KeyCode) -> bool {
+        let time = input.time();
+        if input.is_key_pressed(key) {
+            self.held = Some((key, time + REPEAT_DELAY));
+            return true;
+        }
+
+        match self.held {
+            Some((held, at)) if held == key && input.is_key_down(key) && time >= at => {
+                self.held = Some((key, at + REPEAT_RATE));
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+/// Whether a key that activates the focused widget (Space/Enter) was pressed.
+pub(crate) fn activate_pressed(input: &dyn InputSource) -> bool {
+    [KeyCode::Space, KeyCode::Enter, KeyCode::KpEnter]
+        .into_iter()
+        .any(|key| input.is_key_pressed(key))
+}
+
+/// Input > UnconsumedInput (Input the UI didn't use, see `WindowManager::unconsumed_input`).
+///
+/// Reads through to the backend, except that mouse buttons/wheel (or keys) read as idle when the UI used them.
+/// The mouse position is always passed through.
+pub struct UnconsumedInput<'a> {
+    pub(crate) input: &'a dyn InputSource,
+    pub(crate) mouse: bool,
+    pub(crate) keyboard: bool,
+}
+
+impl UnconsumedInput<'_> {
+    /// Whether mouse buttons + wheel reach the game.
+    pub fn has_mouse(&self) -> bool {
+        self.mouse
+    }
+
+    /// Whether keys reach the game.
+    pub fn has_keyboard(&self) -> bool {
+        self.keyboard
+    }
+}
+
+impl InputSource for UnconsumedInput<'_> {
+    fn mouse_position(&self) -> Vec2 {
+        self.input.mouse_position()
+    }
+
+    fn is_mouse_button_down(&self, button: MouseButton) -> bool {
+        self.mouse && self.input.is_mouse_button_down(button)
+    }
+
+    fn is_mouse_button_pressed(&self, button: MouseButton) -> bool {
+        self.mouse && self.input.is_mouse_button_pressed(button)
+    }
+
+    fn is_mouse_button_released(&self, button: MouseButton) -> bool {
+        self.mouse && self.input.is_mouse_button_released(button)
+    }
+
+    fn mouse_wheel(&self) -> Vec2 {
+        match self.mouse {
+            true => self.input.mouse_wheel(),
+            false => Vec2::ZERO,
+        }
+    }
+
+    fn is_key_down(&self, key: KeyCode) -> bool {
+        self.keyboard && self.input.is_key_down(key)
+    }
+
+    fn is_key_pressed(&self, key: KeyCode) -> bool {
+        self.keyboard && self.input.is_key_pressed(key)
+    }
+
+    fn is_key_released(&self, key: KeyCode) -> bool {
+        self.keyboard && self.input.is_key_released(key)
+    }
+
+    fn chars_pressed(&self) -> Vec<char> {
+        match self.keyboard {
+            true => self.input.chars_pressed(),
+            false => vec![],
+        }
+    }
+
+    fn time(&self) -> f64 {
+        self.input.time()
+    }
+
+    fn clipboard_get(&self) -> Option<String> {
+        self.input.clipboard_get()
+    }
+
+    fn clipboard_set(&self, text: &str) {
+        self.input.clipboard_set(text);
+    }
+}
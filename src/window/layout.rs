@@ -0,0 +1,209 @@
+use std::ops::Range;
+
+use macroquad::prelude::*;
+
+/// Layout > Align (Where a widget sits across the direction widgets are stacked in).
+///
+/// In a window (stacked top to bottom) `Start` is left and `End` is right.
+/// In a row (stacked left to right) `Start` is the top and `End` the bottom.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Align {
+    #[default]
+    Start,
+    Center,
+    End,
+}
+
+/// Layout > Direction (Which way a `WidgetRow` stacks its widgets).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Direction {
+    /// Left to right (a row).
+    #[default]
+    Horizontal,
+    /// Top to bottom (a column).
+    Vertical,
+}
+
+/// Layout > AutoSize (How a window's size follows its content).
+///
+/// Content that doesn't fit in the window is scrolled.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum AutoSize {
+    /// Never resize to content.
+    Off,
+    /// Grow to fit the content (up to the edge of the screen), but never shrink.
+    #[default]
+    Grow,
+    /// Always be exactly as big as the content (up to the edge of the screen).
+    Fit,
+}
+
+/// Layout > Layout (Spacing, padding, alignment and sizing of a window's widgets).
+#[derive(Clone, Copy, Debug)]
+pub struct Layout {
+    /// Space between widgets.
+    pub item_spacing: f32,
+    /// Space between the window's edges (below the title bar) and its widgets.
+    pub padding: Vec2,
+    /// Alignment of widgets pushed without `Window::align()`.
+    pub align: Align,
+    pub auto_size: AutoSize,
+    /// Pixels scrolled per step of the mouse wheel.
+    pub scroll_speed: f32,
+    /// How far `Window::indent()` moves widgets right.
+    pub indent_spacing: f32,
+}
+
+impl Default for Layout {
+    fn default() -> Self {
+        Self {
+            item_spacing: 5.0,
+            padding: vec2(7.0, 5.0),
+            align: Align::Start,
+            auto_size: AutoSize::Grow,
+            scroll_speed: 20.0,
+            indent_spacing: 16.0,
+        }
+    }
+}
+
+/// Size taken up by widgets of `sizes` stacked `spacing` apart (vertically, or horizontally).
+pub(crate) fn content_size(sizes: &[Vec2], spacing: f32, vertical: bool) -> Vec2 {
+    let mut main = 0f32;
+    let mut cross = 0f32;
+
+    for size in sizes.iter() {
+        let (size_main, size_cross) = match vertical {
+            true => (size.y, size.x),
+            false => (size.x, size.y),
+        };
+        main += size_main + spacing;
+        cross = cross.max(size_cross);
+    }
+    main = (main - spacing).max(0.0);
+
+    match vertical {
+        true => vec2(cross, main),
+        false => vec2(main, cross),
+    }
+}
+
+/// Give each of `sizes` a rect, stacked from `origin` `spacing` apart (vertically, or horizontally).
+///
+/// Each widget is aligned by `align` within `cross`, the space available across the stack.
+pub(crate) fn stack(origin: Vec2, sizes: &[Vec2], align: Align, spacing: f32, cross: f32, vertical: bool) -> Vec<Rect> {
+    let mut main = 0.0;
+    let mut rects = Vec::with_capacity(sizes.len());
+
+    for size in sizes.iter() {
+        let (size_main, size_cross) = match vertical {
+            true => (size.y, size.x),
+            false => (size.x, size.y),
+        };
+        let offset = match align {
+            Align::Start => 0.0,
+            Align::Center => (cross - size_cross) / 2.0,
+            Align::End => cross - size_cross,
+        };
+
+        let position = match vertical {
+            true => origin + vec2(offset, main),
+            false => origin + vec2(main, offset),
+        };
+        rects.push(Rect::new(position.x, position.y, size.x, size.y));
+
+        main += size_main + spacing;
+    }
+
+    rects
+}
+
+/// Layout > Placement (How a widget pushed to a window is placed, on top of its size).
+///
+/// Set by `Window::align()`, `indent()`, `spacing()` and `same_line()`.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub(crate) struct Placement {
+    pub align: Align,
+    /// How far the widget's line is moved right.
+    pub indent: f32,
+    /// Extra space before the widget (above its line, or to its left if it's on the same line as the previous one).
+    pub space: f32,
+    /// Whether the widget goes to the right of the previous one instead of under it.
+    pub same_line: bool,
+}
+
+/// Split widgets into lines: each widget starts a new line unless it's placed on the same line as the previous one.
+fn lines(count: usize, placements: &[Placement]) -> Vec<Range<usize>> {
+    let mut lines: Vec<Range<usize>> = vec![];
+    for idx in 0..count {
+        let same_line = placements.get(idx).is_some_and(|placement| placement.same_line);
+        match lines.last_mut() {
+            Some(line) if same_line => line.end = idx + 1,
+            _ => lines.push(idx..idx + 1),
+        }
+    }
+    lines
+}
+
+/// Size of the line of widgets `line`, side by side `spacing` apart.
+fn line_size(line: &Range<usize>, sizes: &[Vec2], placements: &[Placement], spacing: f32) -> Vec2 {
+    let mut size = Vec2::ZERO;
+    for idx in line.clone() {
+        if idx > line.start {
+            size.x += spacing + placements.get(idx).map_or(0.0, |placement| placement.space);
+        }
+        size.x += sizes[idx].x;
+        size.y = size.y.max(sizes[idx].y);
+    }
+    size
+}
+
+/// Size taken up by widgets of `sizes` laid out in lines by `flow()`.
+pub(crate) fn flow_size(sizes: &[Vec2], placements: &[Placement], spacing: f32) -> Vec2 {
+    let mut size = Vec2::ZERO;
+    for (n, line) in lines(sizes.len(), placements).iter().enumerate() {
+        let first = placements.get(line.start).copied().unwrap_or_default();
+        let line_size = line_size(line, sizes, placements, spacing);
+        if n > 0 {
+            size.y += spacing;
+        }
+        size.y += first.space + line_size.y;
+        size.x = size.x.max(first.indent + line_size.x);
+    }
+    size
+}
+
+/// Give each of `sizes` a rect: lines from `origin` down, `spacing` apart, widgets on a line `spacing` apart too.
+///
+/// Each line is indented and aligned (within `cross`, the width available) by the `Placement` of its first widget.
+pub(crate) fn flow(origin: Vec2, sizes: &[Vec2], placements: &[Placement], spacing: f32, cross: f32) -> Vec<Rect> {
+    let mut rects = vec![Rect::new(0., 0., 0., 0.); sizes.len()];
+    let mut y = 0.0;
+
+    for (n, line) in lines(sizes.len(), placements).iter().enumerate() {
+        let first = placements.get(line.start).copied().unwrap_or_default();
+        let line_size = line_size(line, sizes, placements, spacing);
+        if n > 0 {
+            y += spacing;
+        }
+        y += first.space;
+
+        let free = cross - first.indent - line_size.x;
+        let mut x = first.indent
+            + match first.align {
+                Align::Start => 0.0,
+                Align::Center => free / 2.0,
+                Align::End => free,
+            };
+        for idx in line.clone() {
+            if idx > line.start {
+                x += spacing + placements.get(idx).map_or(0.0, |placement| placement.space);
+            }
+            rects[idx] = Rect::new(origin.x + x, origin.y + y, sizes[idx].x, sizes[idx].y);
+            x += sizes[idx].x;
+        }
+        y += line_size.y;
+    }
+
+    rects
+}
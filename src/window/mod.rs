@@ -3,6 +3,20 @@
 
 use macroquad::prelude::*;
 use rand::RandomRange;
+mod backend;
+pub use backend::*;
+mod headless;
+pub use headless::*;
+mod input;
+pub use input::*;
+mod draw;
+pub use draw::*;
+mod id;
+use id::*;
+mod layout;
+pub use layout::*;
+mod scroll;
+pub use scroll::*;
 mod widgets;
 pub use widgets::*;
 mod display;
@@ -0,0 +1,192 @@
+use macroquad::prelude::*;
+
+use super::*;
+
+/// Style > Custom scrollbar styling.
+#[derive(Clone, Debug)]
+pub struct ScrollbarStyle {
+    pub width: f32,
+    pub track_color: Color,
+    pub thumb_color: Color,
+    pub hover_thumb_color: Color,
+}
+
+impl Default for ScrollbarStyle {
+    fn default() -> Self {
+        Self {
+            width: 8.0,
+            track_color: Color::new(0.3, 0.3, 0.3, 0.2),
+            thumb_color: Color::new(1.0, 0.7, 0.0, 0.4),
+            hover_thumb_color: Color::new(1.0, 0.7, 0.0, 0.7),
+        }
+    }
+}
+
+/// Scroll > Scroll (Scroll offset + scrollbars of a window or scroll area).
+///
+/// The content is laid out at `viewport.point() - offset`. Scrollbars appear for the
+/// directions the content doesn't fit in, and can be dragged (or clicked to jump).
+#[derive(Clone, Debug, Default)]
+pub struct Scroll {
+    /// How far the content is scrolled (clamped to the content every update).
+    pub offset: Vec2,
+    /// Size of the content, as of the last update.
+    pub content: Vec2,
+    /// The visible region (the area minus scrollbars), as of the last update.
+    pub viewport: Rect,
+    vertical_track: Option<Rect>,
+    horizontal_track: Option<Rect>,
+    dragging: Option<(bool, f32)>,
+    hovered: bool,
+}
+
+impl Scroll {
+    /// Furthest the content can be scrolled.
+    pub fn max_offset(&self) -> Vec2 {
+        (self.content - self.viewport.size()).max(Vec2::ZERO)
+    }
+
+    /// Whether a scrollbar is being dragged.
+    pub fn is_dragging(&self) -> bool {
+        self.dragging.is_some()
+    }
+
+    /// Fit the viewport into `area`, making room for the scrollbars `content` needs, and clamp the offset.
+    /// `corner` is kept free at the end of each scrollbar (e.g. for a window's scale triangle).
+    pub(crate) fn layout(&mut self, area: Rect, content: Vec2, corner: f32, style: &ScrollbarStyle) {
+        let bar = style.width;
+        let mut viewport = area;
+
+        let mut vertical = content.y > viewport.h;
+        let horizontal = content.x > viewport.w - if vertical { bar } else { 0.0 };
+        if horizontal {
+            viewport.h -= bar;
+            vertical = content.y > viewport.h;
+        }
+        if vertical {
+            viewport.w -= bar;
+        }
+
+        self.vertical_track = vertical.then(|| {
+            let end = corner.max(if horizontal { bar } else { 0.0 });
+            Rect::new(area.right() - bar, area.y, bar, (area.h - end).max(0.0))
+        });
+        self.horizontal_track = horizontal.then(|| {
+            let end = corner.max(if vertical { bar } else { 0.0 });
+            Rect::new(area.x, area.bottom() - bar, (area.w - end).max(0.0), bar)
+        });
+
+        self.content = content;
+        self.viewport = viewport;
+        self.offset = self.offset.clamp(Vec2::ZERO, self.max_offset());
+    }
+
+    /// Handle scrollbar presses + drags. Returns whether the scrollbars have the mouse.
+    pub(crate) fn update(&mut self, mouse_position: Vec2, input: &dyn InputSource) -> bool {
+        if !input.is_mouse_button_down(MouseButton::Left) {
+            self.dragging = None;
+        }
+
+        let tracks = [(self.vertical_track, true), (self.horizontal_track, false)];
+
+        if input.is_mouse_button_pressed(MouseButton::Left) {
+            for (track, vertical) in tracks.iter() {
+                let Some(track) = track else { continue };
+                if !track.contains(mouse_position) {
+                    continue;
+                }
+
+                // Grab the thumb where it was pressed, or jump its center to the press.
+                let thumb = self.thumb(*track, *vertical);
+                let grab = match thumb.contains(mouse_position) {
+                    true => along(mouse_position - thumb.point(), *vertical),
+                    false => along(thumb.size(), *vertical) / 2.0,
+                };
+                self.dragging = Some((*vertical, grab));
+            }
+        }
+
+        if let Some((vertical, grab)) = self.dragging {
+            let track = match vertical {
+                true => self.vertical_track,
+                false => self.horizontal_track,
+            };
+            if let Some(track) = track {
+                let thumb = self.thumb(track, vertical);
+                let free = along(track.size(), vertical) - along(thumb.size(), vertical);
+                let position = along(mouse_position - track.point(), vertical) - grab;
+                let amount = match free > 0.0 {
+                    true => (position / free).clamp(0.0, 1.0),
+                    false => 0.0,
+                };
+
+                match vertical {
+                    true => self.offset.y = amount * self.max_offset().y,
+                    false => self.offset.x = amount * self.max_offset().x,
+                }
+            }
+        }
+
+        self.hovered = tracks
+            .iter()
+            .any(|(track, _)| track.is_some_and(|track| track.contains(mouse_position)));
+        self.hovered || self.dragging.is_some()
+    }
+
+    /// Scroll by `delta` (in pixels). Returns what was left over, at the ends of the content.
+    pub(crate) fn scroll_by(&mut self, delta: Vec2) -> Vec2 {
+        let before = self.offset;
+        self.offset = (self.offset + delta).clamp(Vec2::ZERO, self.max_offset());
+        delta - (self.offset - before)
+    }
+
+    pub(crate) fn render(&self, draw: &mut DrawList, style: &ScrollbarStyle) {
+        let thumb_color = match self.hovered || self.dragging.is_some() {
+            true => style.hover_thumb_color,
+            false => style.thumb_color,
+        };
+
+        for (track, vertical) in [(self.vertical_track, true), (self.horizontal_track, false)] {
+            let Some(track) = track else { continue };
+            let thumb = self.thumb(track, vertical);
+
+            draw.draw_rectangle(track.x, track.y, track.w, track.h, style.track_color);
+            draw.draw_rectangle(thumb.x, thumb.y, thumb.w, thumb.h, thumb_color);
+        }
+    }
+
+    /// The thumb inside `track`: sized to the visible part of the content, placed at the offset.
+    fn thumb(&self, track: Rect, vertical: bool) -> Rect {
+        let length = along(track.size(), vertical);
+        let visible = along(self.viewport.size(), vertical);
+        let content = along(self.content, vertical).max(1.0);
+        let max_offset = along(self.max_offset(), vertical);
+
+        let size = (length * visible / content).clamp(length.min(16.0), length);
+        let position = match max_offset > 0.0 {
+            true => (length - size) * along(self.offset, vertical) / max_offset,
+            false => 0.0,
+        };
+
+        match vertical {
+            true => Rect::new(track.x, track.y + position, track.w, size),
+            false => Rect::new(track.x + position, track.y, size, track.h),
+        }
+    }
+}
+
+/// Mouse wheel movement this frame, as a scroll delta in pixels (hold shift to scroll sideways).
+pub(crate) fn wheel_delta(input: &dyn InputSource, speed: f32) -> Vec2 {
+    let mut wheel = input.mouse_wheel();
+    if shift_down(input) {
+        wheel = vec2(wheel.y, wheel.x);
+    }
+    -wheel * speed
+}
+
+fn along(value: Vec2, vertical: bool) -> f32 {
+    match vertical {
+        true => value.y,
+        false => value.x,
+    }
+}
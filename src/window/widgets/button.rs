@@ -1,5 +1,7 @@
 use macroquad::prelude::*;
 
+use super::super::*;
+
 /// Style > Custom Button styling.
 #[derive(Clone, Debug)]
 pub struct ButtonStyle {
@@ -27,18 +29,24 @@ impl Default for ButtonStyle {
 /// # Examples
 ///
 /// ## Render a button
-/// ```
+/// ```no_run
+/// # use amberwindow::*;
+/// # let mut windows = WindowManager::new();
+/// # let widgets = WindowWidget::new();
 /// loop {
-///     if let Some(win) = windows.begin("my_window", &font) {
+///     if let Some(win) = windows.begin("my_window") {
 ///         widgets.Button(win, "hello");
 ///     }
 /// }
 /// ```
 ///
 /// ## Detecting button presses
-///  ```
+/// ```no_run
+/// # use amberwindow::*;
+/// # let mut windows = WindowManager::new();
+/// # let widgets = WindowWidget::new();
 /// loop {
-///     if let Some(win) = windows.begin("my_window", &font) {
+///     if let Some(win) = windows.begin("my_window") {
 ///         if widgets.Button(win, "hello").1.is_just_pressed {
 ///             println!("PRESSED");
 ///         }
@@ -47,9 +55,12 @@ impl Default for ButtonStyle {
 /// ```
 ///
 /// ## Check if button is held
-///  ```
+/// ```no_run
+/// # use amberwindow::*;
+/// # let mut windows = WindowManager::new();
+/// # let widgets = WindowWidget::new();
 /// loop {
-///     if let Some(win) = windows.begin("my_window", &font) {
+///     if let Some(win) = windows.begin("my_window") {
 ///         if widgets.Button(win, "hello").1.pressed {
 ///             println!("BUTTON HELD");
 ///         }
@@ -64,9 +75,13 @@ pub struct Button {
     pub button_rect: Rect,
     pub pressed: bool,
     pub style: ButtonStyle,
-    pub uuid: &'static str,
+    pub uuid: String,
+    pub id: u64,
     pub is_just_pressed: bool,
     pub queue_free: bool,
+    text_width: f32,
+    text_height: f32,
+    font_scale: f32,
 }
 
 impl Button {
@@ -74,15 +89,13 @@ impl Button {
         text: &str,
         font: Option<Font>,
         color: Option<Color>,
-        uuid: Option<&'static str>,
+        uuid: Option<&str>,
     ) -> Self {
         let mut x = Self {
             text: text.to_string(),
-            uuid: uuid.unwrap_or(""),
-            rect: {
-                let dim = measure_text(text, None, 16, 1.0);
-                Rect::new(0.0, 0.0, dim.width * 1.2 + 2.0, 14.0) // dim.height + 4.0
-            },
+            uuid: uuid.unwrap_or_default().to_owned(),
+            id: 0,
+            rect: Rect::new(0.0, 0.0, 0.0, 14.0),
             style: ButtonStyle {
                 font: font.clone(),
                 color: color.unwrap_or(WHITE),
@@ -93,6 +106,9 @@ impl Button {
             pressed: false,
             is_just_pressed: false,
             queue_free: false,
+            text_width: 0.0,
+            text_height: 0.0,
+            font_scale: 1.0,
         };
 
         x.button_rect.w = x.rect.w + 14.;
@@ -101,34 +117,56 @@ impl Button {
         x
     }
 
-    pub fn set_uuid(&mut self, uuid: &'static str) -> &mut Self {
-        self.uuid = uuid;
+    pub fn set_uuid(&mut self, uuid: impl Into<String>) -> &mut Self {
+        self.uuid = uuid.into();
+        self
+    }
+
+    pub fn style(&mut self, style: ButtonStyle) -> &mut Self {
+        self.style = style;
         self
     }
+}
 
-    pub fn update(&mut self, selected: bool, mouse_position: Vec2, mouse_released: bool) {
-        let dim = measure_text(&self.text.to_string(), None, 16, 1f32);
-        self.rect.w = dim.width * 1.2 + 2.0;
-        self.rect.h = 14.0;
+impl WidgetImpl for Button {
+    fn kind(&self) -> &'static str {
+        "Button"
+    }
 
-        self.is_just_pressed = false;
+    fn label(&self) -> String {
+        self.text.clone()
+    }
 
-        self.button_rect.x = self.rect.x - 2.;
-        self.button_rect.y = self.rect.y - 12.;
-        self.button_rect.w = self.rect.w + 7.;
-        self.button_rect.h = self.rect.h + 7.;
+    fn measure(&mut self, ctx: &WidgetContext) -> Vec2 {
+        let dim = ctx.backend.measure_text(&self.text, None, 16, 1f32);
+        let dim_some = ctx.backend.measure_text(&self.text, self.style.font.as_ref(), 16, 1f32);
 
-        if mouse_released && self.hovering && self.pressed && selected {
+        self.text_width = dim.width;
+        self.text_height = dim.height;
+        self.font_scale = dim.height / dim_some.height;
+        vec2(dim.width * 1.2 + 13.0, 21.0)
+    }
+
+    fn update(&mut self, rect: Rect, ctx: &WidgetContext) {
+        self.rect = rect;
+        self.button_rect = rect;
+
+        self.is_just_pressed = false;
+
+        if ctx.mouse_released && self.button_rect.contains(ctx.mouse_position) && self.pressed && ctx.selected {
+            self.is_just_pressed = true;
+        }
+        if ctx.focused && activate_pressed(ctx.backend) {
             self.is_just_pressed = true;
         }
 
-        if !is_mouse_button_down(MouseButton::Left) {
+        if !ctx.backend.is_mouse_button_down(MouseButton::Left) {
             self.pressed = false;
         }
 
-        if self.button_rect.contains(mouse_position) {
+        if self.button_rect.contains(ctx.mouse_position) {
             self.hovering = true;
-            if is_mouse_button_pressed(MouseButton::Left) && selected {
+            if ctx.backend.is_mouse_button_pressed(MouseButton::Left) && ctx.selected {
                 self.pressed = true;
             }
         } else {
@@ -136,16 +174,11 @@ impl Button {
         }
     }
 
-    pub fn render(&mut self) {
-        let dim = measure_text(&self.text.to_string(), None, 16, 1f32);
-        let dim_some = measure_text(&self.text.to_string(), self.style.font.as_ref(), 16, 1f32);
-
-        let height_diff = dim.height / dim_some.height;
-
-        draw_rectangle(
+    fn render(&mut self, draw: &mut DrawList) {
+        draw.draw_rectangle(
             self.button_rect.x,
             self.button_rect.y,
-            self.button_rect.w + 4.,
+            self.button_rect.w,
             self.button_rect.h,
             match (self.hovering, self.pressed) {
                 (true, false) => self.style.hover_bg_color,
@@ -153,22 +186,37 @@ impl Button {
                 _ => self.style.bg_color,
             },
         );
-        draw_text_ex(
+        draw.draw_text_ex(
             self.text.as_str(),
-            f32::floor(self.button_rect.x + self.button_rect.w / 2.0 - dim.width / 2.0 + 2.0),
-            f32::floor(self.button_rect.y + self.button_rect.h / 2.0 + 4.0),
+            f32::floor(self.button_rect.x + self.button_rect.w / 2.0 - self.text_width / 2.0),
+            f32::floor(self.button_rect.y + self.button_rect.h / 2.0 + self.text_height / 2.0),
             TextParams {
                 font: self.style.font.as_ref(),
                 font_size: 16,
                 color: self.style.color,
-                font_scale: height_diff,
+                font_scale: self.font_scale,
                 ..Default::default()
             },
         );
     }
 
-    pub fn style(&mut self, style: ButtonStyle) -> &mut Self {
-        self.style = style;
-        self
+    fn is_active(&self) -> bool {
+        self.pressed
+    }
+
+    fn focusable(&self) -> bool {
+        true
+    }
+
+    fn transfer_state(&mut self, previous: &mut dyn WidgetImpl) {
+        if let Some(previous) = previous.downcast_mut::<Button>() {
+            self.pressed = previous.pressed;
+            self.hovering = previous.hovering;
+            self.is_just_pressed = previous.is_just_pressed;
+        }
+    }
+
+    fn set_button_style(&mut self, style: &ButtonStyle) {
+        self.style = style.clone();
     }
 }
@@ -1,5 +1,7 @@
 use macroquad::prelude::*;
 
+use super::super::*;
+
 /// Widget > Checkbox (Toggled bool value).
 #[derive(Clone, Debug)]
 pub struct Checkbox {
@@ -9,12 +11,14 @@ pub struct Checkbox {
     pub bg_color: Color,
     pub font: Option<Font>,
     pub queue_free: bool,
-    pub uuid: &'static str,
+    pub uuid: String,
+    pub id: u64,
     pub box_rect: Rect,
     pub value: bool,
     pub hovering: bool,
     pub pressed: bool,
     pub is_just_pressed: bool,
+    font_scale: f32,
 }
 impl Checkbox {
     pub fn new(
@@ -22,11 +26,12 @@ impl Checkbox {
         font: Option<Font>,
         ticked: Option<bool>,
         color: Option<Color>,
-        uuid: Option<&'static str>,
+        uuid: Option<&str>,
     ) -> Self {
-        let mut x = Self {
+        Self {
             text: text.to_owned(),
-            uuid: uuid.unwrap_or(""),
+            uuid: uuid.unwrap_or_default().to_owned(),
+            id: 0,
             rect: Rect::new(0., 0., 0., 0.),
             color: color.unwrap_or(WHITE),
             font: font.clone(),
@@ -37,17 +42,12 @@ impl Checkbox {
             pressed: false,
             bg_color: Color::new(1.0, 0.7, 0., 1.0),
             is_just_pressed: false,
-        };
-
-        let dim = measure_text(&x.text, None, 16, 1f32);
-        x.rect.w = dim.width * 1.2 + 7.0 + x.box_rect.w;
-        x.rect.h = x.box_rect.h + 3.0;
-
-        x
+            font_scale: 1.0,
+        }
     }
 
-    pub fn set_uuid(&mut self, uuid: &'static str) -> &mut Self {
-        self.uuid = uuid;
+    pub fn set_uuid(&mut self, uuid: impl Into<String>) -> &mut Self {
+        self.uuid = uuid.into();
         self
     }
 
@@ -55,27 +55,48 @@ impl Checkbox {
         self.text = text;
         self
     }
+}
+
+impl WidgetImpl for Checkbox {
+    fn kind(&self) -> &'static str {
+        "Checkbox"
+    }
+
+    fn label(&self) -> String {
+        self.text.clone()
+    }
+
+    fn measure(&mut self, ctx: &WidgetContext) -> Vec2 {
+        let dim = ctx.backend.measure_text(&self.text, None, 16, 1f32);
+        let dim_some = ctx.backend.measure_text(&self.text, self.font.as_ref(), 16, 1f32);
+
+        self.font_scale = dim.height / dim_some.height;
+        vec2(dim.width * 1.2 + 7.0 + self.box_rect.w, self.box_rect.h + 3.0)
+    }
 
-    pub fn update(&mut self, selected: bool, mouse_position: Vec2, mouse_released: bool) {
-        let dim = measure_text(&self.text, None, 16, 1f32);
-        self.rect.w = dim.width * 1.2 + 7.0 + self.box_rect.w;
-        self.rect.h = self.box_rect.h + 3.0;
-        self.rect.y -= self.box_rect.h;
+    fn update(&mut self, rect: Rect, ctx: &WidgetContext) {
+        self.rect = rect;
+        self.box_rect.x = rect.x;
+        self.box_rect.y = rect.y;
 
         self.is_just_pressed = false;
 
-        if mouse_released && self.hovering && self.pressed && selected {
+        if ctx.mouse_released && self.rect.contains(ctx.mouse_position) && self.pressed && ctx.selected {
+            self.value = !self.value;
+            self.is_just_pressed = true;
+        }
+        if ctx.focused && activate_pressed(ctx.backend) {
             self.value = !self.value;
             self.is_just_pressed = true;
         }
 
-        if !is_mouse_button_down(MouseButton::Left) {
+        if !ctx.backend.is_mouse_button_down(MouseButton::Left) {
             self.pressed = false;
         }
 
-        if self.rect.contains(mouse_position) {
+        if self.rect.contains(ctx.mouse_position) {
             self.hovering = true;
-            if is_mouse_button_pressed(MouseButton::Left) {
+            if ctx.backend.is_mouse_button_pressed(MouseButton::Left) {
                 self.pressed = true;
             }
         } else {
@@ -83,22 +104,15 @@ impl Checkbox {
         }
     }
 
-    pub fn render(&mut self) {
-        let dim = measure_text(&self.text.to_string(), None, 16, 1f32);
-        let dim_some = measure_text(&self.text.to_string(), self.font.as_ref(), 16, 1f32);
-
-        let height_diff = dim.height / dim_some.height;
-        self.rect.w = dim.width * 1.2 + 7.0 + self.box_rect.w;
-        self.rect.h = self.box_rect.h + 3.0;
-
+    fn render(&mut self, draw: &mut DrawList) {
         let bg_color = match self.value {
             true => self.bg_color,
             false => Color::from_vec(self.bg_color.to_vec() - vec4(0., 0., 0., 0.4)),
         };
 
-        draw_rectangle(
-            self.rect.x,
-            self.rect.y,
+        draw.draw_rectangle(
+            self.box_rect.x,
+            self.box_rect.y,
             self.box_rect.w,
             self.box_rect.h,
             match (self.hovering, self.pressed) {
@@ -111,7 +125,7 @@ impl Checkbox {
             },
         );
 
-        draw_text_ex(
+        draw.draw_text_ex(
             self.text.as_str(),
             self.rect.x + self.box_rect.w + 5.0,
             self.rect.y + self.rect.h / 1.5,
@@ -119,9 +133,26 @@ impl Checkbox {
                 font: self.font.as_ref(),
                 font_size: 16,
                 color: self.color,
-                font_scale: height_diff,
+                font_scale: self.font_scale,
                 ..Default::default()
             },
         );
     }
+
+    fn is_active(&self) -> bool {
+        self.pressed
+    }
+
+    fn focusable(&self) -> bool {
+        true
+    }
+
+    fn transfer_state(&mut self, previous: &mut dyn WidgetImpl) {
+        if let Some(previous) = previous.downcast_mut::<Checkbox>() {
+            self.pressed = previous.pressed;
+            self.hovering = previous.hovering;
+            self.value = previous.value;
+            self.is_just_pressed = previous.is_just_pressed;
+        }
+    }
 }
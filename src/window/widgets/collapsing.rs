@@ -0,0 +1,202 @@
+use macroquad::prelude::*;
+
+use super::super::*;
+
+/// Style > Custom CollapsingHeader/TreeNode header styling.
+#[derive(Clone, Debug)]
+pub struct HeaderStyle {
+    pub font: Option<Font>,
+    pub color: Color,
+    pub bg_color: Color,
+    pub hover_bg_color: Color,
+    pub pressed_bg_color: Color,
+    pub arrow_color: Color,
+}
+
+impl HeaderStyle {
+    /// A filled bar, for `CollapsingHeader`s.
+    pub fn bar() -> Self {
+        Self {
+            font: None,
+            color: WHITE,
+            bg_color: Color::new(1.0, 0.7, 0.0, 0.3),
+            hover_bg_color: Color::new(1.0, 0.7, 0.0, 0.45),
+            pressed_bg_color: Color::new(1.0, 0.7, 0.0, 0.6),
+            arrow_color: WHITE,
+        }
+    }
+
+    /// Just the arrow and label (with a highlight under the mouse), for `TreeNode`s.
+    pub fn plain() -> Self {
+        Self {
+            bg_color: BLANK,
+            hover_bg_color: Color::new(1.0, 1.0, 1.0, 0.1),
+            pressed_bg_color: Color::new(1.0, 1.0, 1.0, 0.2),
+            arrow_color: Color::new(1.0, 0.7, 0.0, 1.0),
+            ..Self::bar()
+        }
+    }
+}
+
+/// Layout > HeaderKind (How a `WidgetRow` with a `Header` looks and indents).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HeaderKind {
+    /// A full-width bar heading a section.
+    CollapsingHeader,
+    /// A line of a tree, its children indented further.
+    TreeNode,
+}
+
+/// Widget > Header (Clickable line that opens and closes a `WidgetRow`'s widgets).
+///
+/// Set on the column made by `WidgetRow::collapsing_header()` / `WidgetRow::tree_node()`:
+/// the row's widgets are shown (indented) under the header, and are only laid out and updated while it is open.
+/// Click the header (or press Space/Enter, or Right/Left while it is focused) to open/close it.
+#[derive(Clone, Debug)]
+pub struct Header {
+    pub label: String,
+    pub kind: HeaderKind,
+    pub open: bool,
+    /// How far the row's widgets are moved right.
+    pub indent: f32,
+    pub style: HeaderStyle,
+    /// The header line (the top of the row).
+    pub rect: Rect,
+    pub hovering: bool,
+    pub pressed: bool,
+    /// Whether the header was opened or closed this frame.
+    pub toggled: bool,
+    /// Not carried over from a previous frame yet (`default_open` only applies then).
+    fresh: bool,
+    text_size: Vec2,
+    font_scale: f32,
+}
+
+impl Header {
+    pub fn new(label: &str, kind: HeaderKind, font: Option<Font>) -> Self {
+        let (indent, style) = match kind {
+            HeaderKind::CollapsingHeader => (8.0, HeaderStyle::bar()),
+            HeaderKind::TreeNode => (16.0, HeaderStyle::plain()),
+        };
+
+        Self {
+            label: label.to_owned(),
+            kind,
+            open: false,
+            indent,
+            style: HeaderStyle { font, ..style },
+            rect: Rect::new(0., 0., 0., 0.),
+            hovering: false,
+            pressed: false,
+            toggled: false,
+            fresh: true,
+            text_size: Vec2::ZERO,
+            font_scale: 1.0,
+        }
+    }
+
+    /// Open the header if this is the first frame it is shown.
+    pub(crate) fn default_open(&mut self, open: bool) {
+        if self.fresh {
+            self.open = open;
+        }
+    }
+
+    pub(crate) fn measure(&mut self, ctx: &WidgetContext) -> Vec2 {
+        let dim = ctx.backend.measure_text(&self.label, None, 16, 1f32);
+        let dim_some = ctx.backend.measure_text(&self.label, self.style.font.as_ref(), 16, 1f32);
+
+        self.font_scale = dim.height / dim_some.height;
+        self.text_size = vec2(dim.width, dim.height);
+        let height = self.height();
+        vec2(height + dim.width + 8.0, height)
+    }
+
+    /// Height of the header line (as of the last `measure()`).
+    pub(crate) fn height(&self) -> f32 {
+        self.text_size.y + 8.0
+    }
+
+    pub(crate) fn update(&mut self, rect: Rect, ctx: &WidgetContext) {
+        self.rect = rect;
+        self.toggled = false;
+        let input = ctx.backend;
+
+        if ctx.mouse_released && self.rect.contains(ctx.mouse_position) && self.pressed && ctx.selected {
+            self.set_open(!self.open);
+        }
+        if ctx.focused {
+            if activate_pressed(input) {
+                self.set_open(!self.open);
+            } else if input.is_key_pressed(KeyCode::Right) {
+                self.set_open(true);
+            } else if input.is_key_pressed(KeyCode::Left) {
+                self.set_open(false);
+            }
+        }
+
+        if !input.is_mouse_button_down(MouseButton::Left) {
+            self.pressed = false;
+        }
+        self.hovering = self.rect.contains(ctx.mouse_position);
+        if self.hovering && input.is_mouse_button_pressed(MouseButton::Left) {
+            self.pressed = true;
+        }
+    }
+
+    pub(crate) fn set_open(&mut self, open: bool) {
+        if open != self.open {
+            self.open = open;
+            self.toggled = true;
+        }
+    }
+
+    pub(crate) fn render(&self, draw: &mut DrawList) {
+        let rect = self.rect;
+        let bg_color = match (self.hovering, self.pressed) {
+            (_, true) => self.style.pressed_bg_color,
+            (true, _) => self.style.hover_bg_color,
+            _ => self.style.bg_color,
+        };
+        draw.draw_rectangle(rect.x, rect.y, rect.w, rect.h, bg_color);
+
+        // ARROW (right when closed, down when open)
+        let center = vec2(rect.x + rect.h / 2.0, rect.center().y);
+        let size = rect.h / 4.0;
+        let (v1, v2, v3) = match self.open {
+            true => (
+                center + vec2(-size, -size / 2.0),
+                center + vec2(size, -size / 2.0),
+                center + vec2(0.0, size),
+            ),
+            false => (
+                center + vec2(-size / 2.0, -size),
+                center + vec2(-size / 2.0, size),
+                center + vec2(size, 0.0),
+            ),
+        };
+        draw.draw_triangle(v1, v2, v3, self.style.arrow_color);
+
+        draw.draw_text_ex(
+            &self.label,
+            f32::floor(rect.x + rect.h),
+            f32::floor(rect.center().y + self.text_size.y / 2.0),
+            TextParams {
+                font: self.style.font.as_ref(),
+                font_size: 16,
+                color: self.style.color,
+                font_scale: self.font_scale,
+                ..Default::default()
+            },
+        );
+    }
+
+    /// Carry the open state (and hover/press) over from last frame's header.
+    pub(crate) fn transfer_state(&mut self, previous: &Header) {
+        self.open = previous.open;
+        self.hovering = previous.hovering;
+        self.pressed = previous.pressed;
+        self.toggled = previous.toggled;
+        self.fresh = false;
+    }
+}
@@ -0,0 +1,620 @@
+use macroquad::prelude::*;
+
+use super::super::*;
+
+/// Space between the swatch, the fields and the label.
+const SPACING: f32 = 2.0;
+/// Space between the popup's edges and its parts.
+const PADDING: f32 = 6.0;
+/// Size of the saturation/value square.
+const SQUARE: f32 = 150.0;
+/// Width of the hue and alpha bars.
+const BAR: f32 = 16.0;
+/// Size of a colour of the recent palette.
+const CHIP: f32 = 16.0;
+/// Most colours kept in the recent palette.
+const MAX_RECENT: usize = 10;
+/// Cells per side the saturation/value square is drawn with (and slices the bars are drawn with).
+const STEPS: usize = 15;
+
+/// Style > Custom ColorEdit styling.
+#[derive(Clone, Debug)]
+pub struct ColorEditStyle {
+    pub font: Option<Font>,
+    pub color: Color,
+    /// Background of the popup.
+    pub popup_bg_color: Color,
+    /// Border of the swatch and the popup's parts.
+    pub border_color: Color,
+    /// Markers of the picked colour on the square and the bars.
+    pub marker_color: Color,
+    /// The two colours of the checkerboard shown behind transparent colours.
+    pub checker_colors: (Color, Color),
+}
+
+impl Default for ColorEditStyle {
+    fn default() -> Self {
+        Self {
+            font: None,
+            color: WHITE,
+            popup_bg_color: Color::from_hex(0x202224),
+            border_color: Color::new(1.0, 1.0, 1.0, 0.3),
+            marker_color: WHITE,
+            checker_colors: (Color::new(0.4, 0.4, 0.4, 1.0), Color::new(0.6, 0.6, 0.6, 1.0)),
+        }
+    }
+}
+
+/// Part of a `ColorEdit` taking the mouse (or keyboard).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Part {
+    /// One of the R/G/B/A fields.
+    Field(usize),
+    Square,
+    Hue,
+    Alpha,
+    Hex,
+}
+
+/// Widget > ColorEdit (Edits a `Color`: a swatch, R/G/B/A fields and a picker popup).
+///
+/// Drag (or type into) the 0-255 R/G/B/A fields, or click the swatch (or press Space/Enter while it is focused)
+/// to open the picker: a saturation/value square, hue and alpha bars, a hex field (`#RRGGBB` or `#RRGGBBAA`,
+/// like `Color::from_hex`) and a palette of recently picked colours. Escape or clicking elsewhere closes it.
+///
+/// # Example
+/// ```no_run
+/// # use amberwindow::*;
+/// # use macroquad::prelude::*;
+/// # let mut windows = WindowManager::new();
+/// # let win = windows.begin("window").unwrap();
+/// # let mut style = WindowStyle::default();
+/// if win.ColorEdit("Background", &mut style.bg_color, 180.).changed {
+///     win.style(style.clone());
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct ColorEdit {
+    /// Drawn to the right of the fields.
+    pub label: String,
+    pub color: Color,
+    pub width: f32,
+    /// Whether the picker popup is open.
+    pub open: bool,
+    /// Colours picked recently (newest first), shown in the popup. Picking one sets the colour.
+    pub recent: Vec<Color>,
+    pub rect: Rect,
+    pub style: ColorEditStyle,
+    pub uuid: String,
+    pub id: u64,
+    pub hovering: bool,
+    pub pressed: bool,
+    /// Whether the colour changed this frame.
+    pub changed: bool,
+    /// Hue, saturation and value of the colour (kept apart so hue survives grey colours).
+    hsv: Vec3,
+    fields: [NumberField; 4],
+    hex_input: TextInput,
+    /// The part last clicked: it gets the keys while the widget is focused (and is dragged while the mouse is down).
+    active: Option<Part>,
+    dragging: bool,
+    /// The colour when the popup opened (the new one goes into `recent` if it differs when it closes).
+    opened_with: Color,
+    /// Whether the widget was focused last frame (the popup closes when focus goes).
+    focused: bool,
+    swatch: Rect,
+    popup: Rect,
+    label_size: Vec2,
+    font_scale: f32,
+}
+
+impl ColorEdit {
+    pub fn new(label: &str, color: Color, font: Option<Font>, width: f32, uuid: Option<&str>) -> Self {
+        let field = |channel: f32| {
+            let mut field = NumberField::new("", (channel * 255.0).round() as f64, font.clone(), 0.0, None);
+            field.integer(true).range(0.0, 255.0).speed(1.0);
+            field
+        };
+
+        Self {
+            label: label.to_owned(),
+            color,
+            width,
+            open: false,
+            recent: vec![],
+            rect: Rect::new(0., 0., width, 21.),
+            style: ColorEditStyle {
+                font: font.clone(),
+                ..Default::default()
+            },
+            uuid: uuid.unwrap_or_default().to_owned(),
+            id: 0,
+            hovering: false,
+            pressed: false,
+            changed: false,
+            hsv: rgb_to_hsv(color),
+            fields: [field(color.r), field(color.g), field(color.b), field(color.a)],
+            hex_input: TextInput::new("#RRGGBB", &hex_text(color), font, 100.0, None),
+            active: None,
+            dragging: false,
+            opened_with: color,
+            focused: false,
+            swatch: Rect::default(),
+            popup: Rect::default(),
+            label_size: Vec2::ZERO,
+            font_scale: 1.0,
+        }
+    }
+
+    pub fn set_uuid(&mut self, uuid: impl Into<String>) -> &mut Self {
+        self.uuid = uuid.into();
+        self
+    }
+
+    pub fn style(&mut self, style: ColorEditStyle) -> &mut Self {
+        self.style = style;
+        self
+    }
+
+    /// The colour as hex text (`#RRGGBB`, or `#RRGGBBAA` if it's transparent).
+    pub fn hex(&self) -> String {
+        hex_text(self.color)
+    }
+
+    /// Where the popup's parts are: the square, the hue bar, the alpha bar, the hex field and the palette's first chip.
+    pub fn popup_parts(&self) -> (Rect, Rect, Rect, Rect, Rect) {
+        let (x, y) = (self.popup.x + PADDING, self.popup.y + PADDING);
+        let square = Rect::new(x, y, SQUARE, SQUARE);
+        let hue = Rect::new(square.right() + PADDING, y, BAR, SQUARE);
+        let alpha = Rect::new(hue.right() + PADDING, y, BAR, SQUARE);
+        let hex = Rect::new(x, square.bottom() + PADDING, 100.0, 21.0);
+        let palette = Rect::new(x, hex.bottom() + PADDING, CHIP, CHIP);
+        (square, hue, alpha, hex, palette)
+    }
+
+    /// Keep the widget and `color` in step: last frame's change is written to `color`, else `color` is shown.
+    pub(crate) fn sync(&mut self, color: &mut Color) {
+        if self.changed {
+            *color = self.color;
+        } else {
+            self.color = *color;
+        }
+    }
+
+    fn set_color(&mut self, color: Color) {
+        if color != self.color {
+            self.color = color;
+            self.changed = true;
+        }
+    }
+
+    fn set_hsv(&mut self, hsv: Vec3) {
+        self.hsv = hsv;
+        self.set_color(hsv_to_rgb(hsv, self.color.a));
+    }
+
+    fn open_popup(&mut self, ctx: &WidgetContext) {
+        self.open = true;
+        self.opened_with = self.color;
+        self.layout_popup(ctx.backend);
+        ctx.request_focus();
+    }
+
+    /// Close the popup, remembering the colour picked in it.
+    fn close_popup(&mut self) {
+        self.open = false;
+        if self.active.is_some_and(|part| !matches!(part, Part::Field(_))) {
+            self.active = None;
+        }
+        if self.color != self.opened_with {
+            self.recent.retain(|color| *color != self.color);
+            self.recent.insert(0, self.color);
+            self.recent.truncate(MAX_RECENT);
+        }
+    }
+
+    /// Place the popup under the widget (or above it, if it doesn't fit below).
+    fn layout_popup(&mut self, backend: &dyn Backend) {
+        let width = PADDING * 4.0 + SQUARE + BAR * 2.0;
+        let height = PADDING * 4.0 + SQUARE + 21.0 + CHIP;
+
+        let mut y = self.rect.bottom() + SPACING;
+        if y + height > backend.screen_height() && self.rect.y - SPACING - height >= 0.0 {
+            y = self.rect.y - SPACING - height;
+        }
+        // Keep it on screen when the widget is near the right or bottom edge.
+        let x = self.rect.x.min(backend.screen_width() - width).max(0.0);
+        let y = y.min(backend.screen_height() - height).max(0.0);
+        self.popup = Rect::new(x, y, width, height);
+    }
+
+    /// Lay out the swatch and the R/G/B/A fields in `rect`.
+    fn layout(&mut self, rect: Rect) {
+        self.rect = rect;
+        self.swatch = Rect::new(rect.x, rect.y, rect.h, rect.h);
+        let width = ((self.width - self.swatch.w - SPACING * 4.0) / 4.0).max(rect.h);
+        for (i, field) in self.fields.iter_mut().enumerate() {
+            field.width = width;
+            let x = self.swatch.right() + SPACING + i as f32 * (width + SPACING);
+            field.rect = Rect::new(x, rect.y, width, rect.h);
+        }
+    }
+
+    /// Handle the open popup's mouse and keys.
+    fn update_popup(&mut self, ctx: &WidgetContext) {
+        let input = ctx.backend;
+        let mouse = ctx.mouse_position;
+        let pressed = input.is_mouse_button_pressed(MouseButton::Left);
+        self.layout_popup(input);
+        let (square, hue, alpha, hex, palette) = self.popup_parts();
+
+        if pressed && self.popup.contains(mouse) {
+            ctx.request_focus();
+            let part = [(square, Part::Square), (hue, Part::Hue), (alpha, Part::Alpha), (hex, Part::Hex)]
+                .into_iter()
+                .find(|(rect, _)| rect.contains(mouse));
+            if let Some((_, part)) = part {
+                self.active = Some(part);
+                self.dragging = part != Part::Hex;
+            }
+
+            for (i, color) in self.recent.clone().into_iter().enumerate() {
+                if chip_rect(palette, i).contains(mouse) {
+                    self.hsv = rgb_to_hsv(color);
+                    self.set_color(color);
+                }
+            }
+        }
+
+        if !input.is_mouse_button_down(MouseButton::Left) {
+            self.dragging = false;
+        }
+        if self.dragging {
+            let along = |rect: Rect| ((mouse - rect.point()) / rect.size()).clamp(Vec2::ZERO, Vec2::ONE);
+            match self.active {
+                Some(Part::Square) => {
+                    let at = along(square);
+                    self.set_hsv(vec3(self.hsv.x, at.x, 1.0 - at.y));
+                }
+                Some(Part::Hue) => self.set_hsv(vec3(1.0 - along(hue).y, self.hsv.y, self.hsv.z)),
+                Some(Part::Alpha) => {
+                    let a = 1.0 - along(alpha).y;
+                    self.set_color(Color { a, ..self.color });
+                }
+                _ => {}
+            }
+        }
+
+        // The hex field only has the keys once clicked; a valid colour typed in is taken right away.
+        let hex_ctx = WidgetContext {
+            focused: ctx.focused && self.active == Some(Part::Hex),
+            ..*ctx
+        };
+        self.hex_input.update(hex, &hex_ctx);
+        if self.hex_input.changed || self.hex_input.enter_pressed {
+            if let Some(color) = parse_hex(&self.hex_input.text, self.color.a) {
+                self.hsv = rgb_to_hsv(color);
+                self.set_color(color);
+            }
+        }
+        if self.hex_input.enter_pressed {
+            self.active = None;
+        }
+
+        // Clicking outside the popup (or the swatch) closes it.
+        if pressed && !self.popup.contains(mouse) && !self.swatch.contains(mouse) {
+            self.close_popup();
+        }
+    }
+
+    fn draw_checker(&self, draw: &mut DrawList, rect: Rect) {
+        let (a, b) = self.style.checker_colors;
+        let cell = 5.0;
+        draw.draw_rectangle(rect.x, rect.y, rect.w, rect.h, a);
+        draw.push_clip(rect);
+        let (columns, rows) = ((rect.w / cell).ceil() as usize, (rect.h / cell).ceil() as usize);
+        for row in 0..rows {
+            for column in (row % 2..columns).step_by(2) {
+                draw.draw_rectangle(rect.x + column as f32 * cell, rect.y + row as f32 * cell, cell, cell, b);
+            }
+        }
+        draw.pop_clip();
+    }
+
+    fn render_popup(&mut self, draw: &mut DrawList) {
+        let popup = self.popup;
+        let (square, hue, alpha, _, palette) = self.popup_parts();
+        draw.draw_rectangle(popup.x, popup.y, popup.w, popup.h, self.style.popup_bg_color);
+        draw.draw_rectangle_lines(popup.x, popup.y, popup.w, popup.h, 1.0, self.style.border_color);
+
+        // SATURATION/VALUE SQUARE (saturation left to right, value bottom to top)
+        let cell = SQUARE / STEPS as f32;
+        for row in 0..STEPS {
+            for column in 0..STEPS {
+                let s = (column as f32 + 0.5) / STEPS as f32;
+                let v = 1.0 - (row as f32 + 0.5) / STEPS as f32;
+                let color = hsv_to_rgb(vec3(self.hsv.x, s, v), 1.0);
+                let (x, y) = (square.x + column as f32 * cell, square.y + row as f32 * cell);
+                draw.draw_rectangle(x, y, cell, cell, color);
+            }
+        }
+        let marker = square.point() + vec2(self.hsv.y, 1.0 - self.hsv.z) * square.size();
+        draw.draw_circle_lines(marker.x, marker.y, 4.0, 1.5, self.style.marker_color);
+
+        // HUE AND ALPHA BARS (top to bottom)
+        self.draw_checker(draw, alpha);
+        let slice = SQUARE / STEPS as f32;
+        for i in 0..STEPS {
+            let t = 1.0 - (i as f32 + 0.5) / STEPS as f32;
+            let y = hue.y + i as f32 * slice;
+            draw.draw_rectangle(hue.x, y, hue.w, slice, hsv_to_rgb(vec3(t, 1.0, 1.0), 1.0));
+            draw.draw_rectangle(alpha.x, y, alpha.w, slice, Color { a: t, ..self.color });
+        }
+        let hue_y = hue.y + (1.0 - self.hsv.x) * hue.h;
+        let alpha_y = alpha.y + (1.0 - self.color.a) * alpha.h;
+        draw.draw_rectangle_lines(hue.x - 1.0, hue_y - 2.0, hue.w + 2.0, 4.0, 1.0, self.style.marker_color);
+        draw.draw_rectangle_lines(alpha.x - 1.0, alpha_y - 2.0, alpha.w + 2.0, 4.0, 1.0, self.style.marker_color);
+        for rect in [square, hue, alpha] {
+            draw.draw_rectangle_lines(rect.x, rect.y, rect.w, rect.h, 1.0, self.style.border_color);
+        }
+
+        self.hex_input.render(draw);
+
+        // RECENT COLOURS
+        for (i, color) in self.recent.iter().enumerate() {
+            let chip = chip_rect(palette, i);
+            self.draw_checker(draw, chip);
+            draw.draw_rectangle(chip.x, chip.y, chip.w, chip.h, *color);
+            draw.draw_rectangle_lines(chip.x, chip.y, chip.w, chip.h, 1.0, self.style.border_color);
+        }
+    }
+}
+
+impl WidgetImpl for ColorEdit {
+    fn kind(&self) -> &'static str {
+        "ColorEdit"
+    }
+
+    fn label(&self) -> String {
+        self.label.clone()
+    }
+
+    fn measure(&mut self, ctx: &WidgetContext) -> Vec2 {
+        let dim = ctx.backend.measure_text("Ay", None, 16, 1f32);
+        let dim_some = ctx.backend.measure_text("Ay", self.style.font.as_ref(), 16, 1f32);
+        self.font_scale = dim.height / dim_some.height;
+        for field in self.fields.iter_mut() {
+            field.measure(ctx);
+        }
+        self.hex_input.measure(ctx);
+
+        let label = ctx.backend.measure_text(&self.label, self.style.font.as_ref(), 16, self.font_scale);
+        self.label_size = vec2(label.width, label.height);
+        match self.label.is_empty() {
+            true => vec2(self.width, 21.0),
+            false => vec2(self.width + SPACING * 2.0 + label.width, 21.0),
+        }
+    }
+
+    fn update(&mut self, rect: Rect, ctx: &WidgetContext) {
+        self.layout(rect);
+        self.changed = false;
+        let input = ctx.backend;
+        let was_focused = std::mem::replace(&mut self.focused, ctx.focused);
+        let was_open = self.open;
+        let pressed = ctx.selected && input.is_mouse_button_pressed(MouseButton::Left);
+
+        // The colour may have been changed by its owner: follow it (keeping the hue of greys).
+        if !same_color(hsv_to_rgb(self.hsv, self.color.a), self.color) {
+            let hsv = rgb_to_hsv(self.color);
+            self.hsv = match hsv.y == 0.0 || hsv.z == 0.0 {
+                true => vec3(self.hsv.x, hsv.y, hsv.z),
+                false => hsv,
+            };
+        }
+
+        if ctx.mouse_released || !input.is_mouse_button_down(MouseButton::Left) {
+            self.pressed = false;
+        }
+        self.hovering = self.swatch.contains(ctx.mouse_position);
+        if self.hovering && pressed {
+            self.pressed = true;
+            match self.open {
+                true => self.close_popup(),
+                false => self.open_popup(ctx),
+            }
+        }
+
+        // R/G/B/A: a field gets the keys once it has been clicked.
+        if pressed {
+            if let Some(i) = self.fields.iter().position(|field| field.rect.contains(ctx.mouse_position)) {
+                self.active = Some(Part::Field(i));
+            }
+        }
+        let mut channels = [self.color.r, self.color.g, self.color.b, self.color.a];
+        for (i, field) in self.fields.iter_mut().enumerate() {
+            let field_ctx = WidgetContext {
+                focused: ctx.focused && self.active == Some(Part::Field(i)),
+                ..*ctx
+            };
+            let rect = field.rect;
+            field.update(rect, &field_ctx);
+            if field.changed {
+                channels[i] = field.value as f32 / 255.0;
+            }
+        }
+        let [r, g, b, a] = channels;
+        if self.fields.iter().any(|field| field.changed) {
+            self.set_color(Color::new(r, g, b, a));
+            self.hsv = rgb_to_hsv(self.color);
+        }
+
+        if was_open && self.open {
+            if was_focused && !ctx.focused {
+                self.close_popup();
+            } else {
+                self.update_popup(ctx);
+            }
+        } else if !self.open && ctx.focused && self.active.is_none() && activate_pressed(input) {
+            self.open_popup(ctx);
+        }
+        if was_focused && !ctx.focused {
+            self.active = None;
+        }
+
+        // Show the colour however it changed (except in the part being typed into).
+        for (i, field) in self.fields.iter_mut().enumerate() {
+            if !field.is_editing() {
+                field.value = ([self.color.r, self.color.g, self.color.b, self.color.a][i] * 255.0).round() as f64;
+            }
+        }
+        if self.active != Some(Part::Hex) || !self.open {
+            self.hex_input.text = hex_text(self.color);
+        }
+    }
+
+    fn render(&mut self, draw: &mut DrawList) {
+        // SWATCH (over a checkerboard, for transparent colours)
+        let swatch = self.swatch;
+        self.draw_checker(draw, swatch);
+        draw.draw_rectangle(swatch.x, swatch.y, swatch.w, swatch.h, self.color);
+        let border = match self.hovering || self.pressed || self.open {
+            true => self.style.color,
+            false => self.style.border_color,
+        };
+        draw.draw_rectangle_lines(swatch.x, swatch.y, swatch.w, swatch.h, 1.0, border);
+
+        for field in self.fields.iter_mut() {
+            field.render(draw);
+        }
+
+        // LABEL
+        if !self.label.is_empty() {
+            draw.draw_text_ex(
+                &self.label,
+                f32::floor(self.rect.x + self.width + SPACING * 2.0),
+                f32::floor(self.rect.center().y + self.label_size.y / 2.0),
+                TextParams {
+                    font: self.style.font.as_ref(),
+                    font_size: 16,
+                    color: self.style.color,
+                    font_scale: self.font_scale,
+                    ..Default::default()
+                },
+            );
+        }
+
+        if self.open {
+            self.render_popup(draw.overlay());
+        }
+    }
+
+    fn is_active(&self) -> bool {
+        self.pressed || self.dragging || self.hex_input.pressed || self.fields.iter().any(|field| field.is_active())
+    }
+
+    fn wants_keyboard(&self) -> bool {
+        let typing = self.fields.iter().any(|field| field.wants_keyboard());
+        self.focused && (typing || (self.open && self.active == Some(Part::Hex)))
+    }
+
+    fn focusable(&self) -> bool {
+        true
+    }
+
+    fn popup(&self) -> Option<Rect> {
+        self.open.then_some(self.popup)
+    }
+
+    fn transfer_state(&mut self, previous: &mut dyn WidgetImpl) {
+        if let Some(previous) = previous.downcast_mut::<ColorEdit>() {
+            self.color = previous.color;
+            self.open = previous.open;
+            self.recent = std::mem::take(&mut previous.recent);
+            self.hovering = previous.hovering;
+            self.pressed = previous.pressed;
+            self.changed = previous.changed;
+            self.hsv = previous.hsv;
+            self.fields = previous.fields.clone();
+            self.hex_input = previous.hex_input.clone();
+            self.active = previous.active;
+            self.dragging = previous.dragging;
+            self.opened_with = previous.opened_with;
+            self.focused = previous.focused;
+            self.popup = previous.popup;
+        }
+    }
+}
+
+/// Rect of the `i`th colour of the recent palette, starting at `first`.
+fn chip_rect(first: Rect, i: usize) -> Rect {
+    Rect::new(first.x + i as f32 * (CHIP + 4.0), first.y, CHIP, CHIP)
+}
+
+/// `color` as `#RRGGBB` (or `#RRGGBBAA` if it's transparent).
+fn hex_text(color: Color) -> String {
+    let [r, g, b, a]: [u8; 4] = color.into();
+    match a {
+        255 => format!("#{r:02X}{g:02X}{b:02X}"),
+        _ => format!("#{r:02X}{g:02X}{b:02X}{a:02X}"),
+    }
+}
+
+/// Parse `RRGGBB` (keeping `alpha`) or `RRGGBBAA`, with or without a leading `#`.
+fn parse_hex(text: &str, alpha: f32) -> Option<Color> {
+    let text = text.trim().trim_start_matches('#');
+    // `from_str_radix` would also take a leading `+`.
+    if !text.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+    let value = u32::from_str_radix(text, 16).ok()?;
+    match text.len() {
+        6 => Some(Color { a: alpha, ..Color::from_hex(value) }),
+        8 => Some(Color {
+            a: (value & 0xff) as f32 / 255.0,
+            ..Color::from_hex(value >> 8)
+        }),
+        _ => None,
+    }
+}
+
+/// Whether `a` and `b` are the same colour, give or take rounding.
+fn same_color(a: Color, b: Color) -> bool {
+    (a.to_vec() - b.to_vec()).abs().max_element() < 0.5 / 255.0
+}
+
+/// Hue, saturation and value (all 0 to 1) of `color`.
+fn rgb_to_hsv(color: Color) -> Vec3 {
+    let (r, g, b) = (color.r, color.g, color.b);
+    let max = r.max(g).max(b);
+    let delta = max - r.min(g).min(b);
+
+    let hue = if delta == 0.0 {
+        0.0
+    } else if max == r {
+        ((g - b) / delta).rem_euclid(6.0)
+    } else if max == g {
+        (b - r) / delta + 2.0
+    } else {
+        (r - g) / delta + 4.0
+    };
+    let saturation = if max > 0.0 { delta / max } else { 0.0 };
+    vec3(hue / 6.0, saturation, max)
+}
+
+/// The colour of `hsv` (hue, saturation and value, all 0 to 1), with `alpha`.
+fn hsv_to_rgb(hsv: Vec3, alpha: f32) -> Color {
+    let (h, s, v) = ((hsv.x.rem_euclid(1.0)) * 6.0, hsv.y, hsv.z);
+    let c = v * s;
+    let x = c * (1.0 - (h % 2.0 - 1.0).abs());
+    let (r, g, b) = match h as u32 {
+        0 => (c, x, 0.0),
+        1 => (x, c, 0.0),
+        2 => (0.0, c, x),
+        3 => (0.0, x, c),
+        4 => (x, 0.0, c),
+        _ => (c, 0.0, x),
+    };
+    let m = v - c;
+    Color::new(r + m, g + m, b + m, alpha)
+}
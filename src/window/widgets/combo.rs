@@ -0,0 +1,499 @@
+use macroquad::prelude::*;
+
+use super::super::*;
+
+/// Space between the box's (and the list's) edges and their text.
+const PADDING: f32 = 4.0;
+/// Space between the box and its label, and the box and its list.
+const SPACING: f32 = 2.0;
+
+/// Style > Custom Combo styling.
+#[derive(Clone, Debug)]
+pub struct ComboStyle {
+    pub font: Option<Font>,
+    pub color: Color,
+    pub bg_color: Color,
+    pub hover_bg_color: Color,
+    pub pressed_bg_color: Color,
+    /// Background of the popup list.
+    pub popup_bg_color: Color,
+    /// Behind the item under the mouse (or picked with the arrow keys).
+    pub highlight_color: Color,
+    /// Text of the selected item in the list.
+    pub selected_color: Color,
+}
+
+impl Default for ComboStyle {
+    fn default() -> Self {
+        Self {
+            font: None,
+            color: WHITE,
+            bg_color: Color::new(0.3, 0.3, 0.3, 0.3),
+            hover_bg_color: Color::new(0.2, 0.2, 0.2, 0.3),
+            pressed_bg_color: Color::new(0.4, 0.4, 0.4, 0.4),
+            popup_bg_color: Color::from_hex(0x202224),
+            highlight_color: Color::new(1.0, 0.7, 0.0, 0.3),
+            selected_color: GOLD,
+        }
+    }
+}
+
+/// Widget > Combo (Pick one item from a dropdown list).
+///
+/// Shows the selected item; clicking it opens a list drawn above every window. In the list, Up/Down
+/// (and PageUp/PageDown) move the highlight, Enter picks it and Escape (or clicking elsewhere) closes the list.
+/// Long lists scroll. With `filter(true)` the list has a field on top, and typing narrows the list down.
+///
+/// # Example
+/// ```no_run
+/// # use amberwindow::*;
+/// # use macroquad::prelude::*;
+/// # let mut windows = WindowManager::new();
+/// let mut difficulty = 1;
+/// loop {
+///     if let Some(win) = windows.begin("settings") {
+///         if win.Combo("Difficulty", &mut difficulty, &["Easy", "Normal", "Hard"], 120.).changed {
+///             println!("Now playing on {}", difficulty);
+///         }
+///     }
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct Combo {
+    /// Drawn to the right of the box.
+    pub label: String,
+    pub items: Vec<String>,
+    /// Index of the selected item.
+    pub selected: usize,
+    pub width: f32,
+    /// Most items shown in the list at once (it scrolls past that).
+    pub max_items: usize,
+    /// Show a field on top of the list, narrowing the list to items containing its text.
+    pub filter: bool,
+    /// Whether the list is open.
+    pub open: bool,
+    pub rect: Rect,
+    pub style: ComboStyle,
+    pub scrollbar_style: ScrollbarStyle,
+    pub uuid: String,
+    pub id: u64,
+    pub hovering: bool,
+    pub pressed: bool,
+    /// Whether another item was picked this frame.
+    pub changed: bool,
+    /// Index (into `matches`) of the highlighted item.
+    highlighted: usize,
+    /// Indices of the items shown in the list (all of them, unless filtered).
+    matches: Vec<usize>,
+    filter_input: TextInput,
+    /// Whether the combo was focused last frame (the list closes when focus goes).
+    focused: bool,
+    scroll: Scroll,
+    repeat: KeyRepeat,
+    field: Rect,
+    popup: Rect,
+    item_height: f32,
+    text_height: f32,
+    label_width: f32,
+    font_scale: f32,
+}
+
+impl Combo {
+    pub fn new(
+        label: &str,
+        items: &[impl AsRef<str>],
+        selected: usize,
+        font: Option<Font>,
+        width: f32,
+        uuid: Option<&str>,
+    ) -> Self {
+        Self {
+            label: label.to_owned(),
+            items: items.iter().map(|item| item.as_ref().to_owned()).collect(),
+            selected,
+            width,
+            max_items: 8,
+            filter: false,
+            open: false,
+            rect: Rect::new(0., 0., width, 21.),
+            style: ComboStyle {
+                font: font.clone(),
+                ..Default::default()
+            },
+            scrollbar_style: ScrollbarStyle::default(),
+            uuid: uuid.unwrap_or_default().to_owned(),
+            id: 0,
+            hovering: false,
+            pressed: false,
+            changed: false,
+            highlighted: 0,
+            matches: vec![],
+            filter_input: TextInput::new("Filter", "", font, width, None),
+            focused: false,
+            scroll: Scroll::default(),
+            repeat: KeyRepeat::default(),
+            field: Rect::default(),
+            popup: Rect::default(),
+            item_height: 20.0,
+            text_height: 0.0,
+            label_width: 0.0,
+            font_scale: 1.0,
+        }
+    }
+
+    pub fn set_uuid(&mut self, uuid: impl Into<String>) -> &mut Self {
+        self.uuid = uuid.into();
+        self
+    }
+
+    pub fn style(&mut self, style: ComboStyle) -> &mut Self {
+        self.style = style;
+        self
+    }
+
+    /// Show at most `max_items` items in the list at once.
+    pub fn max_items(&mut self, max_items: usize) -> &mut Self {
+        self.max_items = max_items.max(1);
+        self
+    }
+
+    /// Show a filter field on top of the list.
+    pub fn filter(&mut self, filter: bool) -> &mut Self {
+        self.filter = filter;
+        self
+    }
+
+    /// The selected item's text (`None` if the index is out of range).
+    pub fn selected_item(&self) -> Option<&str> {
+        self.items.get(self.selected).map(String::as_str)
+    }
+
+    /// Keep the widget and `selected` in step: last frame's pick is written to `selected`, else `selected` is shown.
+    pub(crate) fn sync(&mut self, selected: &mut usize) {
+        if self.changed {
+            *selected = self.selected;
+        } else {
+            self.selected = *selected;
+        }
+    }
+
+    fn open_list(&mut self, ctx: &WidgetContext) {
+        self.open = true;
+        self.filter_input.text.clear();
+        self.update_matches();
+        self.highlighted = self.matches.iter().position(|i| *i == self.selected).unwrap_or(0);
+        self.scroll.offset = Vec2::ZERO;
+        self.layout_popup(ctx.backend);
+        self.scroll_to_highlighted();
+        ctx.request_focus();
+    }
+
+    fn pick(&mut self, idx: usize) {
+        if idx != self.selected {
+            self.selected = idx;
+            self.changed = true;
+        }
+        self.open = false;
+    }
+
+    /// Narrow the list down to the items containing the filter's text.
+    fn update_matches(&mut self) {
+        let filter = self.filter_input.text.to_lowercase();
+        self.matches = (0..self.items.len())
+            .filter(|i| filter.is_empty() || self.items[*i].to_lowercase().contains(&filter))
+            .collect();
+        self.highlighted = self.highlighted.min(self.matches.len().saturating_sub(1));
+    }
+
+    /// Place the list under the box (or above it, if it doesn't fit below) and lay out its scrolling.
+    fn layout_popup(&mut self, backend: &dyn Backend) {
+        let filter = match self.filter {
+            true => self.filter_input.rect.h + PADDING,
+            false => 0.0,
+        };
+        let rows = self.matches.len().clamp(1, self.max_items) as f32;
+        let height = filter + rows * self.item_height + PADDING * 2.0;
+
+        let mut y = self.field.bottom() + SPACING;
+        if y + height > backend.screen_height() && self.field.y - SPACING - height >= 0.0 {
+            y = self.field.y - SPACING - height;
+        }
+        self.popup = Rect::new(self.field.x, y, self.field.w, height);
+
+        let list = Rect::new(
+            self.popup.x,
+            self.popup.y + PADDING + filter,
+            self.popup.w,
+            self.popup.h - PADDING * 2.0 - filter,
+        );
+        let content = vec2(0.0, self.matches.len() as f32 * self.item_height);
+        self.scroll.layout(list, content, 0.0, &self.scrollbar_style);
+    }
+
+    fn scroll_to_highlighted(&mut self) {
+        let top = self.highlighted as f32 * self.item_height;
+        let view = self.scroll.viewport.h;
+        let offset = &mut self.scroll.offset.y;
+        if top < *offset {
+            *offset = top;
+        } else if top + self.item_height > *offset + view {
+            *offset = top + self.item_height - view;
+        }
+        self.scroll.offset = self.scroll.offset.clamp(Vec2::ZERO, self.scroll.max_offset());
+    }
+
+    /// Handle the open list's keys and mouse.
+    fn update_list(&mut self, ctx: &WidgetContext) {
+        let input = ctx.backend;
+        let pressed = input.is_mouse_button_pressed(MouseButton::Left);
+
+        if self.filter {
+            let rect = Rect::new(self.popup.x + PADDING, self.popup.y + PADDING, self.popup.w - PADDING * 2.0, 21.0);
+            self.filter_input.width = rect.w;
+            self.filter_input.update(rect, ctx);
+            if self.filter_input.changed {
+                self.highlighted = 0;
+            }
+        }
+        self.update_matches();
+        self.layout_popup(input);
+        let bars = self.scroll.update(ctx.mouse_position, input);
+
+        let rows = (self.scroll.viewport.h / self.item_height).floor().max(1.0) as usize;
+        let last = self.matches.len().saturating_sub(1);
+        let mut moved = true;
+        if self.repeat.pressed(input, KeyCode::Down) {
+            self.highlighted = (self.highlighted + 1).min(last);
+        } else if self.repeat.pressed(input, KeyCode::Up) {
+            self.highlighted = self.highlighted.saturating_sub(1);
+        } else if input.is_key_pressed(KeyCode::PageDown) {
+            self.highlighted = (self.highlighted + rows).min(last);
+        } else if input.is_key_pressed(KeyCode::PageUp) {
+            self.highlighted = self.highlighted.saturating_sub(rows);
+        } else {
+            moved = false;
+        }
+        if moved && ctx.focused {
+            self.scroll_to_highlighted();
+        }
+
+        // The item under the mouse is highlighted, and picked by clicking it.
+        let list = self.scroll.viewport;
+        if list.contains(ctx.mouse_position) && !bars {
+            let row = ((ctx.mouse_position.y - list.y + self.scroll.offset.y) / self.item_height) as usize;
+            if row < self.matches.len() {
+                self.highlighted = row;
+                if pressed {
+                    self.pick(self.matches[row]);
+                }
+            }
+        }
+        if list.contains(ctx.mouse_position) {
+            let left = self.scroll.scroll_by(ctx.scroll_delta.take());
+            ctx.scroll_delta.set(left);
+        }
+
+        if ctx.focused && (input.is_key_pressed(KeyCode::Enter) || input.is_key_pressed(KeyCode::KpEnter)) {
+            if let Some(idx) = self.matches.get(self.highlighted) {
+                self.pick(*idx);
+            }
+        }
+
+        // Clicking outside the list (or the list losing focus, e.g. to Escape) closes it.
+        if pressed && !self.popup.contains(ctx.mouse_position) && !self.field.contains(ctx.mouse_position) {
+            self.open = false;
+        } else if pressed && self.popup.contains(ctx.mouse_position) {
+            ctx.request_focus();
+        }
+    }
+
+    fn draw_text(&self, draw: &mut DrawList, text: &str, x: f32, y: f32, color: Color) {
+        draw.draw_text_ex(
+            text,
+            f32::floor(x),
+            f32::floor(y + self.item_height / 2.0 + self.text_height / 2.0),
+            TextParams {
+                font: self.style.font.as_ref(),
+                font_size: 16,
+                color,
+                font_scale: self.font_scale,
+                ..Default::default()
+            },
+        );
+    }
+
+    fn render_popup(&mut self, draw: &mut DrawList) {
+        let popup = self.popup;
+        draw.draw_rectangle(popup.x, popup.y, popup.w, popup.h, self.style.popup_bg_color);
+        draw.draw_rectangle_lines(popup.x, popup.y, popup.w, popup.h, 1.0, self.style.bg_color);
+
+        if self.filter {
+            self.filter_input.render(draw);
+        }
+
+        let list = self.scroll.viewport;
+        draw.push_clip(list);
+        let first = (self.scroll.offset.y / self.item_height).floor() as usize;
+        let count = (list.h / self.item_height).ceil() as usize + 1;
+        for (row, idx) in self.matches.iter().enumerate().skip(first).take(count) {
+            let y = list.y + row as f32 * self.item_height - self.scroll.offset.y;
+            if row == self.highlighted {
+                draw.draw_rectangle(list.x, y, list.w, self.item_height, self.style.highlight_color);
+            }
+            let color = match *idx == self.selected {
+                true => self.style.selected_color,
+                false => self.style.color,
+            };
+            self.draw_text(draw, &self.items[*idx], list.x + PADDING, y, color);
+        }
+        draw.pop_clip();
+
+        self.scroll.render(draw, &self.scrollbar_style);
+    }
+}
+
+impl WidgetImpl for Combo {
+    fn kind(&self) -> &'static str {
+        "Combo"
+    }
+
+    fn label(&self) -> String {
+        self.label.clone()
+    }
+
+    fn measure(&mut self, ctx: &WidgetContext) -> Vec2 {
+        let dim = ctx.backend.measure_text("Ay", None, 16, 1f32);
+        let dim_some = ctx.backend.measure_text("Ay", self.style.font.as_ref(), 16, 1f32);
+        self.font_scale = dim.height / dim_some.height;
+        self.text_height = dim.height;
+        self.item_height = dim.height + 8.0;
+        self.filter_input.measure(ctx);
+
+        self.label_width = ctx.backend.measure_text(&self.label, self.style.font.as_ref(), 16, self.font_scale).width;
+        match self.label.is_empty() {
+            true => vec2(self.width, 21.0),
+            false => vec2(self.width + SPACING * 2.0 + self.label_width, 21.0),
+        }
+    }
+
+    fn update(&mut self, rect: Rect, ctx: &WidgetContext) {
+        self.rect = rect;
+        self.field = Rect::new(rect.x, rect.y, self.width, rect.h);
+        self.changed = false;
+        let input = ctx.backend;
+        let was_focused = std::mem::replace(&mut self.focused, ctx.focused);
+        let was_open = self.open;
+
+        if ctx.mouse_released || !input.is_mouse_button_down(MouseButton::Left) {
+            self.pressed = false;
+        }
+
+        self.hovering = self.field.contains(ctx.mouse_position);
+        if self.hovering && input.is_mouse_button_pressed(MouseButton::Left) && ctx.selected {
+            self.pressed = true;
+            match self.open {
+                true => self.open = false,
+                false => self.open_list(ctx),
+            }
+        }
+
+        if was_open && self.open {
+            if was_focused && !ctx.focused {
+                self.open = false;
+            } else {
+                self.update_list(ctx);
+            }
+        } else if !self.open && ctx.focused {
+            // Enter, Space or Down opens the focused combo's list.
+            let keys = [KeyCode::Enter, KeyCode::KpEnter, KeyCode::Space, KeyCode::Down];
+            if keys.iter().any(|key| input.is_key_pressed(*key)) {
+                self.open_list(ctx);
+            }
+        }
+
+        // An index past the end is corrected (and written back like a pick).
+        if self.selected >= self.items.len() && !self.items.is_empty() {
+            self.selected = self.items.len() - 1;
+            self.changed = true;
+        }
+    }
+
+    fn render(&mut self, draw: &mut DrawList) {
+        let field = self.field;
+        draw.draw_rectangle(
+            field.x,
+            field.y,
+            field.w,
+            field.h,
+            match (self.pressed || self.open, self.hovering) {
+                (true, _) => self.style.pressed_bg_color,
+                (false, true) => self.style.hover_bg_color,
+                _ => self.style.bg_color,
+            },
+        );
+
+        // Selected item, then an arrow pointing at where the list opens.
+        let arrow = field.h * 0.3;
+        draw.push_clip(Rect::new(field.x, field.y, (field.w - arrow * 2.0 - PADDING).max(0.0), field.h));
+        if let Some(item) = self.selected_item() {
+            let y = field.y + (field.h - self.item_height) / 2.0;
+            self.draw_text(draw, item, field.x + PADDING, y, self.style.color);
+        }
+        draw.pop_clip();
+
+        let center = vec2(field.right() - PADDING - arrow, field.center().y);
+        let (tip, base) = match self.open && self.popup.y < field.y {
+            true => (-arrow / 2.0, arrow / 2.0),
+            false => (arrow / 2.0, -arrow / 2.0),
+        };
+        draw.draw_triangle(
+            center + vec2(-arrow, base),
+            center + vec2(arrow, base),
+            center + vec2(0.0, tip),
+            self.style.color,
+        );
+
+        // LABEL
+        if !self.label.is_empty() {
+            let y = field.y + (field.h - self.item_height) / 2.0;
+            self.draw_text(draw, &self.label, field.right() + SPACING * 2.0, y, self.style.color);
+        }
+
+        if self.open {
+            self.render_popup(draw.overlay());
+        }
+    }
+
+    fn is_active(&self) -> bool {
+        self.pressed || self.scroll.is_dragging() || self.filter_input.pressed
+    }
+
+    fn wants_keyboard(&self) -> bool {
+        self.open && self.focused
+    }
+
+    fn focusable(&self) -> bool {
+        true
+    }
+
+    fn popup(&self) -> Option<Rect> {
+        self.open.then_some(self.popup)
+    }
+
+    fn transfer_state(&mut self, previous: &mut dyn WidgetImpl) {
+        if let Some(previous) = previous.downcast_mut::<Combo>() {
+            self.selected = previous.selected;
+            self.open = previous.open;
+            self.hovering = previous.hovering;
+            self.pressed = previous.pressed;
+            self.changed = previous.changed;
+            self.highlighted = previous.highlighted;
+            self.matches = std::mem::take(&mut previous.matches);
+            self.filter_input = previous.filter_input.clone();
+            self.focused = previous.focused;
+            self.scroll = std::mem::take(&mut previous.scroll);
+            self.repeat = previous.repeat;
+            self.popup = previous.popup;
+        }
+    }
+}
@@ -1,27 +1,33 @@
 use macroquad::prelude::*;
 
+use super::super::*;
+
 /// Widget > DisplayImage (Displays an image on the window).
+///
+/// Images have no label, so images shown conditionally are told apart with `push_id` (or a uuid).
 #[derive(Clone, PartialEq, Debug)]
 pub struct DisplayImage {
     pub rect: Rect,
     pub texture: Texture2D,
     pub color: Color,
     pub queue_free: bool,
-    pub uuid: &'static str
+    pub uuid: String,
+    pub id: u64,
 }
 impl DisplayImage {
-    pub fn new(texture: Option<Texture2D>, size: Vec2, color: Option<Color>, uuid: Option<&'static str>) -> Self {
+    pub fn new(texture: Option<Texture2D>, size: Vec2, color: Option<Color>, uuid: Option<&str>) -> Self {
         Self {
-            texture: texture.unwrap_or(Texture2D::empty()),
+            texture: texture.unwrap_or_else(Texture2D::empty),
             color: color.unwrap_or(WHITE),
-            uuid: uuid.unwrap_or(""),
+            uuid: uuid.unwrap_or_default().to_owned(),
+            id: 0,
             rect: Rect::new(0.,0.,size.x, size.y),
             queue_free: false
         }
     }
 
-    pub fn set_uuid(&mut self, uuid: &'static str) -> &mut Self {
-        self.uuid = uuid;
+    pub fn set_uuid(&mut self, uuid: impl Into<String>) -> &mut Self {
+        self.uuid = uuid.into();
         self
     }
     
@@ -29,18 +35,29 @@ impl DisplayImage {
         self.rect.w = size.x;
         self.rect.h = size.y;
     }
+}
+
+impl WidgetImpl for DisplayImage {
+    fn kind(&self) -> &'static str {
+        "DisplayImage"
+    }
 
-    pub fn update(&mut self, _selected: bool) {}
+    fn measure(&mut self, _ctx: &WidgetContext) -> Vec2 {
+        self.rect.size()
+    }
+
+    fn update(&mut self, rect: Rect, _ctx: &WidgetContext) {
+        self.rect = rect;
+    }
 
-    pub fn render(&self) {
-        // texture: &Texture2D, x: f32, y: f32, color: Color, params: DrawTextureParams
-        draw_texture_ex(
+    fn render(&mut self, draw: &mut DrawList) {
+        draw.draw_texture_ex(
             &self.texture,
             self.rect.x,
             self.rect.y,
             self.color,
             DrawTextureParams {
-                dest_size: Some(vec2(self.rect.w, self.rect.h + 5.0)),
+                dest_size: Some(self.rect.size()),
                 ..Default::default()
             }
         );
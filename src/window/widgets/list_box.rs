@@ -0,0 +1,416 @@
+use macroquad::prelude::*;
+
+use super::super::*;
+
+/// Space between the box's edges and the items' text.
+const PADDING: f32 = 4.0;
+/// Most seconds between the clicks of a double-click.
+const DOUBLE_CLICK: f64 = 0.3;
+
+/// Style > Custom ListBox styling.
+#[derive(Clone, Debug)]
+pub struct ListBoxStyle {
+    pub font: Option<Font>,
+    pub color: Color,
+    pub bg_color: Color,
+    /// Behind the item under the mouse.
+    pub hover_color: Color,
+    /// Behind selected items.
+    pub selected_color: Color,
+    /// Outline of the item the arrow keys are on (while focused).
+    pub cursor_color: Color,
+}
+
+impl Default for ListBoxStyle {
+    fn default() -> Self {
+        Self {
+            font: None,
+            color: WHITE,
+            bg_color: Color::new(0.3, 0.3, 0.3, 0.3),
+            hover_color: Color::new(1.0, 1.0, 1.0, 0.1),
+            selected_color: Color::new(1.0, 0.7, 0.0, 0.4),
+            cursor_color: Color::new(1.0, 0.8, 0.2, 0.6),
+        }
+    }
+}
+
+/// Widget > ListSelection (What a `ListBox` keeps its selection in).
+///
+/// `Option<usize>` allows one selected item; `Vec<usize>` allows several (Ctrl/Shift-click).
+pub trait ListSelection {
+    /// Whether several items can be selected.
+    const MULTIPLE: bool;
+
+    /// The selected indices, in order.
+    fn indices(&self) -> Vec<usize>;
+
+    /// Select `indices` (in order, at most one unless `MULTIPLE`).
+    fn set_indices(&mut self, indices: &[usize]);
+}
+
+impl ListSelection for Option<usize> {
+    const MULTIPLE: bool = false;
+
+    fn indices(&self) -> Vec<usize> {
+        self.iter().copied().collect()
+    }
+
+    fn set_indices(&mut self, indices: &[usize]) {
+        *self = indices.first().copied();
+    }
+}
+
+impl ListSelection for Vec<usize> {
+    const MULTIPLE: bool = true;
+
+    fn indices(&self) -> Vec<usize> {
+        let mut indices = self.clone();
+        indices.sort_unstable();
+        indices.dedup();
+        indices
+    }
+
+    fn set_indices(&mut self, indices: &[usize]) {
+        self.clear();
+        self.extend_from_slice(indices);
+    }
+}
+
+/// Widget > ListBox (Scrollable list of selectable items).
+///
+/// Click an item to select it. When several items can be selected, Ctrl+click toggles an item and
+/// Shift+click selects the items between the last clicked one and this one.
+/// Double-clicking an item (or pressing Enter on it) activates it.
+/// While focused, Up/Down/Home/End/PageUp/PageDown move the selection (Shift extends it), Space toggles and Ctrl+A selects all.
+///
+/// # Example
+/// ```no_run
+/// # use amberwindow::*;
+/// # use macroquad::prelude::*;
+/// # let mut windows = WindowManager::new();
+/// # let asset_names = ["grass.png", "stone.png"];
+/// # fn open_asset(_idx: usize) {}
+/// let mut picked: Vec<usize> = vec![];
+/// loop {
+///     if let Some(win) = windows.begin("assets") {
+///         let list = win.ListBox("assets", &mut picked, &asset_names, vec2(200., 150.));
+///         if let Some(idx) = list.activated {
+///             open_asset(idx);
+///         }
+///     }
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct ListBox {
+    /// Tells list boxes apart (not drawn).
+    pub label: String,
+    pub items: Vec<String>,
+    /// Indices of the selected items, in order.
+    pub selected: Vec<usize>,
+    /// Whether several items can be selected.
+    pub multiple: bool,
+    pub size: Vec2,
+    pub rect: Rect,
+    pub style: ListBoxStyle,
+    pub scrollbar_style: ScrollbarStyle,
+    pub scroll: Scroll,
+    pub uuid: String,
+    pub id: u64,
+    /// Index of the item under the mouse.
+    pub hovered: Option<usize>,
+    pub pressed: bool,
+    /// Whether the selection changed this frame.
+    pub changed: bool,
+    /// Item double-clicked (or Enter pressed on) this frame.
+    pub activated: Option<usize>,
+    /// Item the arrow keys move from (the last one clicked or moved to).
+    cursor: Option<usize>,
+    /// Item Shift+click/Shift+arrows select from.
+    anchor: Option<usize>,
+    /// When (and on which item) the last click was, for double-clicks.
+    last_click: (f64, Option<usize>),
+    focused: bool,
+    repeat: KeyRepeat,
+    item_height: f32,
+    text_height: f32,
+    font_scale: f32,
+}
+
+impl ListBox {
+    pub fn new(
+        label: &str,
+        items: Vec<String>,
+        multiple: bool,
+        font: Option<Font>,
+        size: Vec2,
+        uuid: Option<&str>,
+    ) -> Self {
+        Self {
+            label: label.to_owned(),
+            items,
+            selected: vec![],
+            multiple,
+            size,
+            rect: Rect::new(0., 0., size.x, size.y),
+            style: ListBoxStyle {
+                font,
+                ..Default::default()
+            },
+            scrollbar_style: ScrollbarStyle::default(),
+            scroll: Scroll::default(),
+            uuid: uuid.unwrap_or_default().to_owned(),
+            id: 0,
+            hovered: None,
+            pressed: false,
+            changed: false,
+            activated: None,
+            cursor: None,
+            anchor: None,
+            last_click: (f64::NEG_INFINITY, None),
+            focused: false,
+            repeat: KeyRepeat::default(),
+            item_height: 20.0,
+            text_height: 0.0,
+            font_scale: 1.0,
+        }
+    }
+
+    pub fn set_uuid(&mut self, uuid: impl Into<String>) -> &mut Self {
+        self.uuid = uuid.into();
+        self
+    }
+
+    pub fn style(&mut self, style: ListBoxStyle) -> &mut Self {
+        self.style = style;
+        self
+    }
+
+    /// Whether item `idx` is selected.
+    pub fn is_selected(&self, idx: usize) -> bool {
+        self.selected.contains(&idx)
+    }
+
+    /// Keep the widget and `selection` in step: last frame's changes are written to `selection`, else `selection` is shown.
+    pub(crate) fn sync(&mut self, selection: &mut impl ListSelection) {
+        if self.changed {
+            selection.set_indices(&self.selected);
+        } else {
+            self.selected = selection.indices();
+        }
+    }
+
+    fn set_selected(&mut self, mut selected: Vec<usize>) {
+        selected.sort_unstable();
+        selected.dedup();
+        if selected != self.selected {
+            self.selected = selected;
+            self.changed = true;
+        }
+    }
+
+    /// Select item `idx` as clicked (or moved to) with the keys held: Ctrl toggles it, Shift selects from the anchor.
+    fn select(&mut self, idx: usize, ctrl: bool, shift: bool) {
+        let shift = shift && self.multiple;
+        let ctrl = ctrl && self.multiple;
+        let anchor = self.anchor.filter(|_| shift).unwrap_or(idx);
+        let range = anchor.min(idx)..=anchor.max(idx);
+
+        let selected = match (ctrl, shift) {
+            (_, true) => {
+                let kept = self.selected.iter().copied().filter(|_| ctrl);
+                kept.chain(range).collect()
+            }
+            (true, false) if self.is_selected(idx) => self.selected.iter().copied().filter(|i| *i != idx).collect(),
+            (true, false) => self.selected.iter().copied().chain([idx]).collect(),
+            (false, false) => vec![idx],
+        };
+        self.set_selected(selected);
+
+        if !shift {
+            self.anchor = Some(idx);
+        }
+        self.cursor = Some(idx);
+    }
+
+    fn scroll_to(&mut self, idx: usize) {
+        let top = idx as f32 * self.item_height;
+        let view = self.scroll.viewport.h;
+        if top < self.scroll.offset.y {
+            self.scroll.offset.y = top;
+        } else if top + self.item_height > self.scroll.offset.y + view {
+            self.scroll.offset.y = top + self.item_height - view;
+        }
+        self.scroll.offset = self.scroll.offset.clamp(Vec2::ZERO, self.scroll.max_offset());
+    }
+
+    fn update_keys(&mut self, input: &dyn InputSource) {
+        let Some(last) = self.items.len().checked_sub(1) else { return };
+        let shift = shift_down(input);
+        let ctrl = ctrl_down(input);
+        let rows = (self.scroll.viewport.h / self.item_height).floor().max(1.0) as usize;
+        let cursor = self.cursor.map(|cursor| cursor.min(last));
+
+        let moved = if self.repeat.pressed(input, KeyCode::Down) {
+            Some(cursor.map_or(0, |cursor| (cursor + 1).min(last)))
+        } else if self.repeat.pressed(input, KeyCode::Up) {
+            Some(cursor.map_or(0, |cursor| cursor.saturating_sub(1)))
+        } else if input.is_key_pressed(KeyCode::PageDown) {
+            Some(cursor.map_or(0, |cursor| (cursor + rows).min(last)))
+        } else if input.is_key_pressed(KeyCode::PageUp) {
+            Some(cursor.map_or(0, |cursor| cursor.saturating_sub(rows)))
+        } else if input.is_key_pressed(KeyCode::Home) {
+            Some(0)
+        } else if input.is_key_pressed(KeyCode::End) {
+            Some(last)
+        } else {
+            None
+        };
+        if let Some(idx) = moved {
+            self.select(idx, false, shift);
+            self.scroll_to(idx);
+        }
+
+        if let Some(cursor) = cursor {
+            if input.is_key_pressed(KeyCode::Space) {
+                self.select(cursor, true, false);
+            }
+            if input.is_key_pressed(KeyCode::Enter) || input.is_key_pressed(KeyCode::KpEnter) {
+                self.activated = Some(cursor);
+            }
+        }
+        if ctrl && self.multiple && input.is_key_pressed(KeyCode::A) {
+            self.set_selected((0..self.items.len()).collect());
+        }
+    }
+}
+
+impl WidgetImpl for ListBox {
+    fn kind(&self) -> &'static str {
+        "ListBox"
+    }
+
+    fn label(&self) -> String {
+        self.label.clone()
+    }
+
+    fn measure(&mut self, ctx: &WidgetContext) -> Vec2 {
+        let dim = ctx.backend.measure_text("Ay", None, 16, 1f32);
+        let dim_some = ctx.backend.measure_text("Ay", self.style.font.as_ref(), 16, 1f32);
+
+        self.font_scale = dim.height / dim_some.height;
+        self.text_height = dim.height;
+        self.item_height = dim.height + 8.0;
+        self.size
+    }
+
+    fn update(&mut self, rect: Rect, ctx: &WidgetContext) {
+        self.rect = rect;
+        self.changed = false;
+        self.activated = None;
+        self.focused = ctx.focused;
+        let input = ctx.backend;
+        self.selected.retain(|idx| *idx < self.items.len());
+
+        let content = vec2(0.0, self.items.len() as f32 * self.item_height);
+        self.scroll.layout(rect, content, 0.0, &self.scrollbar_style);
+        let bars = self.scroll.update(ctx.mouse_position, input);
+
+        if !input.is_mouse_button_down(MouseButton::Left) {
+            self.pressed = false;
+        }
+
+        let list = self.scroll.viewport;
+        self.hovered = None;
+        if list.contains(ctx.mouse_position) && !bars {
+            let row = ((ctx.mouse_position.y - list.y + self.scroll.offset.y) / self.item_height) as usize;
+            self.hovered = (row < self.items.len()).then_some(row);
+        }
+
+        if input.is_mouse_button_pressed(MouseButton::Left) && ctx.selected && list.contains(ctx.mouse_position) && !bars {
+            self.pressed = true;
+            ctx.request_focus();
+            if let Some(idx) = self.hovered {
+                self.select(idx, ctrl_down(input), shift_down(input));
+
+                let time = input.time();
+                if self.last_click.1 == Some(idx) && time - self.last_click.0 < DOUBLE_CLICK {
+                    self.activated = Some(idx);
+                    self.last_click = (f64::NEG_INFINITY, None);
+                } else {
+                    self.last_click = (time, Some(idx));
+                }
+            }
+        }
+
+        if ctx.focused {
+            self.update_keys(input);
+        }
+
+        if list.contains(ctx.mouse_position) {
+            let left = self.scroll.scroll_by(ctx.scroll_delta.take());
+            ctx.scroll_delta.set(left);
+        }
+    }
+
+    fn render(&mut self, draw: &mut DrawList) {
+        draw.draw_rectangle(self.rect.x, self.rect.y, self.rect.w, self.rect.h, self.style.bg_color);
+
+        let list = self.scroll.viewport;
+        draw.push_clip(list);
+        let first = (self.scroll.offset.y / self.item_height).floor() as usize;
+        let count = (list.h / self.item_height).ceil() as usize + 1;
+        for (idx, item) in self.items.iter().enumerate().skip(first).take(count) {
+            let y = list.y + idx as f32 * self.item_height - self.scroll.offset.y;
+
+            if self.is_selected(idx) {
+                draw.draw_rectangle(list.x, y, list.w, self.item_height, self.style.selected_color);
+            }
+            if self.hovered == Some(idx) {
+                draw.draw_rectangle(list.x, y, list.w, self.item_height, self.style.hover_color);
+            }
+            if self.focused && self.cursor == Some(idx) {
+                draw.draw_rectangle_lines(list.x, y, list.w, self.item_height, 1.0, self.style.cursor_color);
+            }
+
+            draw.draw_text_ex(
+                item,
+                f32::floor(list.x + PADDING),
+                f32::floor(y + self.item_height / 2.0 + self.text_height / 2.0),
+                TextParams {
+                    font: self.style.font.as_ref(),
+                    font_size: 16,
+                    color: self.style.color,
+                    font_scale: self.font_scale,
+                    ..Default::default()
+                },
+            );
+        }
+        draw.pop_clip();
+
+        self.scroll.render(draw, &self.scrollbar_style);
+    }
+
+    fn is_active(&self) -> bool {
+        self.pressed || self.scroll.is_dragging()
+    }
+
+    fn focusable(&self) -> bool {
+        true
+    }
+
+    fn transfer_state(&mut self, previous: &mut dyn WidgetImpl) {
+        if let Some(previous) = previous.downcast_mut::<ListBox>() {
+            self.selected = std::mem::take(&mut previous.selected);
+            self.scroll = std::mem::take(&mut previous.scroll);
+            self.hovered = previous.hovered;
+            self.pressed = previous.pressed;
+            self.changed = previous.changed;
+            self.activated = previous.activated;
+            self.cursor = previous.cursor;
+            self.anchor = previous.anchor;
+            self.last_click = previous.last_click;
+            self.focused = previous.focused;
+            self.repeat = previous.repeat;
+        }
+    }
+}
@@ -1,17 +1,41 @@
 mod widget;
 pub use widget::*;
+mod widget_impl;
+pub use widget_impl::*;
 mod text;
 pub use text::*;
 mod button;
 pub use button::*;
 mod widgetrow;
 pub use widgetrow::*;
+mod collapsing;
+pub use collapsing::*;
+mod tab_bar;
+pub use tab_bar::*;
 mod slider;
 pub use slider::*;
 mod image;
 pub use image::*;
 mod checkbox;
 pub use checkbox::*;
+mod radio;
+pub use radio::*;
+mod text_input;
+pub use text_input::*;
+mod text_edit;
+pub use text_edit::*;
+mod number_field;
+pub use number_field::*;
+mod combo;
+pub use combo::*;
+mod color_edit;
+pub use color_edit::*;
+mod list_box;
+pub use list_box::*;
+mod progress;
+pub use progress::*;
+mod separator;
+pub use separator::*;
 
 mod window_widget;
 pub use window_widget::*;
\ No newline at end of file
@@ -0,0 +1,433 @@
+use macroquad::prelude::*;
+
+use super::super::*;
+
+/// Most seconds between the clicks of a double-click.
+const DOUBLE_CLICK: f64 = 0.3;
+/// How far (in pixels) the mouse moves before a press becomes a drag.
+const DRAG_THRESHOLD: f32 = 2.0;
+/// Space between the field, its step buttons and its label.
+const SPACING: f32 = 2.0;
+
+/// Widget > NumberField (Number edited by dragging or typing).
+///
+/// Drag it sideways to change the value by `speed` per pixel (`DragFloat`/`DragInt`),
+/// or double-click it (or Ctrl+click it) to type a value in; Enter (or clicking away) keeps it, Escape doesn't.
+/// `InputFloat`/`InputInt` fields start typing on a single click and have -/+ buttons.
+/// Values are unbounded unless given a `range`. While focused, Enter starts typing and Left/Right step the value.
+///
+/// # Example
+/// ```no_run
+/// # use amberwindow::*;
+/// # use macroquad::prelude::*;
+/// # let mut windows = WindowManager::new();
+/// let mut x = 0.0;
+/// let mut lives = 3;
+/// loop {
+///     if let Some(win) = windows.begin("transform") {
+///         win.DragFloat("X", &mut x, 80.).speed(0.1).precision(2).suffix(" m");
+///         win.InputInt("Lives", &mut lives, 80.).range(0., 9.);
+///     }
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct NumberField {
+    /// Drawn to the right of the field.
+    pub label: String,
+    pub value: f64,
+    pub min: f64,
+    pub max: f64,
+    /// How much the value changes per pixel dragged (`0.0` turns dragging off).
+    pub speed: f64,
+    /// How much the -/+ buttons change the value (`None` hides them).
+    pub step: Option<f64>,
+    /// Digits shown after the decimal point.
+    pub precision: usize,
+    /// Shown after the value (e.g. units).
+    pub suffix: String,
+    /// Round the value to whole numbers.
+    pub integer: bool,
+    pub width: f32,
+    pub rect: Rect,
+    pub style: SliderStyle,
+    pub font: Option<Font>,
+    pub uuid: String,
+    pub id: u64,
+    pub hovering: bool,
+    pub pressed: bool,
+    /// Whether the value changed this frame.
+    pub changed: bool,
+    /// Whether `range()` moved the value into range this frame (it is written back like an edit).
+    clamped: bool,
+    /// The text field while a value is being typed in.
+    editor: Option<TextInput>,
+    /// Whether the field was focused last frame (typing stops when focus goes).
+    focused: bool,
+    press_x: f32,
+    press_value: f64,
+    dragging: bool,
+    last_click: f64,
+    field: Rect,
+    minus: Rect,
+    plus: Rect,
+    text_size: Vec2,
+    label_size: Vec2,
+    sign_width: f32,
+    font_scale: f32,
+}
+
+impl NumberField {
+    /// Create a new field (dragged to change the value, with no step buttons, unbounded).
+    pub fn new(label: &str, value: f64, font: Option<Font>, width: f32, uuid: Option<&str>) -> Self {
+        Self {
+            label: label.to_owned(),
+            value,
+            min: f64::NEG_INFINITY,
+            max: f64::INFINITY,
+            speed: 0.1,
+            step: None,
+            precision: 3,
+            suffix: String::new(),
+            integer: false,
+            width,
+            rect: Rect::new(0., 0., width, 21.),
+            style: SliderStyle {
+                color: WHITE,
+                hover_bg_color: Color::new(0.3, 0.3, 0.3, 0.5),
+                bg_color: Color::new(0.3, 0.3, 0.3, 0.3),
+                value_color: GOLD,
+            },
+            font,
+            uuid: uuid.unwrap_or_default().to_owned(),
+            id: 0,
+            hovering: false,
+            pressed: false,
+            changed: false,
+            clamped: false,
+            editor: None,
+            focused: false,
+            press_x: 0.0,
+            press_value: 0.0,
+            dragging: false,
+            last_click: f64::NEG_INFINITY,
+            field: Rect::default(),
+            minus: Rect::default(),
+            plus: Rect::default(),
+            text_size: Vec2::ZERO,
+            label_size: Vec2::ZERO,
+            sign_width: 0.0,
+            font_scale: 1.0,
+        }
+    }
+
+    pub fn set_uuid(&mut self, uuid: impl Into<String>) -> &mut Self {
+        self.uuid = uuid.into();
+        self
+    }
+
+    pub fn style(&mut self, style: SliderStyle) -> &mut Self {
+        self.style = style;
+        self
+    }
+
+    /// How much the value changes per pixel dragged (`0.0` makes a single click start typing instead).
+    pub fn speed(&mut self, speed: f64) -> &mut Self {
+        self.speed = speed;
+        self
+    }
+
+    /// Keep the value within `min..=max` (the bounds are swapped if `min` is greater than `max`).
+    pub fn range(&mut self, min: f64, max: f64) -> &mut Self {
+        self.min = min.min(max);
+        self.max = max.max(min);
+        let value = self.value.clamp(self.min, self.max);
+        if value != self.value {
+            self.value = value;
+            self.clamped = true;
+        }
+        self
+    }
+
+    /// Show -/+ buttons changing the value by `step` (`0.0` hides them).
+    pub fn step(&mut self, step: f64) -> &mut Self {
+        self.step = (step != 0.0).then_some(step);
+        self
+    }
+
+    /// Show `precision` digits after the decimal point.
+    pub fn precision(&mut self, precision: usize) -> &mut Self {
+        self.precision = precision;
+        self
+    }
+
+    /// Show `suffix` (e.g. units) after the value.
+    pub fn suffix(&mut self, suffix: &str) -> &mut Self {
+        self.suffix = suffix.to_owned();
+        self
+    }
+
+    /// Round the value to whole numbers.
+    pub fn integer(&mut self, integer: bool) -> &mut Self {
+        self.integer = integer;
+        self
+    }
+
+    /// Whether a value is being typed in.
+    pub fn is_editing(&self) -> bool {
+        self.editor.is_some()
+    }
+
+    /// Keep the widget and `value` in step: last frame's changes are returned, else `value` is shown (and returned).
+    pub(crate) fn sync(&mut self, value: f64) -> f64 {
+        if !self.changed {
+            self.value = value;
+        }
+        self.value
+    }
+
+    /// The value as typed (without the suffix).
+    fn value_text(&self) -> String {
+        match self.integer {
+            true => format!("{}", self.value.round() as i64),
+            false => format!("{:.*}", self.precision, self.value),
+        }
+    }
+
+    /// Change the value to `value` (rounded and kept in range).
+    fn set_value(&mut self, value: f64) {
+        let mut value = value.clamp(self.min, self.max);
+        if self.integer {
+            value = value.round();
+        }
+        if value != self.value {
+            self.value = value;
+            self.changed = true;
+        }
+    }
+
+    /// Start typing a value in (the current value, all selected).
+    fn start_editing(&mut self, ctx: &WidgetContext) {
+        let text = self.value_text();
+        let mut editor = TextInput::new("", &text, self.font.clone(), self.field.w, None);
+        editor.select(0, text.chars().count());
+        self.editor = Some(editor);
+        self.pressed = false;
+        ctx.request_focus();
+    }
+
+    /// Stop typing, keeping the typed value if `keep` (and it is a number).
+    fn stop_editing(&mut self, keep: bool) {
+        let Some(editor) = self.editor.take() else { return };
+        if let Ok(value) = editor.text.trim().parse::<f64>() {
+            if keep && value.is_finite() {
+                self.set_value(value);
+            }
+        }
+    }
+
+    /// Lay out the field and its step buttons in `rect`.
+    fn layout(&mut self, rect: Rect) {
+        self.rect = rect;
+        let buttons = match self.step {
+            Some(_) => (rect.h + SPACING) * 2.0,
+            None => 0.0,
+        };
+        self.field = Rect::new(rect.x, rect.y, (self.width - buttons).max(rect.h), rect.h);
+        self.minus = Rect::new(self.field.right() + SPACING, rect.y, rect.h, rect.h);
+        self.plus = Rect::new(self.minus.right() + SPACING, rect.y, rect.h, rect.h);
+    }
+
+    fn draw_text(&self, draw: &mut DrawList, text: &str, x: f32, height: f32, color: Color) {
+        draw.draw_text_ex(
+            text,
+            f32::floor(x),
+            f32::floor(self.rect.y + self.rect.h / 2.0 + height / 2.0),
+            TextParams {
+                font: self.font.as_ref(),
+                font_size: 16,
+                color,
+                font_scale: self.font_scale,
+                ..Default::default()
+            },
+        );
+    }
+}
+
+impl WidgetImpl for NumberField {
+    fn kind(&self) -> &'static str {
+        "NumberField"
+    }
+
+    fn label(&self) -> String {
+        self.label.clone()
+    }
+
+    fn measure(&mut self, ctx: &WidgetContext) -> Vec2 {
+        let dim = ctx.backend.measure_text("Ay", None, 16, 1f32);
+        let dim_some = ctx.backend.measure_text("Ay", self.font.as_ref(), 16, 1f32);
+        self.font_scale = dim.height / dim_some.height;
+
+        let label = ctx.backend.measure_text(&self.label, self.font.as_ref(), 16, self.font_scale);
+        self.label_size = vec2(label.width, label.height);
+        self.sign_width = ctx.backend.measure_text("+", self.font.as_ref(), 16, self.font_scale).width;
+        match self.label.is_empty() {
+            true => vec2(self.width, 21.0),
+            false => vec2(self.width + SPACING * 2.0 + label.width, 21.0),
+        }
+    }
+
+    fn update(&mut self, rect: Rect, ctx: &WidgetContext) {
+        self.layout(rect);
+        self.changed = std::mem::take(&mut self.clamped);
+        let input = ctx.backend;
+        let time = input.time();
+        let was_focused = std::mem::replace(&mut self.focused, ctx.focused);
+
+        if let Some(editor) = &mut self.editor {
+            editor.update(self.field, ctx);
+            if editor.enter_pressed {
+                self.stop_editing(true);
+            } else if was_focused && !ctx.focused {
+                // Focus went: Escape throws the typed value away, anything else (e.g. clicking away) keeps it.
+                let keep = !input.is_key_pressed(KeyCode::Escape);
+                self.stop_editing(keep);
+            }
+        } else {
+            if ctx.mouse_released || !input.is_mouse_button_down(MouseButton::Left) {
+                // A press that didn't drag is a click (two quick ones start typing).
+                if self.pressed && !self.dragging {
+                    if time - self.last_click < DOUBLE_CLICK {
+                        self.start_editing(ctx);
+                    }
+                    self.last_click = time;
+                }
+                self.pressed = false;
+                self.dragging = false;
+            }
+
+            self.hovering = self.rect.contains(ctx.mouse_position);
+            let clicked = ctx.selected && input.is_mouse_button_pressed(MouseButton::Left);
+            if clicked && self.field.contains(ctx.mouse_position) {
+                if ctrl_down(input) || self.speed == 0.0 {
+                    self.start_editing(ctx);
+                } else {
+                    self.pressed = true;
+                    self.press_x = ctx.mouse_position.x;
+                    self.press_value = self.value;
+                }
+            }
+            if let Some(step) = self.step {
+                if clicked && self.minus.contains(ctx.mouse_position) {
+                    self.set_value(self.value - step);
+                }
+                if clicked && self.plus.contains(ctx.mouse_position) {
+                    self.set_value(self.value + step);
+                }
+            }
+
+            if self.pressed {
+                let dx = ctx.mouse_position.x - self.press_x;
+                self.dragging |= dx.abs() > DRAG_THRESHOLD;
+                if self.dragging {
+                    self.set_value(self.press_value + dx as f64 * self.speed);
+                }
+            }
+
+            // Keys work the focused field: Enter starts typing, Left/Right step the value.
+            if ctx.focused && self.editor.is_none() {
+                let step = self.step.unwrap_or(match self.integer {
+                    true => 1.0,
+                    false => self.speed,
+                });
+                if input.is_key_pressed(KeyCode::Enter) || input.is_key_pressed(KeyCode::KpEnter) {
+                    self.start_editing(ctx);
+                }
+                if input.is_key_pressed(KeyCode::Left) || input.is_key_pressed(KeyCode::Down) {
+                    self.set_value(self.value - step);
+                }
+                if input.is_key_pressed(KeyCode::Right) || input.is_key_pressed(KeyCode::Up) {
+                    self.set_value(self.value + step);
+                }
+            }
+        }
+
+        let text = self.value_text() + &self.suffix;
+        let dim = input.measure_text(&text, self.font.as_ref(), 16, self.font_scale);
+        self.text_size = vec2(dim.width, dim.height);
+    }
+
+    fn render(&mut self, draw: &mut DrawList) {
+        match &mut self.editor {
+            Some(editor) => editor.render(draw),
+            None => {
+                let field = self.field;
+                draw.draw_rectangle(
+                    field.x,
+                    field.y,
+                    field.w,
+                    field.h,
+                    match self.hovering || self.pressed {
+                        true => self.style.hover_bg_color,
+                        false => self.style.bg_color,
+                    },
+                );
+
+                draw.push_clip(field);
+                let text = self.value_text() + &self.suffix;
+                let color = match self.dragging {
+                    true => self.style.value_color,
+                    false => self.style.color,
+                };
+                let x = field.x + (field.w - self.text_size.x).max(0.0) / 2.0;
+                self.draw_text(draw, &text, x, self.text_size.y, color);
+                draw.pop_clip();
+            }
+        }
+
+        // STEP BUTTONS
+        if self.step.is_some() {
+            for (rect, text) in [(self.minus, "-"), (self.plus, "+")] {
+                draw.draw_rectangle(rect.x, rect.y, rect.w, rect.h, self.style.bg_color);
+                let x = rect.x + (rect.w - self.sign_width) / 2.0;
+                self.draw_text(draw, text, x, self.text_size.y, self.style.color);
+            }
+        }
+
+        // LABEL
+        if !self.label.is_empty() {
+            let x = self.rect.x + self.width + SPACING * 2.0;
+            self.draw_text(draw, &self.label, x, self.label_size.y, self.style.color);
+        }
+    }
+
+    fn is_active(&self) -> bool {
+        self.pressed || self.editor.as_ref().is_some_and(|editor| editor.pressed)
+    }
+
+    fn wants_keyboard(&self) -> bool {
+        self.focused && self.editor.is_some()
+    }
+
+    fn focusable(&self) -> bool {
+        true
+    }
+
+    fn transfer_state(&mut self, previous: &mut dyn WidgetImpl) {
+        if let Some(previous) = previous.downcast_mut::<NumberField>() {
+            self.value = previous.value;
+            self.hovering = previous.hovering;
+            self.pressed = previous.pressed;
+            self.changed = previous.changed;
+            self.editor = previous.editor.take();
+            self.focused = previous.focused;
+            self.press_x = previous.press_x;
+            self.press_value = previous.press_value;
+            self.dragging = previous.dragging;
+            self.last_click = previous.last_click;
+        }
+    }
+
+    fn set_slider_style(&mut self, style: &SliderStyle) {
+        self.style = style.clone();
+    }
+}
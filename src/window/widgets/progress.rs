@@ -0,0 +1,243 @@
+use macroquad::prelude::*;
+
+use super::super::*;
+
+/// Seconds the indeterminate block takes to cross a `ProgressBar`.
+const SWEEP_PERIOD: f64 = 1.5;
+/// Dots around a `Spinner`.
+const SPINNER_DOTS: usize = 8;
+
+fn default_style() -> SliderStyle {
+    SliderStyle {
+        color: WHITE,
+        hover_bg_color: Color::new(0.3, 0.3, 0.3, 0.5),
+        bg_color: Color::new(0.3, 0.3, 0.3, 0.3),
+        value_color: GOLD,
+    }
+}
+
+/// Widget > ProgressBar (Bar filled to show how far along something is).
+///
+/// Styled with a `SliderStyle`: `value_color` fills the bar over `bg_color`, and `color` is the overlay text's.
+/// An indeterminate bar (for work of unknown length) sweeps a block across instead of filling up.
+/// Bars have no label: put one that is only shown sometimes above another in its own `push_id` scope.
+///
+/// # Example
+/// ```no_run
+/// # use amberwindow::*;
+/// # use macroquad::prelude::*;
+/// # let mut windows = WindowManager::new();
+/// # let win = windows.begin("window").unwrap();
+/// # let (loaded, total) = (3, 10);
+/// win.ProgressBar(loaded as f32 / total as f32, vec2(0., 15.)).overlay(format!("{loaded}/{total}"));
+/// ```
+#[derive(Clone, Debug)]
+pub struct ProgressBar {
+    pub font: Option<Font>,
+    pub rect: Rect,
+    pub style: SliderStyle,
+    pub uuid: String,
+    pub id: u64,
+    /// How far along, from 0 to 1.
+    pub fraction: f32,
+    /// Text shown in the middle of the bar.
+    pub overlay: Option<String>,
+    pub indeterminate: bool,
+    /// Time of the last update (drives the indeterminate animation).
+    time: f64,
+    text_size: Vec2,
+    font_scale: f32,
+}
+
+impl ProgressBar {
+    pub fn new(fraction: f32, font: Option<Font>, size: Vec2, uuid: Option<&str>) -> Self {
+        Self {
+            font,
+            rect: Rect::new(0., 0., size.x, size.y),
+            style: default_style(),
+            uuid: uuid.unwrap_or_default().to_owned(),
+            id: 0,
+            fraction: fraction.clamp(0.0, 1.0),
+            overlay: None,
+            indeterminate: false,
+            time: 0.0,
+            text_size: Vec2::ZERO,
+            font_scale: 1.0,
+        }
+    }
+
+    pub fn set_uuid(&mut self, uuid: impl Into<String>) -> &mut Self {
+        self.uuid = uuid.into();
+        self
+    }
+
+    /// Show `text` in the middle of the bar.
+    pub fn overlay(&mut self, text: impl Into<String>) -> &mut Self {
+        self.overlay = Some(text.into());
+        self
+    }
+
+    /// Animate a block sweeping across instead of showing the fraction.
+    pub fn indeterminate(&mut self, indeterminate: bool) -> &mut Self {
+        self.indeterminate = indeterminate;
+        self
+    }
+
+    pub fn style(&mut self, style: SliderStyle) -> &mut Self {
+        self.style = style;
+        self
+    }
+
+    /// The filled part of the bar: up to the fraction, or the sweeping block.
+    pub fn fill_rect(&self) -> Rect {
+        let rect = self.rect;
+        match self.indeterminate {
+            true => {
+                let block = rect.w / 3.0;
+                let t = (self.time / SWEEP_PERIOD).fract() as f32;
+                Rect::new(rect.x - block + t * (rect.w + block), rect.y, block, rect.h)
+            }
+            false => Rect::new(rect.x, rect.y, rect.w * self.fraction, rect.h),
+        }
+    }
+}
+
+impl WidgetImpl for ProgressBar {
+    fn kind(&self) -> &'static str {
+        "ProgressBar"
+    }
+
+    fn measure(&mut self, _ctx: &WidgetContext) -> Vec2 {
+        self.rect.size()
+    }
+
+    fn update(&mut self, rect: Rect, ctx: &WidgetContext) {
+        self.rect = rect;
+        self.time = ctx.backend.time();
+
+        let text = self.overlay.as_deref().unwrap_or_default();
+        let dim = ctx.backend.measure_text(text, None, 16, 1f32);
+        let dim_some = ctx.backend.measure_text(text, self.font.as_ref(), 16, 1f32);
+
+        self.text_size = vec2(dim.width, dim.height);
+        self.font_scale = dim.height / dim_some.height;
+    }
+
+    fn render(&mut self, draw: &mut DrawList) {
+        let rect = self.rect;
+        draw.draw_rectangle(rect.x, rect.y, rect.w, rect.h, self.style.bg_color);
+
+        draw.push_clip(rect);
+        let fill = self.fill_rect();
+        draw.draw_rectangle(
+            fill.x,
+            fill.y,
+            fill.w,
+            fill.h,
+            Color::from_vec(self.style.value_color.to_vec() - vec4(0.1, 0.1, 0.1, 0.22)),
+        );
+        draw.pop_clip();
+
+        if let Some(overlay) = self.overlay.as_ref() {
+            draw.draw_text_ex(
+                overlay,
+                f32::floor(rect.x + rect.w / 2.0 - self.text_size.x / 2.0),
+                f32::floor(rect.y + rect.h / 2.0 + self.text_size.y / 2.0 + 1.),
+                TextParams {
+                    font: self.font.as_ref(),
+                    font_size: 16,
+                    color: self.style.color,
+                    font_scale: self.font_scale,
+                    ..Default::default()
+                },
+            );
+        }
+    }
+
+    fn set_slider_style(&mut self, style: &SliderStyle) {
+        self.style = style.clone();
+    }
+}
+
+/// Widget > Spinner (Ring of dots going round while something is busy).
+///
+/// Styled with a `SliderStyle`: the dots are `value_color`, fading out behind the leading one.
+/// The ring is as big as fits in its size, centred. Like `ProgressBar`, it has no label to take an ID from.
+#[derive(Clone, Debug)]
+pub struct Spinner {
+    pub rect: Rect,
+    pub style: SliderStyle,
+    pub uuid: String,
+    pub id: u64,
+    /// Turns per second.
+    pub speed: f32,
+    time: f64,
+}
+
+impl Spinner {
+    pub fn new(size: Vec2, uuid: Option<&str>) -> Self {
+        Self {
+            rect: Rect::new(0., 0., size.x, size.y),
+            style: default_style(),
+            uuid: uuid.unwrap_or_default().to_owned(),
+            id: 0,
+            speed: 1.0,
+            time: 0.0,
+        }
+    }
+
+    pub fn set_uuid(&mut self, uuid: impl Into<String>) -> &mut Self {
+        self.uuid = uuid.into();
+        self
+    }
+
+    pub fn speed(&mut self, speed: f32) -> &mut Self {
+        self.speed = speed;
+        self
+    }
+
+    pub fn style(&mut self, style: SliderStyle) -> &mut Self {
+        self.style = style;
+        self
+    }
+
+    /// Index of the leading dot (dot 0 is at the top, going clockwise).
+    pub fn head(&self) -> usize {
+        (self.time * self.speed as f64 * SPINNER_DOTS as f64).floor() as usize % SPINNER_DOTS
+    }
+}
+
+impl WidgetImpl for Spinner {
+    fn kind(&self) -> &'static str {
+        "Spinner"
+    }
+
+    fn measure(&mut self, _ctx: &WidgetContext) -> Vec2 {
+        self.rect.size()
+    }
+
+    fn update(&mut self, rect: Rect, ctx: &WidgetContext) {
+        self.rect = rect;
+        self.time = ctx.backend.time();
+    }
+
+    fn render(&mut self, draw: &mut DrawList) {
+        let center = self.rect.center();
+        let radius = self.rect.w.min(self.rect.h) / 2.0;
+        let dot = radius / 5.0;
+        let head = self.head();
+
+        for i in 0..SPINNER_DOTS {
+            let angle = i as f32 / SPINNER_DOTS as f32 * std::f32::consts::TAU;
+            let position = center + vec2(angle.sin(), -angle.cos()) * (radius - dot);
+            let behind = (head + SPINNER_DOTS - i) % SPINNER_DOTS;
+            let mut color = self.style.value_color;
+            color.a *= 1.0 - behind as f32 / SPINNER_DOTS as f32;
+            draw.draw_circle(position.x, position.y, dot, color);
+        }
+    }
+
+    fn set_slider_style(&mut self, style: &SliderStyle) {
+        self.style = style.clone();
+    }
+}
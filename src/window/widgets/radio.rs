@@ -0,0 +1,174 @@
+use macroquad::prelude::*;
+
+use super::super::*;
+
+/// Widget > RadioButton (One choice out of a group sharing a value).
+///
+/// Every button of a group binds the same value and has its own option:
+/// clicking one (or pressing Space/Enter on it) sets the value to its option.
+///
+/// # Example
+/// ```no_run
+/// # use amberwindow::*;
+/// # use macroquad::prelude::*;
+/// # let mut windows = WindowManager::new();
+/// # let win = windows.begin("window").unwrap();
+/// # #[derive(Clone, PartialEq)]
+/// # enum Difficulty { Easy, Hard }
+/// # let mut difficulty = Difficulty::Easy;
+/// win.RadioButton("Easy", &mut difficulty, Difficulty::Easy);
+/// win.RadioButton("Hard", &mut difficulty, Difficulty::Hard);
+/// ```
+#[derive(Clone, Debug)]
+pub struct RadioButton {
+    pub text: String,
+    pub rect: Rect,
+    pub color: Color,
+    pub bg_color: Color,
+    pub font: Option<Font>,
+    pub uuid: String,
+    pub id: u64,
+    pub radius: f32,
+    /// Whether the bound value is this button's option.
+    pub selected: bool,
+    pub hovering: bool,
+    pub pressed: bool,
+    /// Whether this button was picked this frame (it wasn't selected before).
+    pub changed: bool,
+    font_scale: f32,
+}
+
+impl RadioButton {
+    pub fn new(text: &str, font: Option<Font>, selected: bool, color: Option<Color>, uuid: Option<&str>) -> Self {
+        Self {
+            text: text.to_owned(),
+            rect: Rect::new(0., 0., 0., 0.),
+            color: color.unwrap_or(WHITE),
+            bg_color: Color::new(1.0, 0.7, 0., 1.0),
+            font,
+            uuid: uuid.unwrap_or_default().to_owned(),
+            id: 0,
+            radius: 7.5,
+            selected,
+            hovering: false,
+            pressed: false,
+            changed: false,
+            font_scale: 1.0,
+        }
+    }
+
+    pub fn set_uuid(&mut self, uuid: impl Into<String>) -> &mut Self {
+        self.uuid = uuid.into();
+        self
+    }
+
+    pub fn set_text(&mut self, text: String) -> &mut Self {
+        self.text = text;
+        self
+    }
+
+    /// Keep the widget and `value` in step: if this button was picked last frame `value` becomes `option`,
+    /// then the button shows whether `value` is `option`.
+    pub(crate) fn sync<T: PartialEq + Clone>(&mut self, value: &mut T, option: &T) {
+        if self.changed {
+            *value = option.clone();
+        }
+        self.selected = *value == *option;
+    }
+
+    fn pick(&mut self) {
+        if !self.selected {
+            self.selected = true;
+            self.changed = true;
+        }
+    }
+}
+
+impl WidgetImpl for RadioButton {
+    fn kind(&self) -> &'static str {
+        "RadioButton"
+    }
+
+    fn label(&self) -> String {
+        self.text.clone()
+    }
+
+    fn measure(&mut self, ctx: &WidgetContext) -> Vec2 {
+        let dim = ctx.backend.measure_text(&self.text, None, 16, 1f32);
+        let dim_some = ctx.backend.measure_text(&self.text, self.font.as_ref(), 16, 1f32);
+
+        self.font_scale = dim.height / dim_some.height;
+        let size = self.radius * 2.0;
+        vec2(dim.width * 1.2 + 7.0 + size, size + 3.0)
+    }
+
+    fn update(&mut self, rect: Rect, ctx: &WidgetContext) {
+        self.rect = rect;
+        self.changed = false;
+
+        if ctx.mouse_released && self.rect.contains(ctx.mouse_position) && self.pressed && ctx.selected {
+            self.pick();
+        }
+        if ctx.focused && activate_pressed(ctx.backend) {
+            self.pick();
+        }
+
+        if !ctx.backend.is_mouse_button_down(MouseButton::Left) {
+            self.pressed = false;
+        }
+
+        self.hovering = self.rect.contains(ctx.mouse_position);
+        if self.hovering && ctx.backend.is_mouse_button_pressed(MouseButton::Left) {
+            self.pressed = true;
+        }
+    }
+
+    fn render(&mut self, draw: &mut DrawList) {
+        let faded = |alpha: f32| Color::from_vec(self.bg_color.to_vec() - vec4(0., 0., 0., alpha));
+        let x = self.rect.x + self.radius;
+        let y = self.rect.y + self.radius;
+
+        let fill = match (self.hovering, self.pressed) {
+            (_, true) => Some(faded(0.5)),
+            (true, _) => Some(faded(0.7)),
+            _ => None,
+        };
+        if let Some(fill) = fill {
+            draw.draw_circle(x, y, self.radius, fill);
+        }
+        draw.draw_circle_lines(x, y, self.radius, 1.5, self.bg_color);
+        if self.selected {
+            draw.draw_circle(x, y, self.radius - 3.5, self.bg_color);
+        }
+
+        draw.draw_text_ex(
+            self.text.as_str(),
+            self.rect.x + self.radius * 2.0 + 5.0,
+            self.rect.y + self.rect.h / 1.5,
+            TextParams {
+                font: self.font.as_ref(),
+                font_size: 16,
+                color: self.color,
+                font_scale: self.font_scale,
+                ..Default::default()
+            },
+        );
+    }
+
+    fn is_active(&self) -> bool {
+        self.pressed
+    }
+
+    fn focusable(&self) -> bool {
+        true
+    }
+
+    fn transfer_state(&mut self, previous: &mut dyn WidgetImpl) {
+        if let Some(previous) = previous.downcast_mut::<RadioButton>() {
+            self.pressed = previous.pressed;
+            self.hovering = previous.hovering;
+            self.selected = previous.selected;
+            self.changed = previous.changed;
+        }
+    }
+}
@@ -0,0 +1,132 @@
+use macroquad::prelude::*;
+
+use super::super::*;
+
+/// Widget > Separator (Horizontal line between widgets, optionally with a label on it).
+#[derive(Clone, Debug)]
+pub struct Separator {
+    pub label: Option<String>,
+    pub rect: Rect,
+    /// Colour of the line.
+    pub color: Color,
+    pub text_color: Color,
+    pub font: Option<Font>,
+    pub uuid: String,
+    pub id: u64,
+    pub thickness: f32,
+    text_size: Vec2,
+    font_scale: f32,
+}
+
+impl Separator {
+    pub fn new(label: Option<&str>, font: Option<Font>, width: f32, uuid: Option<&str>) -> Self {
+        Self {
+            label: label.map(str::to_owned),
+            rect: Rect::new(0., 0., width, 0.),
+            color: Color::new(1.0, 1.0, 1.0, 0.3),
+            text_color: WHITE,
+            font,
+            uuid: uuid.unwrap_or_default().to_owned(),
+            id: 0,
+            thickness: 1.0,
+            text_size: Vec2::ZERO,
+            font_scale: 1.0,
+        }
+    }
+
+    pub fn set_uuid(&mut self, uuid: impl Into<String>) -> &mut Self {
+        self.uuid = uuid.into();
+        self
+    }
+}
+
+impl WidgetImpl for Separator {
+    fn kind(&self) -> &'static str {
+        "Separator"
+    }
+
+    fn label(&self) -> String {
+        self.label.clone().unwrap_or_default()
+    }
+
+    fn measure(&mut self, ctx: &WidgetContext) -> Vec2 {
+        let Some(label) = self.label.as_ref() else {
+            return vec2(self.rect.w, self.thickness);
+        };
+        let dim = ctx.backend.measure_text(label, None, 16, 1f32);
+        let dim_some = ctx.backend.measure_text(label, self.font.as_ref(), 16, 1f32);
+
+        self.font_scale = dim.height / dim_some.height;
+        self.text_size = vec2(dim.width, dim.height);
+        vec2(self.rect.w, dim.height.max(self.thickness))
+    }
+
+    fn update(&mut self, rect: Rect, _ctx: &WidgetContext) {
+        self.rect = rect;
+    }
+
+    fn render(&mut self, draw: &mut DrawList) {
+        let rect = self.rect;
+        let y = rect.center().y - self.thickness / 2.0;
+
+        let Some(label) = self.label.as_ref() else {
+            draw.draw_rectangle(rect.x, y, rect.w, self.thickness, self.color);
+            return;
+        };
+
+        // A short line, the label, then the line on to the end.
+        let lead = 10f32.min(rect.w);
+        let text_x = rect.x + lead + 5.0;
+        let rest = text_x + self.text_size.x + 5.0;
+        draw.draw_rectangle(rect.x, y, lead, self.thickness, self.color);
+        draw.draw_text_ex(
+            label,
+            f32::floor(text_x),
+            f32::floor(rect.center().y + self.text_size.y / 2.0),
+            TextParams {
+                font: self.font.as_ref(),
+                font_size: 16,
+                color: self.text_color,
+                font_scale: self.font_scale,
+                ..Default::default()
+            },
+        );
+        if rest < rect.right() {
+            draw.draw_rectangle(rest, y, rect.right() - rest, self.thickness, self.color);
+        }
+    }
+}
+
+/// Widget > Dummy (Empty space of a given size, drawing nothing). Every dummy in a scope has the same ID source.
+#[derive(Clone, Debug)]
+pub struct Dummy {
+    pub rect: Rect,
+    pub uuid: String,
+    pub id: u64,
+}
+
+impl Dummy {
+    pub fn new(size: Vec2, uuid: Option<&str>) -> Self {
+        Self {
+            rect: Rect::new(0., 0., size.x, size.y),
+            uuid: uuid.unwrap_or_default().to_owned(),
+            id: 0,
+        }
+    }
+}
+
+impl WidgetImpl for Dummy {
+    fn kind(&self) -> &'static str {
+        "Dummy"
+    }
+
+    fn measure(&mut self, _ctx: &WidgetContext) -> Vec2 {
+        self.rect.size()
+    }
+
+    fn update(&mut self, rect: Rect, _ctx: &WidgetContext) {
+        self.rect = rect;
+    }
+
+    fn render(&mut self, _draw: &mut DrawList) {}
+}
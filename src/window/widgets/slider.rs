@@ -3,6 +3,8 @@ use std::default;
 use camera::mouse;
 use macroquad::prelude::*;
 
+use super::super::*;
+
 /// Style > Custom Slider Styling.
 #[derive(Clone, Debug)]
 pub struct SliderStyle {
@@ -13,6 +15,9 @@ pub struct SliderStyle {
 }
 
 /// Widget > Slider (Simple floating point slider).
+///
+/// A slider has no label, so its ID comes from its range: sliders with the same range that come and go
+/// need an ID scope each (`push_id`), or the one below takes over the hidden one's value.
 #[derive(Clone, Debug)]
 pub struct Slider {
     pub font: Option<Font>,
@@ -20,13 +25,16 @@ pub struct Slider {
     pub hovering: bool,
     pub pressed: bool,
     pub style: SliderStyle,
-    pub uuid: &'static str,
+    pub uuid: String,
+    pub id: u64,
     pub min: f32,
     pub max: f32,
     pub queue_free: bool,
     pub value: f32,
     pub percentage: f32,
-    integer_only: bool
+    integer_only: bool,
+    text_size: Vec2,
+    font_scale: f32,
 }
 
 impl Slider {
@@ -38,12 +46,13 @@ impl Slider {
         default: Option<f32>,
         size: Vec2,
         integer_only: bool,
-        uuid: Option<&'static str>,
+        uuid: Option<&str>,
     ) -> Self {
         Self {
             integer_only,
             font: font.clone(),
-            uuid: uuid.unwrap_or(""),
+            uuid: uuid.unwrap_or_default().to_owned(),
+            id: 0,
             rect: Rect::new(0., 0., size.x, size.y),
             style: SliderStyle {
                 color: WHITE,
@@ -58,22 +67,54 @@ impl Slider {
             value: default.unwrap_or(min),
             min,
             max,
+            text_size: Vec2::ZERO,
+            font_scale: 1.0,
         }
     }
 
-    pub fn set_uuid(&mut self, uuid: &'static str) -> &mut Self {
-        self.uuid = uuid;
+    pub fn set_uuid(&mut self, uuid: impl Into<String>) -> &mut Self {
+        self.uuid = uuid.into();
         self
     }
 
-    pub fn update(&mut self, selected: bool, mouse_position: &Vec2, mouse_released: bool) {
-        if mouse_released {
+    /// The value as displayed on the slider.
+    fn value_text(&self) -> String {
+        let mut text = self.value.to_string();
+        if self.integer_only {
+            text = (self.value as i32).to_string();
+        }
+        format!("{:.4}", text)
+    }
+
+    pub fn style(&mut self, style: SliderStyle) -> &mut Self {
+        self.style = style;
+        self
+    }
+}
+
+impl WidgetImpl for Slider {
+    fn kind(&self) -> &'static str {
+        "Slider"
+    }
+
+    fn label(&self) -> String {
+        format!("{}..{}", self.min, self.max)
+    }
+
+    fn measure(&mut self, _ctx: &WidgetContext) -> Vec2 {
+        self.rect.size()
+    }
+
+    fn update(&mut self, rect: Rect, ctx: &WidgetContext) {
+        self.rect = rect;
+
+        if ctx.mouse_released {
             self.pressed = false;
         }
 
-        if self.rect.contains(*mouse_position) {
+        if self.rect.contains(ctx.mouse_position) {
             self.hovering = true;
-            if is_mouse_button_pressed(MouseButton::Left) && selected {
+            if ctx.backend.is_mouse_button_pressed(MouseButton::Left) && ctx.selected {
                 self.pressed = true;
             }
         } else {
@@ -81,20 +122,37 @@ impl Slider {
         }
 
         if self.pressed {
-            let slider_button_width = self.rect.w / 10.0;
-            let percentage = (((*mouse_position).x - self.rect.x) / self.rect.w);
+            let percentage = (ctx.mouse_position.x - self.rect.x) / self.rect.w;
             self.value = clamp(percentage * (self.max - self.min), self.min, self.max);
-            if self.integer_only {
-                self.value = self.value
+        }
+
+        // Arrow keys nudge the focused slider by a step (1 for integer sliders, else 1% of the range).
+        if ctx.focused {
+            let step = match self.integer_only {
+                true => 1.0,
+                false => (self.max - self.min) / 100.0,
+            };
+            if ctx.backend.is_key_pressed(KeyCode::Left) || ctx.backend.is_key_pressed(KeyCode::Down) {
+                self.value = clamp(self.value - step, self.min, self.max);
+            }
+            if ctx.backend.is_key_pressed(KeyCode::Right) || ctx.backend.is_key_pressed(KeyCode::Up) {
+                self.value = clamp(self.value + step, self.min, self.max);
             }
         }
 
         self.percentage = self.value / self.max;
+
+        let text = self.value_text();
+        let dim = ctx.backend.measure_text(&text, None, 16, 1f32);
+        let dim_some = ctx.backend.measure_text(&text, self.font.as_ref(), 16, 1f32);
+
+        self.text_size = vec2(dim.width, dim.height);
+        self.font_scale = dim.height / dim_some.height;
     }
 
-    pub fn render(&mut self) {
+    fn render(&mut self, draw: &mut DrawList) {
         // BG
-        draw_rectangle(
+        draw.draw_rectangle(
             self.rect.x,
             self.rect.y,
             self.rect.w,
@@ -106,7 +164,7 @@ impl Slider {
         );
 
         // Value
-        draw_rectangle(
+        draw.draw_rectangle(
             self.rect.x,
             self.rect.y,
             f32::clamp(self.rect.w * self.value / self.max, 0., self.rect.w),
@@ -124,7 +182,7 @@ impl Slider {
         // SliderButton
         let slider_button_width = self.rect.w / 30.0 + 2.0;
 
-        draw_rectangle(
+        draw.draw_rectangle(
             clamp(
                 self.rect.x + self.rect.w * self.value / self.max - slider_button_width / 2.0,
                 self.rect.x,
@@ -144,34 +202,37 @@ impl Slider {
         );
 
         // Text
-        let mut text = self.value.to_string();
-        if self.integer_only {
-            text = (self.value as i32).to_string();
-        }
-        text = format!("{:.4}", text);
-
-        let font_size = 16;
-        let dim = measure_text(&text.to_string(), None, 16, 1f32);
-        let dim_some = measure_text(&text.to_string(), self.font.as_ref(), 16, 1f32);
-
-        let height_diff = dim.height / dim_some.height;
-
-        draw_text_ex(
-            &text,
-            f32::floor(self.rect.x + self.rect.w / 2.0 - dim.width / 2.0),
-            f32::floor(self.rect.y + self.rect.h / 2.0 + dim.height / 2.0 + 1.),
+        draw.draw_text_ex(
+            &self.value_text(),
+            f32::floor(self.rect.x + self.rect.w / 2.0 - self.text_size.x / 2.0),
+            f32::floor(self.rect.y + self.rect.h / 2.0 + self.text_size.y / 2.0 + 1.),
             TextParams {
                 font: self.font.as_ref(),
                 font_size: 16,
                 color: self.style.color,
-                font_scale: height_diff,
+                font_scale: self.font_scale,
                 ..Default::default()
             },
         );
     }
 
-    pub fn style(&mut self, style: SliderStyle) -> &mut Self {
-        self.style = style;
-        self
+    fn is_active(&self) -> bool {
+        self.pressed
+    }
+
+    fn focusable(&self) -> bool {
+        true
+    }
+
+    fn transfer_state(&mut self, previous: &mut dyn WidgetImpl) {
+        if let Some(previous) = previous.downcast_mut::<Slider>() {
+            self.pressed = previous.pressed;
+            self.hovering = previous.hovering;
+            self.value = previous.value;
+        }
+    }
+
+    fn set_slider_style(&mut self, style: &SliderStyle) {
+        self.style = style.clone();
     }
 }
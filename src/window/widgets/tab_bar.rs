@@ -0,0 +1,398 @@
+use macroquad::prelude::*;
+
+use super::super::*;
+
+/// Space between tabs.
+const GAP: f32 = 2.0;
+/// Size of a tab's close button.
+const CLOSE_SIZE: f32 = 12.0;
+/// Pixels the strip scrolls per click of its arrows.
+const ARROW_STEP: f32 = 40.0;
+
+/// Style > Custom TabBar styling (like `ButtonStyle`, plus the active tab's colour).
+#[derive(Clone, Debug)]
+pub struct TabStyle {
+    pub font: Option<Font>,
+    pub color: Color,
+    pub bg_color: Color,
+    pub hover_bg_color: Color,
+    pub pressed_bg_color: Color,
+    /// Behind the selected tab, and the line under the strip.
+    pub active_bg_color: Color,
+    /// The 'x' of closable tabs.
+    pub close_color: Color,
+}
+
+impl Default for TabStyle {
+    fn default() -> Self {
+        Self {
+            font: None,
+            color: WHITE,
+            bg_color: Color::new(0.3, 0.3, 0.3, 0.3),
+            hover_bg_color: Color::new(0.2, 0.2, 0.2, 0.3),
+            pressed_bg_color: Color::new(0.4, 0.4, 0.4, 0.4),
+            active_bg_color: Color::new(1.0, 0.7, 0.0, 0.6),
+            close_color: WHITE,
+        }
+    }
+}
+
+/// Widget > TabItem (A tab declared on a `TabStrip` this frame).
+#[derive(Clone, Debug)]
+pub struct TabItem {
+    pub label: String,
+    pub closable: bool,
+    pub rect: Rect,
+    width: f32,
+}
+
+impl TabItem {
+    fn close_rect(&self) -> Rect {
+        Rect::new(
+            self.rect.right() - CLOSE_SIZE - 4.0,
+            self.rect.center().y - CLOSE_SIZE / 2.0,
+            CLOSE_SIZE,
+            CLOSE_SIZE,
+        )
+    }
+}
+
+/// Widget > TabStrip (The row of tabs on top of a `WidgetRow` made by `WidgetRow::tab_bar()`).
+///
+/// Tabs are declared every frame with `WidgetRow::Tab` / `WidgetRow::TabClosable`; the selected tab and the order
+/// the tabs were dragged into are kept per ID. Press a tab to select it, drag it sideways to move it, and use
+/// the arrows (or the mouse wheel) to scroll tabs that don't fit. Left/Right select the previous/next tab
+/// while the bar is focused.
+#[derive(Clone, Debug)]
+pub struct TabStrip {
+    pub label: String,
+    /// The tabs declared this frame, in the order they are shown.
+    pub tabs: Vec<TabItem>,
+    /// Labels of every tab seen, in the order they are shown.
+    pub order: Vec<String>,
+    pub selected: Option<String>,
+    /// Whether the selected tab changed this frame.
+    pub changed: bool,
+    /// Tab whose close button was clicked this frame.
+    pub closed: Option<String>,
+    pub style: TabStyle,
+    pub rect: Rect,
+    /// How far the tabs are scrolled, when they don't fit.
+    pub scroll: f32,
+    pub hovered: Option<String>,
+    /// The tab being pressed, and whether on its close button.
+    pub pressed: Option<(String, bool)>,
+    arrows: Option<(Rect, Rect)>,
+    /// The selected tab as of the last update.
+    shown: Option<String>,
+    text_height: f32,
+    font_scale: f32,
+}
+
+impl TabStrip {
+    pub fn new(label: &str, font: Option<Font>) -> Self {
+        Self {
+            label: label.to_owned(),
+            tabs: vec![],
+            order: vec![],
+            selected: None,
+            changed: false,
+            closed: None,
+            style: TabStyle {
+                font,
+                ..Default::default()
+            },
+            rect: Rect::new(0., 0., 0., 0.),
+            scroll: 0.0,
+            hovered: None,
+            pressed: None,
+            arrows: None,
+            shown: None,
+            text_height: 0.0,
+            font_scale: 1.0,
+        }
+    }
+
+    /// Declare a tab for this frame. Returns whether it is the selected one.
+    pub(crate) fn add(&mut self, label: &str, closable: bool) -> bool {
+        self.tabs.push(TabItem {
+            label: label.to_owned(),
+            closable,
+            rect: Rect::new(0., 0., 0., 0.),
+            width: 0.0,
+        });
+        if !self.order.iter().any(|i| i == label) {
+            self.order.push(label.to_owned());
+        }
+        if self.selected.is_none() {
+            self.selected = Some(label.to_owned());
+        }
+        self.selected.as_deref() == Some(label)
+    }
+
+    /// Whether `label`'s close button was clicked (last frame): it is closed now.
+    pub(crate) fn take_closed(&mut self, label: &str) -> bool {
+        if self.closed.as_deref() == Some(label) {
+            self.closed = None;
+            return true;
+        }
+        false
+    }
+
+    pub(crate) fn select(&mut self, label: &str) {
+        if self.selected.as_deref() != Some(label) {
+            self.selected = Some(label.to_owned());
+            self.changed = true;
+        }
+    }
+
+    /// Height of the strip (as of the last `measure()`).
+    pub(crate) fn height(&self) -> f32 {
+        self.text_height + 10.0
+    }
+
+    /// Size the tabs want: all of them side by side.
+    pub(crate) fn measure(&mut self, ctx: &WidgetContext) -> Vec2 {
+        let dim = ctx.backend.measure_text("Ay", None, 16, 1f32);
+        let dim_some = ctx.backend.measure_text("Ay", self.style.font.as_ref(), 16, 1f32);
+        self.font_scale = dim.height / dim_some.height;
+        self.text_height = dim.height;
+
+        // Drop what was only meant for this frame's `TabClosable`s.
+        self.closed = None;
+
+        let order = &self.order;
+        self.tabs.sort_by_key(|tab| order.iter().position(|i| *i == tab.label));
+        for tab in self.tabs.iter_mut() {
+            let text = ctx.backend.measure_text(&tab.label, None, 16, 1f32);
+            tab.width = text.width + 16.0 + if tab.closable { CLOSE_SIZE + 4.0 } else { 0.0 };
+        }
+
+        // A tab that is gone can't stay selected.
+        let selected = self.selected.as_deref();
+        if !self.tabs.iter().any(|tab| Some(tab.label.as_str()) == selected) {
+            self.selected = self.tabs.first().map(|tab| tab.label.clone());
+        }
+
+        let total = self.tabs.iter().map(|tab| tab.width + GAP).sum::<f32>() - GAP;
+        vec2(total.max(0.0), self.height())
+    }
+
+    fn total_width(&self) -> f32 {
+        (self.tabs.iter().map(|tab| tab.width + GAP).sum::<f32>() - GAP).max(0.0)
+    }
+
+    /// Where the tabs are shown: the strip, minus the arrows when they don't fit.
+    fn area(&self) -> Rect {
+        match self.arrows {
+            Some((left, _)) => Rect::new(self.rect.x, self.rect.y, left.x - self.rect.x, self.rect.h),
+            None => self.rect,
+        }
+    }
+
+    fn layout(&mut self) {
+        let area = self.area();
+        let max = (self.total_width() - area.w).max(0.0);
+        self.scroll = self.scroll.clamp(0.0, max);
+
+        let mut x = area.x - self.scroll;
+        for tab in self.tabs.iter_mut() {
+            tab.rect = Rect::new(x, area.y, tab.width, area.h);
+            x += tab.width + GAP;
+        }
+    }
+
+    /// Scroll the selected tab into view.
+    fn scroll_to_selected(&mut self) {
+        let area = self.area();
+        let selected = self.selected.as_deref();
+        let Some(tab) = self.tabs.iter().find(|tab| Some(tab.label.as_str()) == selected) else { return };
+        let (left, right) = (tab.rect.x - area.x + self.scroll, tab.rect.right() - area.x + self.scroll);
+
+        if left < self.scroll {
+            self.scroll = left;
+        } else if right > self.scroll + area.w {
+            self.scroll = right - area.w;
+        }
+        self.layout();
+    }
+
+    pub(crate) fn update(&mut self, rect: Rect, ctx: &WidgetContext) {
+        self.rect = rect;
+        let input = ctx.backend;
+        let mouse = ctx.mouse_position;
+
+        self.arrows = (self.total_width() > rect.w).then(|| {
+            let size = rect.h;
+            (
+                Rect::new(rect.right() - size * 2.0, rect.y, size, size),
+                Rect::new(rect.right() - size, rect.y, size, size),
+            )
+        });
+        self.layout();
+
+        let area = self.area();
+        self.hovered = self
+            .tabs
+            .iter()
+            .find(|tab| area.contains(mouse) && tab.rect.contains(mouse))
+            .map(|tab| tab.label.clone());
+
+        if input.is_mouse_button_pressed(MouseButton::Left) && ctx.selected {
+            match (self.arrows, self.hovered.clone()) {
+                (Some((left, _)), _) if left.contains(mouse) => self.scroll -= ARROW_STEP,
+                (Some((_, right)), _) if right.contains(mouse) => self.scroll += ARROW_STEP,
+                (_, Some(label)) => {
+                    let tab = self.tabs.iter().find(|tab| tab.label == label);
+                    let on_close = tab.is_some_and(|tab| tab.closable && tab.close_rect().contains(mouse));
+                    if !on_close {
+                        self.select(&label);
+                    }
+                    self.pressed = Some((label, on_close));
+                }
+                _ => {}
+            }
+            self.layout();
+        }
+
+        // Drag a tab past the middle of its neighbour to swap them.
+        if let Some((label, false)) = self.pressed.clone() {
+            if let Some(idx) = self.tabs.iter().position(|tab| tab.label == label) {
+                let next = self.tabs.get(idx + 1).filter(|next| mouse.x > next.rect.center().x);
+                let previous = idx.checked_sub(1).map(|i| &self.tabs[i]).filter(|prev| mouse.x < prev.rect.center().x);
+                if let Some(other) = next.or(previous).map(|tab| tab.label.clone()) {
+                    let a = self.order.iter().position(|i| *i == label);
+                    let b = self.order.iter().position(|i| *i == other);
+                    if let (Some(a), Some(b)) = (a, b) {
+                        self.order.swap(a, b);
+                    }
+                    let other = self.tabs.iter().position(|tab| tab.label == other).unwrap_or(idx);
+                    self.tabs.swap(idx, other);
+                    self.layout();
+                }
+            }
+        }
+
+        if ctx.mouse_released {
+            if let Some((label, true)) = self.pressed.clone() {
+                let tab = self.tabs.iter().find(|tab| tab.label == label);
+                if tab.is_some_and(|tab| tab.close_rect().contains(mouse)) {
+                    self.close(&label);
+                }
+            }
+        }
+        if !input.is_mouse_button_down(MouseButton::Left) {
+            self.pressed = None;
+        }
+
+        if ctx.focused {
+            let selected = self.selected.as_deref();
+            let idx = self.tabs.iter().position(|tab| Some(tab.label.as_str()) == selected);
+            let moved = match idx {
+                Some(idx) if input.is_key_pressed(KeyCode::Left) => idx.checked_sub(1),
+                Some(idx) if input.is_key_pressed(KeyCode::Right) => Some(idx + 1),
+                _ => None,
+            };
+            if let Some(tab) = moved.and_then(|idx| self.tabs.get(idx)) {
+                let label = tab.label.clone();
+                self.select(&label);
+            }
+        }
+        // (However it was selected: by the mouse, the keyboard, `select_tab` or closing a tab.)
+        // (The first tab selected when the bar first shows isn't a change.)
+        if self.selected != self.shown {
+            self.changed = self.shown.is_some();
+            self.scroll_to_selected();
+        } else {
+            self.changed = false;
+        }
+        self.shown = self.selected.clone();
+
+        if self.arrows.is_some() && rect.contains(mouse) {
+            let delta = ctx.scroll_delta.take();
+            self.scroll += delta.x + delta.y;
+            self.layout();
+        }
+    }
+
+    /// Close `label` (reported to its `TabClosable` next frame), selecting a neighbour if it was selected.
+    fn close(&mut self, label: &str) {
+        self.closed = Some(label.to_owned());
+        if self.selected.as_deref() != Some(label) {
+            return;
+        }
+
+        let idx = self.tabs.iter().position(|tab| tab.label == label).unwrap_or(0);
+        let neighbour = self.tabs.get(idx + 1).or(idx.checked_sub(1).and_then(|i| self.tabs.get(i)));
+        match neighbour.map(|tab| tab.label.clone()) {
+            Some(neighbour) => self.select(&neighbour),
+            None => self.selected = None,
+        }
+    }
+
+    pub(crate) fn render(&self, draw: &mut DrawList) {
+        draw.push_clip(self.area());
+        for tab in self.tabs.iter() {
+            let pressed = self.pressed.as_ref().is_some_and(|(label, _)| *label == tab.label);
+            let hovered = self.hovered.as_ref() == Some(&tab.label);
+            let bg_color = match (self.selected.as_ref() == Some(&tab.label), pressed, hovered) {
+                (true, _, _) => self.style.active_bg_color,
+                (_, true, _) => self.style.pressed_bg_color,
+                (_, _, true) => self.style.hover_bg_color,
+                _ => self.style.bg_color,
+            };
+            draw.draw_rectangle(tab.rect.x, tab.rect.y, tab.rect.w, tab.rect.h, bg_color);
+
+            draw.draw_text_ex(
+                &tab.label,
+                f32::floor(tab.rect.x + 8.0),
+                f32::floor(tab.rect.center().y + self.text_height / 2.0),
+                TextParams {
+                    font: self.style.font.as_ref(),
+                    font_size: 16,
+                    color: self.style.color,
+                    font_scale: self.font_scale,
+                    ..Default::default()
+                },
+            );
+
+            if tab.closable {
+                let close = tab.close_rect();
+                let (left, right) = (close.x + 3.0, close.right() - 3.0);
+                let (top, bottom) = (close.y + 3.0, close.bottom() - 3.0);
+                draw.draw_line(left, top, right, bottom, 1.5, self.style.close_color);
+                draw.draw_line(right, top, left, bottom, 1.5, self.style.close_color);
+            }
+        }
+        draw.pop_clip();
+
+        let rect = self.rect;
+        draw.draw_rectangle(rect.x, rect.bottom() - 2.0, rect.w, 2.0, self.style.active_bg_color);
+
+        if let Some((left, right)) = self.arrows {
+            for (arrow, direction) in [(left, -1.0), (right, 1.0)] {
+                draw.draw_rectangle(arrow.x, arrow.y, arrow.w, arrow.h, self.style.bg_color);
+                let center = arrow.center();
+                let size = arrow.h / 5.0;
+                draw.draw_triangle(
+                    center + vec2(size * direction, 0.0),
+                    center + vec2(-size * direction, -size),
+                    center + vec2(-size * direction, size),
+                    self.style.color,
+                );
+            }
+        }
+    }
+
+    /// Carry the selection, order, scroll and interaction over from last frame's strip.
+    pub(crate) fn transfer_state(&mut self, previous: &mut TabStrip) {
+        self.order = std::mem::take(&mut previous.order);
+        self.selected = previous.selected.take();
+        self.changed = previous.changed;
+        self.closed = previous.closed.take();
+        self.shown = previous.shown.take();
+        self.scroll = previous.scroll;
+        self.hovered = previous.hovered.take();
+        self.pressed = previous.pressed.take();
+    }
+}
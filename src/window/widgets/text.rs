@@ -1,5 +1,7 @@
 use macroquad::prelude::*;
 
+use super::super::*;
+
 /// Widget > Text (Renders single-line text).
 #[derive(Clone, Debug)]
 pub struct Text {
@@ -8,22 +10,28 @@ pub struct Text {
     pub color: Color,
     pub font: Option<Font>,
     pub queue_free: bool,
-    pub uuid: &'static str
+    pub uuid: String,
+    pub id: u64,
+    pub(crate) font_scale: f32,
+    baseline: f32,
 }
 impl Text {
-    pub fn new(text: &str, font: Option<Font>, color: Option<Color>, uuid: Option<&'static str>) -> Self {
+    pub fn new(text: &str, font: Option<Font>, color: Option<Color>, uuid: Option<&str>) -> Self {
         Self {
             text: text.to_owned(),
-            uuid: uuid.unwrap_or(""),
+            uuid: uuid.unwrap_or_default().to_owned(),
+            id: 0,
             rect: Rect::new(0.,0.,0.,0.),
             color: color.unwrap_or(WHITE),
-            font: font,
-            queue_free: false
+            font,
+            queue_free: false,
+            font_scale: 1.0,
+            baseline: 0.0,
         }
     }
 
-    pub fn set_uuid(&mut self, uuid: &'static str) -> &mut Self {
-        self.uuid = uuid;
+    pub fn set_uuid(&mut self, uuid: impl Into<String>) -> &mut Self {
+        self.uuid = uuid.into();
         self
     }
     
@@ -31,30 +39,40 @@ impl Text {
         self.text = text;
         self
     }
+}
+
+impl WidgetImpl for Text {
+    fn kind(&self) -> &'static str {
+        "Text"
+    }
 
-    pub fn update(&mut self, _selected: bool) {
-        let dim = measure_text(&self.text, None, 16, 1f32);
-        self.rect.w = dim.width + 3.0;
-        self.rect.h = dim.height + 3.0;
+    fn label(&self) -> String {
+        self.text.clone()
     }
 
-    pub fn render(&mut self) {
-        let dim = measure_text(&self.text.to_string(), None, 16, 1f32);
-        let dim_some = measure_text(&self.text.to_string(), self.font.as_ref(), 16, 1f32);
+    fn measure(&mut self, ctx: &WidgetContext) -> Vec2 {
+        let dim = ctx.backend.measure_text(&self.text, None, 16, 1f32);
+        let dim_some = ctx.backend.measure_text(&self.text, self.font.as_ref(), 16, 1f32);
+
+        self.font_scale = dim.height / dim_some.height;
+        self.baseline = dim.offset_y;
+        vec2(dim.width + 3.0, dim.height + 3.0)
+    }
+
+    fn update(&mut self, rect: Rect, _ctx: &WidgetContext) {
+        self.rect = rect;
+    }
 
-        let height_diff = dim.height/dim_some.height;
-        self.rect.w = dim.width + 3.0;
-        self.rect.h = dim.height + 3.0;
-        
-        draw_text_ex(
+    fn render(&mut self, draw: &mut DrawList) {
+        draw.draw_text_ex(
             self.text.as_str(),
             f32::floor(self.rect.x),
-            f32::floor(self.rect.y),
+            f32::floor(self.rect.y + self.baseline),
             TextParams {
                 font: self.font.as_ref(),
                 font_size: 16,
                 color: self.color,
-                font_scale: height_diff,
+                font_scale: self.font_scale,
                 ..Default::default()
             }
         );
@@ -0,0 +1,799 @@
+use std::collections::HashMap;
+
+use macroquad::prelude::*;
+
+use super::super::*;
+
+/// Space between the box's edges (or the line numbers) and the text.
+const PADDING: f32 = 4.0;
+/// What Tab inserts.
+const TAB: &str = "    ";
+/// Most edits that can be undone.
+const UNDO_LIMIT: usize = 100;
+
+/// One row of text as laid out (a line, or part of a wrapped line).
+#[derive(Clone, Debug, Default)]
+struct Row {
+    /// Character index of the row's first character.
+    start: usize,
+    /// Character index just past the row's last character (not counting the line break).
+    end: usize,
+    /// X of every caret position in the row, `start..=end`.
+    offsets: Vec<f32>,
+    /// Number of the line the row starts, `None` if it continues a wrapped line.
+    line: Option<usize>,
+}
+
+/// What an edit was, so runs of typing (or deleting) undo together.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Edit {
+    Typing,
+    Deleting,
+    Other,
+}
+
+/// Text + selection to go back to with undo/redo.
+#[derive(Clone, Debug)]
+struct Snapshot {
+    text: String,
+    caret: usize,
+    anchor: usize,
+}
+
+/// Widget > TextEdit (Multi-line text editor).
+///
+/// Scrolls (or wraps) long lines and scrolls vertically. Besides what `TextInput` supports,
+/// Ctrl+arrows/Backspace/Delete work word by word, Up/Down/PageUp/PageDown move between lines,
+/// Enter and Tab are typed and Ctrl+Z/Ctrl+Y undo/redo.
+/// A read-only `TextEdit` can still be scrolled, selected and copied from (e.g. for logs).
+///
+/// # Example
+/// ```no_run
+/// # use amberwindow::*;
+/// # use macroquad::prelude::*;
+/// # let mut windows = WindowManager::new();
+/// # let mut log = String::new();
+/// let mut script = String::new();
+/// loop {
+///     if let Some(win) = windows.begin("script") {
+///         win.TextEdit("", &mut script, vec2(300., 200.)).line_numbers(true);
+///         win.TextEdit("", &mut log, vec2(300., 100.)).read_only(true).wrap(true);
+///     }
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct TextEdit {
+    pub text: String,
+    /// Shown (greyed out) while the text is empty.
+    pub hint: String,
+    pub size: Vec2,
+    /// Wrap lines at the edge of the box (instead of scrolling sideways).
+    pub wrap: bool,
+    /// Show line numbers down the left.
+    pub line_numbers: bool,
+    /// Only allow scrolling, selecting and copying.
+    pub read_only: bool,
+    pub rect: Rect,
+    pub style: TextInputStyle,
+    pub scrollbar_style: ScrollbarStyle,
+    pub scroll: Scroll,
+    pub uuid: String,
+    pub id: u64,
+    pub hovering: bool,
+    pub pressed: bool,
+    /// Whether the editor has keyboard focus.
+    pub focused: bool,
+    /// Whether the text was edited this frame.
+    pub changed: bool,
+    caret: usize,
+    anchor: usize,
+    /// X the caret keeps to while moving up/down.
+    goal_x: Option<f32>,
+    rows: Vec<Row>,
+    chars: Vec<char>,
+    /// What `rows` were laid out for (the text, and the wrap width).
+    measured: (String, Option<f32>),
+    gutter: f32,
+    line_height: f32,
+    undo: Vec<Snapshot>,
+    redo: Vec<Snapshot>,
+    last_edit: Option<Edit>,
+    repeat: KeyRepeat,
+    blink: f64,
+    time: f64,
+    font_scale: f32,
+}
+
+impl TextEdit {
+    pub fn new(hint: &str, text: &str, font: Option<Font>, size: Vec2, uuid: Option<&str>) -> Self {
+        Self {
+            text: text.to_owned(),
+            hint: hint.to_owned(),
+            size,
+            wrap: false,
+            line_numbers: false,
+            read_only: false,
+            rect: Rect::new(0., 0., size.x, size.y),
+            style: TextInputStyle {
+                font,
+                ..Default::default()
+            },
+            scrollbar_style: ScrollbarStyle::default(),
+            scroll: Scroll::default(),
+            uuid: uuid.unwrap_or_default().to_owned(),
+            id: 0,
+            hovering: false,
+            pressed: false,
+            focused: false,
+            changed: false,
+            caret: 0,
+            anchor: 0,
+            goal_x: None,
+            rows: vec![],
+            chars: vec![],
+            measured: (String::new(), None),
+            gutter: 0.0,
+            line_height: 16.0,
+            undo: vec![],
+            redo: vec![],
+            last_edit: None,
+            repeat: KeyRepeat::default(),
+            blink: 0.0,
+            time: 0.0,
+            font_scale: 1.0,
+        }
+    }
+
+    pub fn set_uuid(&mut self, uuid: impl Into<String>) -> &mut Self {
+        self.uuid = uuid.into();
+        self
+    }
+
+    pub fn style(&mut self, style: TextInputStyle) -> &mut Self {
+        self.style = style;
+        self
+    }
+
+    pub fn wrap(&mut self, wrap: bool) -> &mut Self {
+        self.wrap = wrap;
+        self
+    }
+
+    pub fn line_numbers(&mut self, line_numbers: bool) -> &mut Self {
+        self.line_numbers = line_numbers;
+        self
+    }
+
+    pub fn read_only(&mut self, read_only: bool) -> &mut Self {
+        self.read_only = read_only;
+        self
+    }
+
+    /// The caret's position (in characters).
+    pub fn caret(&self) -> usize {
+        self.caret
+    }
+
+    /// The selected characters (start..end), if any are.
+    pub fn selection(&self) -> Option<(usize, usize)> {
+        (self.caret != self.anchor).then(|| (self.caret.min(self.anchor), self.caret.max(self.anchor)))
+    }
+
+    /// Select the characters `start..end` (the caret goes to `end`).
+    pub fn select(&mut self, start: usize, end: usize) -> &mut Self {
+        let len = self.text.chars().count();
+        self.anchor = start.min(len);
+        self.caret = end.min(len);
+        self
+    }
+
+    /// Keep the widget and `text` in step: last frame's edits are written to `text`, else `text` is shown.
+    /// Text changed from outside can't be undone past (the undo and redo history is dropped).
+    pub(crate) fn sync(&mut self, text: &mut String) {
+        if self.changed {
+            text.clone_from(&self.text);
+        } else if self.text != *text {
+            self.text.clone_from(text);
+            let len = self.text.chars().count();
+            self.caret = self.caret.min(len);
+            self.anchor = self.anchor.min(len);
+            self.undo.clear();
+            self.redo.clear();
+            self.last_edit = None;
+        }
+    }
+
+    /// Byte index of the character at `idx`.
+    fn byte(&self, idx: usize) -> usize {
+        self.text.char_indices().nth(idx).map_or(self.text.len(), |(byte, _)| byte)
+    }
+
+    /// Remember the text before an edit, so it can be undone (runs of typing or deleting undo together).
+    fn begin_edit(&mut self, edit: Edit) {
+        if edit == Edit::Other || self.last_edit != Some(edit) {
+            self.undo.push(Snapshot {
+                text: self.text.clone(),
+                caret: self.caret,
+                anchor: self.anchor,
+            });
+            if self.undo.len() > UNDO_LIMIT {
+                self.undo.remove(0);
+            }
+        }
+        self.redo.clear();
+        self.last_edit = Some(edit);
+        self.changed = true;
+    }
+
+    /// Go back to the text in `from` (the undo or redo stack), saving the current text in the other one.
+    fn restore(&mut self, undo: bool) {
+        let (from, to) = match undo {
+            true => (&mut self.undo, &mut self.redo),
+            false => (&mut self.redo, &mut self.undo),
+        };
+        let Some(snapshot) = from.pop() else { return };
+        to.push(Snapshot {
+            text: std::mem::replace(&mut self.text, snapshot.text),
+            caret: self.caret,
+            anchor: self.anchor,
+        });
+        self.caret = snapshot.caret;
+        self.anchor = snapshot.anchor;
+        self.last_edit = None;
+        self.changed = true;
+    }
+
+    /// Remove the characters `start..end`.
+    fn remove(&mut self, start: usize, end: usize, edit: Edit) {
+        if start == end {
+            return;
+        }
+        self.begin_edit(edit);
+        let range = self.byte(start)..self.byte(end);
+        self.text.replace_range(range, "");
+        self.caret = start;
+        self.anchor = start;
+    }
+
+    /// Type `text` over the selection.
+    fn insert(&mut self, text: &str, edit: Edit) {
+        let text: String = text.chars().filter(|c| *c == '\n' || !c.is_control()).collect();
+        if text.is_empty() {
+            return;
+        }
+
+        self.begin_edit(edit);
+        if let Some((start, end)) = self.selection() {
+            let range = self.byte(start)..self.byte(end);
+            self.text.replace_range(range, "");
+            self.caret = start;
+        }
+        let byte = self.byte(self.caret);
+        self.text.insert_str(byte, &text);
+        self.caret += text.chars().count();
+        self.anchor = self.caret;
+    }
+
+    /// Where the text starts (on screen), as scrolled.
+    fn origin(&self) -> Vec2 {
+        self.scroll.viewport.point() + Vec2::splat(PADDING) - self.scroll.offset
+    }
+
+    /// Break the text into rows (wrapping at `wrap_width`), when it or the width changed.
+    fn layout_rows(&mut self, backend: &dyn Backend, wrap_width: Option<f32>) {
+        if self.measured.0 == self.text && self.measured.1 == wrap_width && !self.rows.is_empty() {
+            return;
+        }
+
+        let font = self.style.font.as_ref();
+        let mut widths: HashMap<char, f32> = HashMap::new();
+        let mut width = |c: char| {
+            *widths
+                .entry(c)
+                .or_insert_with(|| backend.measure_text(&c.to_string(), font, 16, self.font_scale).width)
+        };
+
+        let chars: Vec<char> = self.text.chars().collect();
+        let mut rows = vec![];
+        let mut line_start = 0;
+        for (line, text) in self.text.split('\n').enumerate() {
+            let line_end = line_start + text.chars().count();
+            let mut row = Row {
+                start: line_start,
+                end: line_start,
+                offsets: vec![0.0],
+                line: Some(line),
+            };
+            let mut space = None;
+
+            let mut idx = line_start;
+            while idx < line_end {
+                let x = row.offsets.last().copied().unwrap_or(0.0) + width(chars[idx]);
+
+                let full = wrap_width.is_some_and(|wrap| x > wrap) && idx > row.start;
+                if full {
+                    // Break after the row's last space, or mid-word if it has none.
+                    let end = space.filter(|space| *space > row.start).unwrap_or(idx);
+                    row.end = end;
+                    row.offsets.truncate(end - row.start + 1);
+                    rows.push(std::mem::take(&mut row));
+
+                    row = Row {
+                        start: end,
+                        end,
+                        offsets: vec![0.0],
+                        line: None,
+                    };
+                    space = None;
+                    for (i, c) in chars.iter().enumerate().take(idx).skip(end) {
+                        let x = row.offsets.last().copied().unwrap_or(0.0) + width(*c);
+                        row.offsets.push(x);
+                        if *c == ' ' {
+                            space = Some(i + 1);
+                        }
+                    }
+                    continue;
+                }
+
+                row.offsets.push(x);
+                if chars[idx] == ' ' {
+                    space = Some(idx + 1);
+                }
+                idx += 1;
+            }
+
+            row.end = line_end;
+            rows.push(row);
+            line_start = line_end + 1;
+        }
+
+        self.rows = rows;
+        self.chars = chars;
+        self.measured = (self.text.clone(), wrap_width);
+    }
+
+    /// Index of the row the caret at `caret` is shown in.
+    fn row_of(&self, caret: usize) -> usize {
+        let last = self.rows.len() - 1;
+        for (idx, row) in self.rows.iter().enumerate() {
+            let continues = self.rows.get(idx + 1).is_some_and(|next| next.line.is_none());
+            if caret < row.end || (caret == row.end && !continues) {
+                return idx;
+            }
+        }
+        last
+    }
+
+    /// Where the caret at `caret` is, in content coordinates (the top of its row).
+    fn caret_position(&self, caret: usize) -> Vec2 {
+        let idx = self.row_of(caret);
+        let row = &self.rows[idx];
+        vec2(row.offsets[caret.clamp(row.start, row.end) - row.start], idx as f32 * self.line_height)
+    }
+
+    /// The caret nearest to `x` in row `idx`.
+    fn caret_in_row(&self, idx: usize, x: f32) -> usize {
+        let row = &self.rows[idx.min(self.rows.len() - 1)];
+        let mut nearest = 0;
+        for (i, offset) in row.offsets.iter().enumerate() {
+            if (offset - x).abs() < (row.offsets[nearest] - x).abs() {
+                nearest = i;
+            }
+        }
+        row.start + nearest
+    }
+
+    /// The caret nearest to `point` (on screen).
+    fn caret_at(&self, point: Vec2) -> usize {
+        let local = point - self.origin();
+        let idx = (local.y / self.line_height).floor().max(0.0) as usize;
+        self.caret_in_row(idx.min(self.rows.len() - 1), local.x)
+    }
+
+    fn is_word(c: char) -> bool {
+        c.is_alphanumeric() || c == '_'
+    }
+
+    /// Start of the word before `idx`.
+    fn word_start(&self, mut idx: usize) -> usize {
+        while idx > 0 && !Self::is_word(self.chars[idx - 1]) {
+            idx -= 1;
+        }
+        while idx > 0 && Self::is_word(self.chars[idx - 1]) {
+            idx -= 1;
+        }
+        idx
+    }
+
+    /// End of the word after `idx`.
+    fn word_end(&self, mut idx: usize) -> usize {
+        let len = self.chars.len();
+        while idx < len && !Self::is_word(self.chars[idx]) {
+            idx += 1;
+        }
+        while idx < len && Self::is_word(self.chars[idx]) {
+            idx += 1;
+        }
+        idx
+    }
+
+    /// Handle this frame's typing and editing keys. Returns whether the caret moved or the text changed.
+    fn update_keys(&mut self, input: &dyn Backend, visible_rows: usize) -> bool {
+        let shift = shift_down(input);
+        let ctrl = ctrl_down(input);
+        let (caret, anchor, text_changed) = (self.caret, self.anchor, self.changed);
+        let editable = !self.read_only;
+
+        if editable && (!ctrl || alt_down(input)) {
+            let typed: String = input.chars_pressed().into_iter().collect();
+            self.insert(&typed, Edit::Typing);
+            // The keys below work on the rows as typed into.
+            self.layout(input);
+        }
+        let len = self.chars.len();
+
+        // Moving the caret (Shift extends the selection, else a selection collapses to its side).
+        let mut moved = None;
+        let mut vertical = None;
+        if self.repeat.pressed(input, KeyCode::Left) {
+            moved = Some(match (self.selection(), shift, ctrl) {
+                (Some((start, _)), false, false) => start,
+                (_, _, true) => self.word_start(self.caret),
+                _ => self.caret.saturating_sub(1),
+            });
+        }
+        if self.repeat.pressed(input, KeyCode::Right) {
+            moved = Some(match (self.selection(), shift, ctrl) {
+                (Some((_, end)), false, false) => end,
+                (_, _, true) => self.word_end(self.caret),
+                _ => (self.caret + 1).min(len),
+            });
+        }
+        if self.repeat.pressed(input, KeyCode::Up) {
+            vertical = Some(-1);
+        }
+        if self.repeat.pressed(input, KeyCode::Down) {
+            vertical = Some(1);
+        }
+        if input.is_key_pressed(KeyCode::PageUp) {
+            vertical = Some(-(visible_rows as isize));
+        }
+        if input.is_key_pressed(KeyCode::PageDown) {
+            vertical = Some(visible_rows as isize);
+        }
+        if input.is_key_pressed(KeyCode::Home) {
+            moved = Some(match ctrl {
+                true => 0,
+                false => self.rows[self.row_of(self.caret)].start,
+            });
+        }
+        if input.is_key_pressed(KeyCode::End) {
+            moved = Some(match ctrl {
+                true => len,
+                false => self.rows[self.row_of(self.caret)].end,
+            });
+        }
+
+        if let Some(rows) = vertical {
+            let position = self.caret_position(self.caret);
+            let x = *self.goal_x.get_or_insert(position.x);
+            let row = self.row_of(self.caret) as isize + rows;
+            moved = Some(match row {
+                row if row < 0 => 0,
+                row if row as usize >= self.rows.len() => len,
+                row => self.caret_in_row(row as usize, x),
+            });
+        } else if moved.is_some() {
+            self.goal_x = None;
+        }
+        if let Some(caret) = moved {
+            self.caret = caret;
+            if !shift {
+                self.anchor = caret;
+            }
+            self.last_edit = None;
+        }
+
+        if editable && self.repeat.pressed(input, KeyCode::Backspace) {
+            match self.selection() {
+                Some((start, end)) => self.remove(start, end, Edit::Other),
+                None if ctrl => self.remove(self.word_start(self.caret), self.caret, Edit::Other),
+                None => self.remove(self.caret.saturating_sub(1), self.caret, Edit::Deleting),
+            }
+        }
+        if editable && self.repeat.pressed(input, KeyCode::Delete) {
+            match self.selection() {
+                Some((start, end)) => self.remove(start, end, Edit::Other),
+                None if ctrl => self.remove(self.caret, self.word_end(self.caret), Edit::Other),
+                None => self.remove(self.caret, (self.caret + 1).min(len), Edit::Deleting),
+            }
+        }
+        if editable && (self.repeat.pressed(input, KeyCode::Enter) || self.repeat.pressed(input, KeyCode::KpEnter)) {
+            self.insert("\n", Edit::Other);
+        }
+        if editable && self.repeat.pressed(input, KeyCode::Tab) {
+            self.insert(TAB, Edit::Typing);
+        }
+
+        if ctrl && input.is_key_pressed(KeyCode::A) {
+            self.select(0, len);
+        }
+        if ctrl && (input.is_key_pressed(KeyCode::C) || input.is_key_pressed(KeyCode::X)) {
+            if let Some((start, end)) = self.selection() {
+                input.clipboard_set(&self.text[self.byte(start)..self.byte(end)]);
+                if editable && input.is_key_pressed(KeyCode::X) {
+                    self.remove(start, end, Edit::Other);
+                }
+            }
+        }
+        if editable && ctrl && input.is_key_pressed(KeyCode::V) {
+            if let Some(text) = input.clipboard_get() {
+                self.insert(&text.replace("\r\n", "\n"), Edit::Other);
+            }
+        }
+        let redo = input.is_key_pressed(KeyCode::Y) || (shift && input.is_key_pressed(KeyCode::Z));
+        if editable && ctrl && redo {
+            self.restore(false);
+        } else if editable && ctrl && input.is_key_pressed(KeyCode::Z) {
+            self.restore(true);
+        }
+
+        (self.caret, self.anchor) != (caret, anchor) || self.changed != text_changed
+    }
+
+    /// Scroll so the caret's row is in view.
+    fn scroll_to_caret(&mut self) {
+        let caret = self.caret_position(self.caret) + Vec2::splat(PADDING);
+        let view = self.scroll.viewport.size();
+        let max = self.scroll.max_offset();
+        let offset = &mut self.scroll.offset;
+
+        if caret.y < offset.y + PADDING {
+            offset.y = caret.y - PADDING;
+        } else if caret.y + self.line_height > offset.y + view.y - PADDING {
+            offset.y = caret.y + self.line_height + PADDING - view.y;
+        }
+        if caret.x < offset.x + PADDING {
+            offset.x = caret.x - PADDING;
+        } else if caret.x + 1.0 > offset.x + view.x - PADDING {
+            offset.x = caret.x + 1.0 + PADDING - view.x;
+        }
+        *offset = offset.clamp(Vec2::ZERO, max);
+    }
+
+    /// Lay the rows out for the current text and fit the scroll region to them.
+    fn layout(&mut self, backend: &dyn Backend) {
+        self.gutter = match self.line_numbers {
+            true => {
+                let lines = self.text.split('\n').count().to_string();
+                backend.measure_text(&lines, self.style.font.as_ref(), 16, self.font_scale).width + PADDING * 2.0
+            }
+            false => 0.0,
+        };
+
+        let area = Rect::new(self.rect.x + self.gutter, self.rect.y, self.rect.w - self.gutter, self.rect.h);
+        let wrap_width = self
+            .wrap
+            .then(|| (area.w - self.scrollbar_style.width - PADDING * 2.0).max(1.0));
+        self.layout_rows(backend, wrap_width);
+
+        let width = self.rows.iter().filter_map(|row| row.offsets.last()).fold(0f32, |a, b| a.max(*b));
+        let content = vec2(
+            match self.wrap {
+                true => 0.0,
+                // (+1 for the caret past the end of the longest line)
+                false => width + PADDING * 2.0 + 1.0,
+            },
+            self.rows.len() as f32 * self.line_height + PADDING * 2.0,
+        );
+        self.scroll.layout(area, content, 0.0, &self.scrollbar_style);
+    }
+}
+
+impl WidgetImpl for TextEdit {
+    fn kind(&self) -> &'static str {
+        "TextEdit"
+    }
+
+    fn label(&self) -> String {
+        self.hint.clone()
+    }
+
+    fn measure(&mut self, ctx: &WidgetContext) -> Vec2 {
+        let dim = ctx.backend.measure_text("Ay", None, 16, 1f32);
+        let dim_some = ctx.backend.measure_text("Ay", self.style.font.as_ref(), 16, 1f32);
+
+        self.font_scale = dim.height / dim_some.height;
+        self.line_height = dim.height + 4.0;
+        self.size
+    }
+
+    fn update(&mut self, rect: Rect, ctx: &WidgetContext) {
+        self.rect = rect;
+        self.changed = false;
+        self.time = ctx.backend.time();
+        let len = self.text.chars().count();
+        self.caret = self.caret.min(len);
+        self.anchor = self.anchor.min(len);
+
+        if !ctx.focused && self.focused {
+            self.anchor = self.caret;
+        }
+        self.focused = ctx.focused;
+
+        self.layout(ctx.backend);
+        let bars = self.scroll.update(ctx.mouse_position, ctx.backend);
+
+        if !ctx.backend.is_mouse_button_down(MouseButton::Left) {
+            self.pressed = false;
+        }
+
+        let mut follow_caret = false;
+        self.hovering = self.rect.contains(ctx.mouse_position);
+        let in_text = self.scroll.viewport.contains(ctx.mouse_position) && !bars;
+        if in_text && ctx.backend.is_mouse_button_pressed(MouseButton::Left) && ctx.selected {
+            self.pressed = true;
+            self.caret = self.caret_at(ctx.mouse_position);
+            if !shift_down(ctx.backend) {
+                self.anchor = self.caret;
+            }
+            self.goal_x = None;
+            self.last_edit = None;
+            self.blink = self.time;
+            ctx.request_focus();
+        } else if self.pressed {
+            // Dragging a selection (past the edges scrolls).
+            self.caret = self.caret_at(ctx.mouse_position);
+            follow_caret = true;
+        }
+
+        if self.focused {
+            let visible_rows = (self.scroll.viewport.h / self.line_height).floor().max(1.0) as usize;
+            if self.update_keys(ctx.backend, visible_rows) {
+                self.layout(ctx.backend);
+                self.blink = self.time;
+                follow_caret = true;
+            }
+        }
+        if follow_caret {
+            self.scroll_to_caret();
+        }
+
+        if self.scroll.viewport.contains(ctx.mouse_position) {
+            let left = self.scroll.scroll_by(ctx.scroll_delta.take());
+            ctx.scroll_delta.set(left);
+        }
+    }
+
+    fn render(&mut self, draw: &mut DrawList) {
+        draw.draw_rectangle(
+            self.rect.x,
+            self.rect.y,
+            self.rect.w,
+            self.rect.h,
+            match (self.focused, self.hovering) {
+                (true, _) => self.style.focused_bg_color,
+                (false, true) => self.style.hover_bg_color,
+                _ => self.style.bg_color,
+            },
+        );
+
+        let viewport = self.scroll.viewport;
+        let origin = self.origin();
+        let first = ((self.scroll.offset.y - PADDING) / self.line_height).floor().max(0.0) as usize;
+        let count = (viewport.h / self.line_height).ceil() as usize + 1;
+        let baseline = self.line_height - 4.0;
+        let params = |color| TextParams {
+            font: self.style.font.as_ref(),
+            font_size: 16,
+            color,
+            font_scale: self.font_scale,
+            ..Default::default()
+        };
+
+        // LINE NUMBERS
+        if self.line_numbers {
+            draw.push_clip(Rect::new(self.rect.x, viewport.y, self.gutter, viewport.h));
+            for (idx, row) in self.rows.iter().enumerate().skip(first).take(count) {
+                let Some(line) = row.line else { continue };
+                draw.draw_text_ex(
+                    &(line + 1).to_string(),
+                    f32::floor(self.rect.x + PADDING),
+                    f32::floor(origin.y + idx as f32 * self.line_height + baseline),
+                    params(self.style.hint_color),
+                );
+            }
+            draw.pop_clip();
+        }
+
+        draw.push_clip(viewport);
+
+        // HINT
+        if self.text.is_empty() && !self.focused {
+            draw.draw_text_ex(
+                &self.hint,
+                f32::floor(origin.x),
+                f32::floor(origin.y + baseline),
+                params(self.style.hint_color),
+            );
+        }
+
+        let selection = self.selection().filter(|_| self.focused);
+        for (idx, row) in self.rows.iter().enumerate().skip(first).take(count) {
+            let y = origin.y + idx as f32 * self.line_height;
+
+            // SELECTION (line breaks show as a sliver past the row's end)
+            if let Some((start, end)) = selection {
+                let from = start.max(row.start);
+                let to = end.min(row.end);
+                if from <= to && start <= row.end && end >= row.start {
+                    let left = row.offsets[from - row.start];
+                    let mut width = row.offsets[to - row.start] - left;
+                    if end > row.end && row.end < self.chars.len() {
+                        width += 4.0;
+                    }
+                    draw.draw_rectangle(origin.x + left, y, width, self.line_height, self.style.selection_color);
+                }
+            }
+
+            // TEXT
+            let text: String = self.chars[row.start..row.end].iter().collect();
+            draw.draw_text_ex(
+                &text,
+                f32::floor(origin.x),
+                f32::floor(y + baseline),
+                params(self.style.color),
+            );
+        }
+
+        // CARET (blinking, shown right after it moves)
+        if self.focused && (self.time - self.blink).rem_euclid(1.0) < 0.5 {
+            let caret = origin + self.caret_position(self.caret);
+            let x = f32::floor(caret.x) + 0.5;
+            draw.draw_line(x, caret.y + 1.0, x, caret.y + self.line_height - 1.0, 1.0, self.style.caret_color);
+        }
+
+        draw.pop_clip();
+
+        self.scroll.render(draw, &self.scrollbar_style);
+    }
+
+    fn is_active(&self) -> bool {
+        self.pressed || self.scroll.is_dragging()
+    }
+
+    fn wants_keyboard(&self) -> bool {
+        self.focused
+    }
+
+    fn focusable(&self) -> bool {
+        true
+    }
+
+    fn wants_tab(&self) -> bool {
+        self.focused && !self.read_only
+    }
+
+    fn transfer_state(&mut self, previous: &mut dyn WidgetImpl) {
+        if let Some(previous) = previous.downcast_mut::<TextEdit>() {
+            self.text = std::mem::take(&mut previous.text);
+            self.scroll = std::mem::take(&mut previous.scroll);
+            self.hovering = previous.hovering;
+            self.pressed = previous.pressed;
+            self.focused = previous.focused;
+            self.changed = previous.changed;
+            self.caret = previous.caret;
+            self.anchor = previous.anchor;
+            self.goal_x = previous.goal_x;
+            self.rows = std::mem::take(&mut previous.rows);
+            self.chars = std::mem::take(&mut previous.chars);
+            self.measured = std::mem::take(&mut previous.measured);
+            self.undo = std::mem::take(&mut previous.undo);
+            self.redo = std::mem::take(&mut previous.redo);
+            self.last_edit = previous.last_edit;
+            self.repeat = previous.repeat;
+            self.blink = previous.blink;
+            self.time = previous.time;
+        }
+    }
+}
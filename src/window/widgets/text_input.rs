@@ -0,0 +1,481 @@
+use macroquad::prelude::*;
+
+use super::super::*;
+
+/// Space between the box's edges and its text.
+const PADDING: f32 = 4.0;
+
+/// Style > Custom TextInput styling.
+#[derive(Clone, Debug)]
+pub struct TextInputStyle {
+    pub font: Option<Font>,
+    pub color: Color,
+    pub hint_color: Color,
+    pub bg_color: Color,
+    pub hover_bg_color: Color,
+    pub focused_bg_color: Color,
+    pub selection_color: Color,
+    pub caret_color: Color,
+}
+
+impl Default for TextInputStyle {
+    fn default() -> Self {
+        Self {
+            font: None,
+            color: WHITE,
+            hint_color: Color::new(1.0, 1.0, 1.0, 0.4),
+            bg_color: Color::new(0.3, 0.3, 0.3, 0.3),
+            hover_bg_color: Color::new(0.3, 0.3, 0.3, 0.45),
+            focused_bg_color: Color::new(0.05, 0.05, 0.05, 0.6),
+            selection_color: Color::new(1.0, 0.7, 0.0, 0.4),
+            caret_color: GOLD,
+        }
+    }
+}
+
+/// Widget > TextInput (Single line of editable text).
+///
+/// Click it (or Tab to it) to type. Supports selection by mouse and Shift+arrows, Home/End,
+/// Backspace/Delete, Ctrl+A and clipboard copy/cut/paste (Ctrl+C/X/V).
+///
+/// # Example
+/// ```no_run
+/// # use amberwindow::*;
+/// # use macroquad::prelude::*;
+/// # let mut windows = WindowManager::new();
+/// let mut name = String::new();
+/// loop {
+///     if let Some(win) = windows.begin("rename") {
+///         if win.TextInput("Name", &mut name, 150.).max_length(16).enter_pressed {
+///             println!("Renamed to {}", name);
+///         }
+///     }
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct TextInput {
+    pub text: String,
+    /// Shown (greyed out) while the text is empty.
+    pub hint: String,
+    /// Most characters the text can have.
+    pub max_length: Option<usize>,
+    /// Show every character as `*` (copying is disabled too).
+    pub password: bool,
+    pub width: f32,
+    pub rect: Rect,
+    pub style: TextInputStyle,
+    pub uuid: String,
+    pub id: u64,
+    pub hovering: bool,
+    pub pressed: bool,
+    /// Whether the input has keyboard focus (is being typed in).
+    pub focused: bool,
+    /// Whether the text was edited this frame.
+    pub changed: bool,
+    /// Whether Enter was pressed this frame.
+    pub enter_pressed: bool,
+    caret: usize,
+    anchor: usize,
+    scroll_x: f32,
+    /// X of every caret position (from the start of the text) in `measured`.
+    offsets: Vec<f32>,
+    measured: String,
+    repeat: KeyRepeat,
+    blink: f64,
+    time: f64,
+    text_height: f32,
+    font_scale: f32,
+}
+
+impl TextInput {
+    pub fn new(hint: &str, text: &str, font: Option<Font>, width: f32, uuid: Option<&str>) -> Self {
+        Self {
+            text: text.to_owned(),
+            hint: hint.to_owned(),
+            max_length: None,
+            password: false,
+            width,
+            rect: Rect::new(0., 0., width, 21.),
+            style: TextInputStyle {
+                font,
+                ..Default::default()
+            },
+            uuid: uuid.unwrap_or_default().to_owned(),
+            id: 0,
+            hovering: false,
+            pressed: false,
+            focused: false,
+            changed: false,
+            enter_pressed: false,
+            caret: 0,
+            anchor: 0,
+            scroll_x: 0.0,
+            offsets: vec![],
+            measured: String::new(),
+            repeat: KeyRepeat::default(),
+            blink: 0.0,
+            time: 0.0,
+            text_height: 0.0,
+            font_scale: 1.0,
+        }
+    }
+
+    pub fn set_uuid(&mut self, uuid: impl Into<String>) -> &mut Self {
+        self.uuid = uuid.into();
+        self
+    }
+
+    pub fn style(&mut self, style: TextInputStyle) -> &mut Self {
+        self.style = style;
+        self
+    }
+
+    pub fn max_length(&mut self, max_length: usize) -> &mut Self {
+        self.max_length = Some(max_length);
+        self
+    }
+
+    pub fn password(&mut self, password: bool) -> &mut Self {
+        self.password = password;
+        self
+    }
+
+    /// The caret's position (in characters).
+    pub fn caret(&self) -> usize {
+        self.caret
+    }
+
+    /// The selected characters (start..end), if any are.
+    pub fn selection(&self) -> Option<(usize, usize)> {
+        (self.caret != self.anchor).then(|| (self.caret.min(self.anchor), self.caret.max(self.anchor)))
+    }
+
+    /// Select the characters `start..end` (the caret goes to `end`).
+    pub fn select(&mut self, start: usize, end: usize) -> &mut Self {
+        let len = self.text.chars().count();
+        self.anchor = start.min(len);
+        self.caret = end.min(len);
+        self
+    }
+
+    /// Keep the widget and `text` in step: last frame's edits are written to `text`, else `text` is shown.
+    pub(crate) fn sync(&mut self, text: &mut String) {
+        if self.changed {
+            text.clone_from(&self.text);
+        } else if self.text != *text {
+            self.text.clone_from(text);
+            let len = self.text.chars().count();
+            self.caret = self.caret.min(len);
+            self.anchor = self.anchor.min(len);
+        }
+    }
+
+    /// Byte index of the character at `idx`.
+    fn byte(&self, idx: usize) -> usize {
+        self.text.char_indices().nth(idx).map_or(self.text.len(), |(byte, _)| byte)
+    }
+
+    fn display_text(&self) -> String {
+        match self.password {
+            true => "*".repeat(self.text.chars().count()),
+            false => self.text.clone(),
+        }
+    }
+
+    /// Remove the selected text. Returns whether anything was selected.
+    fn delete_selection(&mut self) -> bool {
+        let Some((start, end)) = self.selection() else { return false };
+        let range = self.byte(start)..self.byte(end);
+        self.text.replace_range(range, "");
+        self.caret = start;
+        self.anchor = start;
+        self.changed = true;
+        true
+    }
+
+    /// Type `text` over the selection (as much of it as `max_length` leaves room for).
+    fn insert(&mut self, text: &str) {
+        if !text.chars().any(|c| !c.is_control()) {
+            return;
+        }
+        self.delete_selection();
+
+        let room = self.max_length.map_or(usize::MAX, |max| max.saturating_sub(self.text.chars().count()));
+        let text: String = text.chars().filter(|c| !c.is_control()).take(room).collect();
+        if text.is_empty() {
+            return;
+        }
+
+        let byte = self.byte(self.caret);
+        self.text.insert_str(byte, &text);
+        self.caret += text.chars().count();
+        self.anchor = self.caret;
+        self.changed = true;
+    }
+
+    /// Measure where each caret position is (only when the text changed).
+    fn measure_text(&mut self, backend: &dyn Backend) {
+        let text = self.display_text();
+        if text == self.measured && !self.offsets.is_empty() {
+            return;
+        }
+
+        let font = self.style.font.as_ref();
+        self.offsets = std::iter::once(0.0)
+            .chain(text.char_indices().map(|(byte, c)| {
+                backend.measure_text(&text[..byte + c.len_utf8()], font, 16, self.font_scale).width
+            }))
+            .collect();
+        self.measured = text;
+    }
+
+    /// The caret position nearest to `x` (in screen coordinates).
+    fn caret_at(&self, x: f32) -> usize {
+        let x = x - (self.rect.x + PADDING - self.scroll_x);
+        let mut nearest = 0;
+        for (idx, offset) in self.offsets.iter().enumerate() {
+            if (offset - x).abs() < (self.offsets[nearest] - x).abs() {
+                nearest = idx;
+            }
+        }
+        nearest
+    }
+
+    fn update_keys(&mut self, input: &dyn InputSource) {
+        let shift = shift_down(input);
+        let ctrl = ctrl_down(input);
+        let (caret, anchor) = (self.caret, self.anchor);
+
+        if !ctrl || alt_down(input) {
+            let typed: String = input.chars_pressed().into_iter().collect();
+            self.insert(&typed);
+        }
+        // (The keys below see the text as typed into.)
+        let len = self.text.chars().count();
+
+        // Moving the caret (Shift extends the selection, else a selection collapses to its side).
+        let mut moved = None;
+        if self.repeat.pressed(input, KeyCode::Left) {
+            moved = Some(match (self.selection(), shift) {
+                (Some((start, _)), false) => start,
+                _ => self.caret.saturating_sub(1),
+            });
+        }
+        if self.repeat.pressed(input, KeyCode::Right) {
+            moved = Some(match (self.selection(), shift) {
+                (Some((_, end)), false) => end,
+                _ => (self.caret + 1).min(len),
+            });
+        }
+        if input.is_key_pressed(KeyCode::Home) {
+            moved = Some(0);
+        }
+        if input.is_key_pressed(KeyCode::End) {
+            moved = Some(len);
+        }
+        if let Some(caret) = moved {
+            self.caret = caret;
+            if !shift {
+                self.anchor = caret;
+            }
+        }
+
+        if self.repeat.pressed(input, KeyCode::Backspace) && !self.delete_selection() && self.caret > 0 {
+            self.select(self.caret - 1, self.caret);
+            self.delete_selection();
+        }
+        if self.repeat.pressed(input, KeyCode::Delete) && !self.delete_selection() && self.caret < len {
+            self.select(self.caret, self.caret + 1);
+            self.delete_selection();
+        }
+
+        if ctrl && input.is_key_pressed(KeyCode::A) {
+            self.select(0, len);
+        }
+        if ctrl && (input.is_key_pressed(KeyCode::C) || input.is_key_pressed(KeyCode::X)) && !self.password {
+            if let Some((start, end)) = self.selection() {
+                input.clipboard_set(&self.text[self.byte(start)..self.byte(end)]);
+                if input.is_key_pressed(KeyCode::X) {
+                    self.delete_selection();
+                }
+            }
+        }
+        if ctrl && input.is_key_pressed(KeyCode::V) {
+            if let Some(text) = input.clipboard_get() {
+                self.insert(&text.replace(['\r', '\n'], " "));
+            }
+        }
+
+        if input.is_key_pressed(KeyCode::Enter) || input.is_key_pressed(KeyCode::KpEnter) {
+            self.enter_pressed = true;
+        }
+
+        if (self.caret, self.anchor) != (caret, anchor) {
+            self.blink = self.time;
+        }
+    }
+}
+
+impl WidgetImpl for TextInput {
+    fn kind(&self) -> &'static str {
+        "TextInput"
+    }
+
+    fn label(&self) -> String {
+        self.hint.clone()
+    }
+
+    fn measure(&mut self, ctx: &WidgetContext) -> Vec2 {
+        let dim = ctx.backend.measure_text("Ay", None, 16, 1f32);
+        let dim_some = ctx.backend.measure_text("Ay", self.style.font.as_ref(), 16, 1f32);
+
+        self.text_height = dim.height;
+        self.font_scale = dim.height / dim_some.height;
+        vec2(self.width, 21.0)
+    }
+
+    fn update(&mut self, rect: Rect, ctx: &WidgetContext) {
+        self.rect = rect;
+        self.changed = false;
+        self.enter_pressed = false;
+        self.time = ctx.backend.time();
+        let len = self.text.chars().count();
+        self.caret = self.caret.min(len);
+        self.anchor = self.anchor.min(len);
+        self.measure_text(ctx.backend);
+
+        if !ctx.focused && self.focused {
+            self.anchor = self.caret;
+        }
+        self.focused = ctx.focused;
+
+        if !ctx.backend.is_mouse_button_down(MouseButton::Left) {
+            self.pressed = false;
+        }
+
+        self.hovering = self.rect.contains(ctx.mouse_position);
+        if self.hovering && ctx.backend.is_mouse_button_pressed(MouseButton::Left) && ctx.selected {
+            let shift = shift_down(ctx.backend);
+            self.pressed = true;
+            self.caret = self.caret_at(ctx.mouse_position.x);
+            if !shift {
+                self.anchor = self.caret;
+            }
+            self.blink = self.time;
+            ctx.request_focus();
+        } else if self.pressed {
+            self.caret = self.caret_at(ctx.mouse_position.x);
+        }
+
+        if self.focused {
+            self.update_keys(ctx.backend);
+        }
+
+        // Scroll the text to keep the caret in the box.
+        self.measure_text(ctx.backend);
+        let inner = (self.rect.w - PADDING * 2.0).max(0.0);
+        let caret_x = self.offsets[self.caret];
+        if caret_x - self.scroll_x > inner {
+            self.scroll_x = caret_x - inner;
+        } else if caret_x < self.scroll_x {
+            self.scroll_x = caret_x;
+        }
+        let width = self.offsets.last().copied().unwrap_or(0.0);
+        self.scroll_x = self.scroll_x.clamp(0.0, (width - inner).max(0.0));
+    }
+
+    fn render(&mut self, draw: &mut DrawList) {
+        draw.draw_rectangle(
+            self.rect.x,
+            self.rect.y,
+            self.rect.w,
+            self.rect.h,
+            match (self.focused, self.hovering) {
+                (true, _) => self.style.focused_bg_color,
+                (false, true) => self.style.hover_bg_color,
+                _ => self.style.bg_color,
+            },
+        );
+
+        draw.push_clip(Rect::new(
+            self.rect.x + PADDING / 2.0,
+            self.rect.y,
+            self.rect.w - PADDING,
+            self.rect.h,
+        ));
+
+        let x = self.rect.x + PADDING - self.scroll_x;
+        if let Some((start, end)) = self.selection().filter(|_| self.focused) {
+            draw.draw_rectangle(
+                x + self.offsets[start],
+                self.rect.y + 3.0,
+                self.offsets[end] - self.offsets[start],
+                self.rect.h - 6.0,
+                self.style.selection_color,
+            );
+        }
+
+        let (text, color) = match self.text.is_empty() && !self.focused {
+            true => (self.hint.clone(), self.style.hint_color),
+            false => (self.measured.clone(), self.style.color),
+        };
+        draw.draw_text_ex(
+            &text,
+            f32::floor(x),
+            f32::floor(self.rect.y + self.rect.h / 2.0 + self.text_height / 2.0),
+            TextParams {
+                font: self.style.font.as_ref(),
+                font_size: 16,
+                color,
+                font_scale: self.font_scale,
+                ..Default::default()
+            },
+        );
+
+        // Caret (blinking, shown right after it moves).
+        if self.focused && (self.time - self.blink).rem_euclid(1.0) < 0.5 {
+            let caret_x = f32::floor(x + self.offsets[self.caret]) + 0.5;
+            draw.draw_line(
+                caret_x,
+                self.rect.y + 3.0,
+                caret_x,
+                self.rect.bottom() - 3.0,
+                1.0,
+                self.style.caret_color,
+            );
+        }
+
+        draw.pop_clip();
+    }
+
+    fn is_active(&self) -> bool {
+        self.pressed
+    }
+
+    fn wants_keyboard(&self) -> bool {
+        self.focused
+    }
+
+    fn focusable(&self) -> bool {
+        true
+    }
+
+    fn transfer_state(&mut self, previous: &mut dyn WidgetImpl) {
+        if let Some(previous) = previous.downcast_mut::<TextInput>() {
+            self.text = std::mem::take(&mut previous.text);
+            self.hovering = previous.hovering;
+            self.pressed = previous.pressed;
+            self.focused = previous.focused;
+            self.changed = previous.changed;
+            self.enter_pressed = previous.enter_pressed;
+            self.caret = previous.caret;
+            self.anchor = previous.anchor;
+            self.scroll_x = previous.scroll_x;
+            self.offsets = std::mem::take(&mut previous.offsets);
+            self.measured = std::mem::take(&mut previous.measured);
+            self.repeat = previous.repeat;
+            self.blink = previous.blink;
+            self.time = previous.time;
+        }
+    }
+}
@@ -1,9 +1,11 @@
 #![allow(non_snake_case)]
+use macroquad::prelude::*;
+
 use super::super::*;
-use std::fmt::Error;
 
 /// Widget > Widget (Base enum for all widgets).
 #[derive(Clone, Debug)]
+#[allow(clippy::large_enum_variant)] // Widgets live in `Vec`s, boxing them would only add indirection.
 pub enum Widget {
     Text(Text),
     Button(Button),
@@ -11,64 +13,425 @@ pub enum Widget {
     Slider(Slider),
     DisplayImage(DisplayImage),
     Checkbox(Checkbox),
+    RadioButton(RadioButton),
+    TextInput(TextInput),
+    TextEdit(TextEdit),
+    NumberField(NumberField),
+    Combo(Combo),
+    ListBox(ListBox),
+    ProgressBar(ProgressBar),
+    Spinner(Spinner),
+    Separator(Separator),
+    Dummy(Dummy),
+    ColorEdit(ColorEdit),
+    /// A user-defined widget (see `WidgetImpl`).
+    Custom(CustomWidget),
 }
 
 impl Widget {
-    pub fn equate(&self, other: &mut Self) -> bool {
+    /// The widget as its `WidgetImpl` (the boxed widget itself for `Widget::Custom`).
+    pub fn as_dyn(&self) -> &dyn WidgetImpl {
         match self {
-            Widget::Text(i) => i.equate(other.as_text()),
-            Widget::Button(i) => i.equate(other.as_button()),
-            Widget::Slider(i) => {
-                let x = i.equate(other.as_slider());
-                return x
-            },
-            Widget::DisplayImage(i) => i.equate(other.as_image()),
-            Widget::WidgetRow(i) => i.equate(other.as_widget_row()),
-            Widget::Checkbox(i) => i.equate(other.as_checkbox()),
-            _ => false,
+            Widget::Text(i) => i,
+            Widget::Button(i) => i,
+            Widget::WidgetRow(i) => i,
+            Widget::Slider(i) => i,
+            Widget::DisplayImage(i) => i,
+            Widget::Checkbox(i) => i,
+            Widget::RadioButton(i) => i,
+            Widget::TextInput(i) => i,
+            Widget::TextEdit(i) => i,
+            Widget::NumberField(i) => i,
+            Widget::Combo(i) => i,
+            Widget::ListBox(i) => i,
+            Widget::ProgressBar(i) => i,
+            Widget::Spinner(i) => i,
+            Widget::Separator(i) => i,
+            Widget::Dummy(i) => i,
+            Widget::ColorEdit(i) => i,
+            Widget::Custom(i) => i.widget.as_ref(),
         }
     }
-    pub fn as_text(&mut self) -> &mut Text {
+
+    /// The widget as its `WidgetImpl` (the boxed widget itself for `Widget::Custom`).
+    pub fn as_dyn_mut(&mut self) -> &mut dyn WidgetImpl {
         match self {
-            Widget::Text(ref mut obj) => Ok(obj),
-            _ => Err(Error),
+            Widget::Text(i) => i,
+            Widget::Button(i) => i,
+            Widget::WidgetRow(i) => i,
+            Widget::Slider(i) => i,
+            Widget::DisplayImage(i) => i,
+            Widget::Checkbox(i) => i,
+            Widget::RadioButton(i) => i,
+            Widget::TextInput(i) => i,
+            Widget::TextEdit(i) => i,
+            Widget::NumberField(i) => i,
+            Widget::Combo(i) => i,
+            Widget::ListBox(i) => i,
+            Widget::ProgressBar(i) => i,
+            Widget::Spinner(i) => i,
+            Widget::Separator(i) => i,
+            Widget::Dummy(i) => i,
+            Widget::ColorEdit(i) => i,
+            Widget::Custom(i) => i.widget.as_mut(),
         }
-        .unwrap()
     }
-    pub fn as_button(&mut self) -> &mut Button {
+
+    /// The widget's ID (assigned when it is pushed, stable across frames).
+    pub fn id(&self) -> u64 {
         match self {
-            Widget::Button(ref mut obj) => Ok(obj),
-            _ => Err(Error),
+            Widget::Text(i) => i.id,
+            Widget::Button(i) => i.id,
+            Widget::WidgetRow(i) => i.id,
+            Widget::Slider(i) => i.id,
+            Widget::DisplayImage(i) => i.id,
+            Widget::Checkbox(i) => i.id,
+            Widget::RadioButton(i) => i.id,
+            Widget::TextInput(i) => i.id,
+            Widget::TextEdit(i) => i.id,
+            Widget::NumberField(i) => i.id,
+            Widget::Combo(i) => i.id,
+            Widget::ListBox(i) => i.id,
+            Widget::ProgressBar(i) => i.id,
+            Widget::Spinner(i) => i.id,
+            Widget::Separator(i) => i.id,
+            Widget::Dummy(i) => i.id,
+            Widget::ColorEdit(i) => i.id,
+            Widget::Custom(i) => i.id,
         }
-        .unwrap()
     }
-    pub fn as_widget_row(&mut self) -> &mut WidgetRow {
+
+    /// The widget's (user assigned) uuid, `""` if it has none.
+    pub fn uuid(&self) -> &str {
         match self {
-            Widget::WidgetRow(ref mut obj) => Ok(obj),
-            _ => Err(Error),
+            Widget::Text(i) => &i.uuid,
+            Widget::Button(i) => &i.uuid,
+            Widget::WidgetRow(i) => &i.uuid,
+            Widget::Slider(i) => &i.uuid,
+            Widget::DisplayImage(i) => &i.uuid,
+            Widget::Checkbox(i) => &i.uuid,
+            Widget::RadioButton(i) => &i.uuid,
+            Widget::TextInput(i) => &i.uuid,
+            Widget::TextEdit(i) => &i.uuid,
+            Widget::NumberField(i) => &i.uuid,
+            Widget::Combo(i) => &i.uuid,
+            Widget::ListBox(i) => &i.uuid,
+            Widget::ProgressBar(i) => &i.uuid,
+            Widget::Spinner(i) => &i.uuid,
+            Widget::Separator(i) => &i.uuid,
+            Widget::Dummy(i) => &i.uuid,
+            Widget::ColorEdit(i) => &i.uuid,
+            Widget::Custom(i) => &i.uuid,
         }
-        .unwrap()
     }
-    pub fn as_slider(&mut self) -> &mut Slider {
+
+    fn uuid_mut(&mut self) -> &mut String {
+        match self {
+            Widget::Text(i) => &mut i.uuid,
+            Widget::Button(i) => &mut i.uuid,
+            Widget::WidgetRow(i) => &mut i.uuid,
+            Widget::Slider(i) => &mut i.uuid,
+            Widget::DisplayImage(i) => &mut i.uuid,
+            Widget::Checkbox(i) => &mut i.uuid,
+            Widget::RadioButton(i) => &mut i.uuid,
+            Widget::TextInput(i) => &mut i.uuid,
+            Widget::TextEdit(i) => &mut i.uuid,
+            Widget::NumberField(i) => &mut i.uuid,
+            Widget::Combo(i) => &mut i.uuid,
+            Widget::ListBox(i) => &mut i.uuid,
+            Widget::ProgressBar(i) => &mut i.uuid,
+            Widget::Spinner(i) => &mut i.uuid,
+            Widget::Separator(i) => &mut i.uuid,
+            Widget::Dummy(i) => &mut i.uuid,
+            Widget::ColorEdit(i) => &mut i.uuid,
+            Widget::Custom(i) => &mut i.uuid,
+        }
+    }
+
+    fn set_id(&mut self, id: u64) {
+        match self {
+            Widget::Text(i) => i.id = id,
+            Widget::Button(i) => i.id = id,
+            Widget::WidgetRow(i) => i.id = id,
+            Widget::Slider(i) => i.id = id,
+            Widget::DisplayImage(i) => i.id = id,
+            Widget::Checkbox(i) => i.id = id,
+            Widget::RadioButton(i) => i.id = id,
+            Widget::TextInput(i) => i.id = id,
+            Widget::TextEdit(i) => i.id = id,
+            Widget::NumberField(i) => i.id = id,
+            Widget::Combo(i) => i.id = id,
+            Widget::ListBox(i) => i.id = id,
+            Widget::ProgressBar(i) => i.id = id,
+            Widget::Spinner(i) => i.id = id,
+            Widget::Separator(i) => i.id = id,
+            Widget::Dummy(i) => i.id = id,
+            Widget::ColorEdit(i) => i.id = id,
+            Widget::Custom(i) => i.id = id,
+        }
+    }
+
+    /// The rect the widget was laid out in (as of its last update).
+    pub fn rect(&self) -> Rect {
+        match self {
+            Widget::Text(i) => i.rect,
+            Widget::Button(i) => i.rect,
+            Widget::WidgetRow(i) => i.rect,
+            Widget::Slider(i) => i.rect,
+            Widget::DisplayImage(i) => i.rect,
+            Widget::Checkbox(i) => i.rect,
+            Widget::RadioButton(i) => i.rect,
+            Widget::TextInput(i) => i.rect,
+            Widget::TextEdit(i) => i.rect,
+            Widget::NumberField(i) => i.rect,
+            Widget::Combo(i) => i.rect,
+            Widget::ListBox(i) => i.rect,
+            Widget::ProgressBar(i) => i.rect,
+            Widget::Spinner(i) => i.rect,
+            Widget::Separator(i) => i.rect,
+            Widget::Dummy(i) => i.rect,
+            Widget::ColorEdit(i) => i.rect,
+            Widget::Custom(i) => i.rect,
+        }
+    }
+
+    /// Where the focus ring goes: the widget's rect, or just the header line of a collapsing header/tree node (the tabs of a tab bar).
+    pub(crate) fn focus_rect(&self) -> Rect {
         match self {
-            Widget::Slider(ref mut obj) => Ok(obj),
-            _ => Err(Error),
+            Widget::WidgetRow(WidgetRow { header: Some(header), .. }) => header.rect,
+            Widget::WidgetRow(WidgetRow { tabs: Some(tabs), .. }) => tabs.rect,
+            _ => self.rect(),
+        }
+    }
+
+    /// What the ID is derived from: the kind of widget + its uuid if it has one, else its label.
+    fn id_source(&self) -> (&'static str, String) {
+        if !self.uuid().is_empty() {
+            return ("uuid", self.uuid().to_owned());
+        }
+
+        (self.as_dyn().kind(), self.as_dyn().label())
+    }
+
+    /// Carry interaction state (values, hover/press, children, uuid) over from last frame's `previous`.
+    pub fn transfer_state(&mut self, previous: &mut Widget) {
+        if self.uuid().is_empty() {
+            *self.uuid_mut() = std::mem::take(previous.uuid_mut());
+        }
+
+        self.as_dyn_mut().transfer_state(previous.as_dyn_mut());
+    }
+
+    /// The size the widget wants this frame (see `WidgetImpl::measure`).
+    pub fn measure(&mut self, ctx: &WidgetContext) -> Vec2 {
+        self.as_dyn_mut().measure(ctx)
+    }
+
+    /// Lay the widget out in `rect` and handle this frame's input (see `WidgetImpl::update`).
+    pub fn update(&mut self, rect: Rect, ctx: &WidgetContext) {
+        if let Widget::Custom(i) = self {
+            i.rect = rect;
+        }
+        let mut ctx = WidgetContext {
+            focused: ctx.focus == Some(self.id()),
+            widget_id: self.id(),
+            ..*ctx
+        };
+        // Over a popup, only its widget (and the rows holding it) see the mouse.
+        if let Some((owner, _)) = ctx.popup.filter(|_| ctx.over_popup()) {
+            let holds = match self {
+                Widget::WidgetRow(row) => find_id(&row.widgets, owner).is_some(),
+                _ => self.id() == owner,
+            };
+            if !holds {
+                ctx.mouse_position = NOWHERE;
+            }
+        }
+        self.as_dyn_mut().update(rect, &ctx);
+    }
+
+    /// Record the widget's drawing (see `WidgetImpl::render`).
+    pub fn render(&mut self, draw: &mut DrawList) {
+        self.as_dyn_mut().render(draw);
+    }
+
+    /// Whether `other` is the same kind of widget, set up the same way (widgets of different kinds never are).
+    pub fn equate(&self, other: &mut Self) -> bool {
+        match (self, other) {
+            (Widget::Text(i), Widget::Text(other)) => i.equate(other),
+            (Widget::Button(i), Widget::Button(other)) => i.equate(other),
+            (Widget::Slider(i), Widget::Slider(other)) => i.equate(other),
+            (Widget::DisplayImage(i), Widget::DisplayImage(other)) => i.equate(other),
+            (Widget::WidgetRow(i), Widget::WidgetRow(other)) => i.equate(other),
+            (Widget::Checkbox(i), Widget::Checkbox(other)) => i.equate(other),
+            (Widget::Combo(i), Widget::Combo(other)) => i.equate(other),
+            (Widget::ListBox(i), Widget::ListBox(other)) => i.equate(other),
+            // The rest (custom widgets too) are told apart by their kind and label.
+            (i, other) => i.as_dyn().kind() == other.as_dyn().kind() && i.as_dyn().label() == other.as_dyn().label(),
         }
-        .unwrap()
+    }
+    pub fn as_text(&mut self) -> &mut Text {
+        self.try_as_text().expect("widget is not a `Text`")
+    }
+    pub fn as_button(&mut self) -> &mut Button {
+        self.try_as_button().expect("widget is not a `Button`")
+    }
+    pub fn as_widget_row(&mut self) -> &mut WidgetRow {
+        self.try_as_widget_row().expect("widget is not a `WidgetRow`")
+    }
+    pub fn as_slider(&mut self) -> &mut Slider {
+        self.try_as_slider().expect("widget is not a `Slider`")
     }
     pub fn as_image(&mut self) -> &mut DisplayImage {
+        self.try_as_image().expect("widget is not a `DisplayImage`")
+    }
+    pub fn as_checkbox(&mut self) -> &mut Checkbox {
+        self.try_as_checkbox().expect("widget is not a `Checkbox`")
+    }
+    pub fn as_radio_button(&mut self) -> &mut RadioButton {
+        self.try_as_radio_button().expect("widget is not a `RadioButton`")
+    }
+    pub fn as_text_input(&mut self) -> &mut TextInput {
+        self.try_as_text_input().expect("widget is not a `TextInput`")
+    }
+    pub fn as_text_edit(&mut self) -> &mut TextEdit {
+        self.try_as_text_edit().expect("widget is not a `TextEdit`")
+    }
+    pub fn as_number_field(&mut self) -> &mut NumberField {
+        self.try_as_number_field().expect("widget is not a `NumberField`")
+    }
+    pub fn as_combo(&mut self) -> &mut Combo {
+        self.try_as_combo().expect("widget is not a `Combo`")
+    }
+    pub fn as_list_box(&mut self) -> &mut ListBox {
+        self.try_as_list_box().expect("widget is not a `ListBox`")
+    }
+    pub fn as_progress_bar(&mut self) -> &mut ProgressBar {
+        self.try_as_progress_bar().expect("widget is not a `ProgressBar`")
+    }
+    pub fn as_spinner(&mut self) -> &mut Spinner {
+        self.try_as_spinner().expect("widget is not a `Spinner`")
+    }
+    pub fn as_separator(&mut self) -> &mut Separator {
+        self.try_as_separator().expect("widget is not a `Separator`")
+    }
+    pub fn as_dummy(&mut self) -> &mut Dummy {
+        self.try_as_dummy().expect("widget is not a `Dummy`")
+    }
+    pub fn as_color_edit(&mut self) -> &mut ColorEdit {
+        self.try_as_color_edit().expect("widget is not a `ColorEdit`")
+    }
+    pub fn as_custom<T: WidgetImpl>(&mut self) -> &mut T {
+        self.try_as_custom().expect("widget is not a custom widget of that type")
+    }
+
+    pub fn try_as_text(&mut self) -> Option<&mut Text> {
+        match self {
+            Widget::Text(ref mut obj) => Some(obj),
+            _ => None,
+        }
+    }
+    pub fn try_as_button(&mut self) -> Option<&mut Button> {
+        match self {
+            Widget::Button(ref mut obj) => Some(obj),
+            _ => None,
+        }
+    }
+    pub fn try_as_widget_row(&mut self) -> Option<&mut WidgetRow> {
+        match self {
+            Widget::WidgetRow(ref mut obj) => Some(obj),
+            _ => None,
+        }
+    }
+    pub fn try_as_slider(&mut self) -> Option<&mut Slider> {
+        match self {
+            Widget::Slider(ref mut obj) => Some(obj),
+            _ => None,
+        }
+    }
+    pub fn try_as_image(&mut self) -> Option<&mut DisplayImage> {
+        match self {
+            Widget::DisplayImage(ref mut obj) => Some(obj),
+            _ => None,
+        }
+    }
+    pub fn try_as_checkbox(&mut self) -> Option<&mut Checkbox> {
+        match self {
+            Widget::Checkbox(ref mut obj) => Some(obj),
+            _ => None,
+        }
+    }
+    pub fn try_as_radio_button(&mut self) -> Option<&mut RadioButton> {
+        match self {
+            Widget::RadioButton(ref mut obj) => Some(obj),
+            _ => None,
+        }
+    }
+    pub fn try_as_text_input(&mut self) -> Option<&mut TextInput> {
+        match self {
+            Widget::TextInput(ref mut obj) => Some(obj),
+            _ => None,
+        }
+    }
+    pub fn try_as_text_edit(&mut self) -> Option<&mut TextEdit> {
+        match self {
+            Widget::TextEdit(ref mut obj) => Some(obj),
+            _ => None,
+        }
+    }
+    pub fn try_as_number_field(&mut self) -> Option<&mut NumberField> {
+        match self {
+            Widget::NumberField(ref mut obj) => Some(obj),
+            _ => None,
+        }
+    }
+    pub fn try_as_combo(&mut self) -> Option<&mut Combo> {
+        match self {
+            Widget::Combo(ref mut obj) => Some(obj),
+            _ => None,
+        }
+    }
+    pub fn try_as_list_box(&mut self) -> Option<&mut ListBox> {
+        match self {
+            Widget::ListBox(ref mut obj) => Some(obj),
+            _ => None,
+        }
+    }
+    pub fn try_as_progress_bar(&mut self) -> Option<&mut ProgressBar> {
+        match self {
+            Widget::ProgressBar(ref mut obj) => Some(obj),
+            _ => None,
+        }
+    }
+    pub fn try_as_spinner(&mut self) -> Option<&mut Spinner> {
+        match self {
+            Widget::Spinner(ref mut obj) => Some(obj),
+            _ => None,
+        }
+    }
+    pub fn try_as_separator(&mut self) -> Option<&mut Separator> {
+        match self {
+            Widget::Separator(ref mut obj) => Some(obj),
+            _ => None,
+        }
+    }
+    pub fn try_as_dummy(&mut self) -> Option<&mut Dummy> {
+        match self {
+            Widget::Dummy(ref mut obj) => Some(obj),
+            _ => None,
+        }
+    }
+    pub fn try_as_color_edit(&mut self) -> Option<&mut ColorEdit> {
         match self {
-            Widget::DisplayImage(ref mut obj) => Ok(obj),
-            _ => Err(Error),
+            Widget::ColorEdit(ref mut obj) => Some(obj),
+            _ => None,
         }
-        .unwrap()
     }
-    pub fn as_checkbox (&mut self) -> &mut Checkbox {
+    pub fn try_as_custom<T: WidgetImpl>(&mut self) -> Option<&mut T> {
         match self {
-            Widget::Checkbox(ref mut obj) => Ok(obj),
-            _ => Err(Error),
+            Widget::Custom(ref mut obj) => obj.widget.downcast_mut(),
+            _ => None,
         }
-        .unwrap()
     }
 }
 
@@ -78,19 +441,14 @@ trait Equate {
 
 impl Equate for Text {
     fn equate(&self, other: &mut Self) -> bool {
-        // self.text == other.text
-        //     && self.rect == other.rect
-        //     && self.color == other.color
-        true
+        self.text == other.text
+            && self.color == other.color
+            && self.font_scale == other.font_scale
     }
 }
 impl Equate for Button {
     fn equate(&self, other: &mut Self) -> bool {
         self.text == other.text
-            && self.rect.w == other.rect.w
-            && self.rect.h == other.rect.h
-            && self.button_rect.w == other.button_rect.w
-            && self.button_rect.h == other.button_rect.h
     }
 }
 impl Equate for Slider {
@@ -108,23 +466,115 @@ impl Equate for DisplayImage {
 }
 impl Equate for WidgetRow {
     fn equate(&self, other: &mut Self) -> bool {
-        let mut idx = 0;
-        for i in self.widgets.iter() {
-            let equal = match i {
-                Widget::Text(i) => i.equate(other.widgets[idx].as_text()),
-                Widget::Button(i) => i.equate(other.widgets[idx].as_button()),
-                Widget::Slider(i) => i.equate(other.widgets[idx].as_slider()),
-                Widget::DisplayImage(i) => i.equate(other.widgets[idx].as_image()),
-                _ => true,
-            };
-            idx += 1;
-        }
-        return true;
+        self.widgets.len() == other.widgets.len()
+            && self.widgets.iter().zip(other.widgets.iter_mut()).all(|(i, other)| i.equate(other))
     }
 }
 impl Equate for Checkbox {
     fn equate(&self, other: &mut Self) -> bool {
-        self.rect.w == other.rect.w
-            && self.rect.h == other.rect.h
+        self.text == other.text
+    }
+}
+impl Equate for Combo {
+    fn equate(&self, other: &mut Self) -> bool {
+        self.label == other.label && self.items == other.items
+    }
+}
+impl Equate for ListBox {
+    fn equate(&self, other: &mut Self) -> bool {
+        self.label == other.label && self.items == other.items
+    }
+}
+
+/// Give `widget` its ID within scope `seed`, then put it at `idx` in `widgets`.
+///
+/// Last frame's widget with the same ID is moved to `idx` and its state carried over,
+/// so state follows the widget even when widgets above it come and go.
+/// `frame_pushed` (this frame's widgets so far) makes duplicate labels get distinct IDs.
+pub(crate) fn push_with_id(
+    widgets: &mut Vec<Widget>,
+    frame_pushed: &[Widget],
+    seed: u64,
+    widget: &mut Widget,
+) -> usize {
+    let idx = frame_pushed.len();
+
+    let mut id = hash_id(seed, &widget.id_source());
+    while frame_pushed.iter().any(|i| i.id() == id) {
+        id = hash_id(id, "duplicate");
+    }
+    widget.set_id(id);
+
+    let previous = widgets.iter().skip(idx).position(|i| i.id() == id);
+    if let Some(previous) = previous {
+        let mut previous = widgets.remove(idx + previous);
+        widget.transfer_state(&mut previous);
+    }
+    widgets.insert(idx, widget.clone());
+
+    idx
+}
+
+/// Find the widget with `uuid` in `widgets`, searching inside `WidgetRow`s too.
+pub(crate) fn find_uuid<'a>(widgets: &'a mut [Widget], uuid: &str) -> Option<&'a mut Widget> {
+    for i in widgets.iter_mut() {
+        if i.uuid() == uuid {
+            return Some(i);
+        }
+        if let Widget::WidgetRow(row) = i {
+            if let Some(found) = find_uuid(&mut row.widgets, uuid) {
+                return Some(found);
+            }
+        }
+    }
+    None
+}
+
+/// Find the widget with `id` in `widgets`, searching inside (open) `WidgetRow`s too.
+pub(crate) fn find_id(widgets: &[Widget], id: u64) -> Option<&Widget> {
+    for i in widgets.iter() {
+        if i.id() == id {
+            return Some(i);
+        }
+        match i {
+            Widget::WidgetRow(row) if row.is_open() => {
+                if let Some(found) = find_id(&row.widgets, id) {
+                    return Some(found);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Find the widget with a popup open in `widgets` (searching inside open `WidgetRow`s too): its ID and the popup's rect.
+pub(crate) fn find_popup(widgets: &[Widget]) -> Option<(u64, Rect)> {
+    for i in widgets.iter() {
+        if let Some(rect) = i.as_dyn().popup() {
+            return Some((i.id(), rect));
+        }
+        match i {
+            Widget::WidgetRow(row) if row.is_open() => {
+                if let Some(found) = find_popup(&row.widgets) {
+                    return Some(found);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Collect the IDs of the focusable widgets in `widgets` (and their open `WidgetRow`s), in order.
+pub(crate) fn focusable_ids(widgets: &[Widget], ids: &mut Vec<u64>) {
+    for i in widgets.iter() {
+        if i.as_dyn().focusable() {
+            ids.push(i.id());
+        }
+        match i {
+            Widget::WidgetRow(row) if row.is_open() => focusable_ids(&row.widgets, ids),
+            _ => {}
+        }
     }
 }
@@ -0,0 +1,189 @@
+use std::any::Any;
+use std::cell::Cell;
+use std::fmt::Debug;
+
+use macroquad::prelude::*;
+
+use super::super::*;
+
+/// Widget > WidgetContext (Input + backend handed to widgets while they are measured and updated).
+#[derive(Clone, Copy)]
+pub struct WidgetContext<'a> {
+    pub backend: &'a dyn Backend,
+    /// Whether the widget's window is selected (widgets only take presses when it is).
+    pub selected: bool,
+    pub mouse_position: Vec2,
+    pub mouse_released: bool,
+    /// Mouse wheel scrolling (in pixels) no widget has used yet; `take()` it to scroll.
+    pub scroll_delta: &'a Cell<Vec2>,
+    /// Whether the widget being updated has keyboard focus (see `WidgetImpl::focusable`).
+    pub focused: bool,
+    /// ID of the widget with keyboard focus in the (selected) window.
+    pub(crate) focus: Option<u64>,
+    /// ID of the widget being updated.
+    pub(crate) widget_id: u64,
+    /// Widget that asked for focus this frame (see `request_focus()`).
+    pub(crate) focus_request: &'a Cell<Option<u64>>,
+    /// ID and rect of the popup open in the window (see `WidgetImpl::popup`).
+    pub(crate) popup: Option<(u64, Rect)>,
+}
+
+impl WidgetContext<'_> {
+    /// Give the widget being updated keyboard focus (e.g. when it is clicked).
+    pub fn request_focus(&self) {
+        self.focus_request.set(Some(self.widget_id));
+    }
+
+    /// Whether the mouse is over the popup open in the window.
+    pub(crate) fn over_popup(&self) -> bool {
+        self.popup.is_some_and(|(_, rect)| rect.contains(self.mouse_position))
+    }
+}
+
+/// Widget > WidgetImpl (What every widget implements, built-in or custom).
+///
+/// Each frame a widget is measured, given a rect by its window (or row), updated with that
+/// frame's input and then rendered into the window's `DrawList`.
+/// Implement this on a `Clone` type and push it with `Window::Custom()` to make your own widgets.
+///
+/// # Example
+/// ```
+/// use amberwindow::*;
+/// use macroquad::prelude::*;
+///
+/// #[derive(Clone, Debug)]
+/// struct HealthBar {
+///     health: f32,
+///     rect: Rect,
+/// }
+///
+/// impl WidgetImpl for HealthBar {
+///     fn measure(&mut self, _ctx: &WidgetContext) -> Vec2 {
+///         vec2(100., 10.)
+///     }
+///
+///     fn update(&mut self, rect: Rect, _ctx: &WidgetContext) {
+///         self.rect = rect;
+///     }
+///
+///     fn render(&mut self, draw: &mut DrawList) {
+///         draw.draw_rectangle(self.rect.x, self.rect.y, self.rect.w * self.health, self.rect.h, RED);
+///     }
+/// }
+///
+/// let mut windows = WindowManager::with_backend(HeadlessBackend::new(800., 600.));
+/// if let Some(win) = windows.begin("player") {
+///     win.Custom(HealthBar { health: 0.5, rect: Rect::default() });
+/// }
+/// windows.end_windows();
+/// ```
+pub trait WidgetImpl: WidgetClone + Debug + Any {
+    /// Kind of widget, hashed together with `label()` into the widget's ID.
+    fn kind(&self) -> &'static str {
+        std::any::type_name::<Self>()
+    }
+
+    /// Tells apart widgets of the same kind (e.g. a button's text), `""` by default.
+    fn label(&self) -> String {
+        String::new()
+    }
+
+    /// The size the widget wants this frame.
+    fn measure(&mut self, ctx: &WidgetContext) -> Vec2;
+
+    /// Handle this frame's input, in the `rect` the layout gave the widget.
+    fn update(&mut self, rect: Rect, ctx: &WidgetContext);
+
+    /// Record this frame's drawing.
+    fn render(&mut self, draw: &mut DrawList);
+
+    /// Whether the widget holds the mouse (e.g. is being pressed or dragged).
+    fn is_active(&self) -> bool {
+        false
+    }
+
+    /// Whether the widget is taking keyboard input (e.g. a key-binding capture waiting for a key).
+    fn wants_keyboard(&self) -> bool {
+        false
+    }
+
+    /// Whether Tab can move keyboard focus to the widget (it then gets `ctx.focused`).
+    fn focusable(&self) -> bool {
+        false
+    }
+
+    /// Whether the widget takes Tab itself while focused (e.g. to indent), so Tab doesn't move focus on.
+    fn wants_tab(&self) -> bool {
+        false
+    }
+
+    /// Rect of a popup the widget has open (drawn into `DrawList::overlay()`), `None` by default.
+    ///
+    /// The popup is drawn above every window, and only this widget sees the mouse over it (even outside the window).
+    fn popup(&self) -> Option<Rect> {
+        None
+    }
+
+    /// Carry state over from last frame's widget with the same ID (use `previous.downcast_mut()`).
+    fn transfer_state(&mut self, _previous: &mut dyn WidgetImpl) {}
+
+    /// Take on the window's button style (see `Window::button_style`).
+    fn set_button_style(&mut self, _style: &ButtonStyle) {}
+
+    /// Take on the window's slider style (see `Window::slider_style`).
+    fn set_slider_style(&mut self, _style: &SliderStyle) {}
+}
+
+impl dyn WidgetImpl {
+    pub fn downcast_ref<T: WidgetImpl>(&self) -> Option<&T> {
+        let any: &dyn Any = self;
+        any.downcast_ref()
+    }
+
+    pub fn downcast_mut<T: WidgetImpl>(&mut self) -> Option<&mut T> {
+        let any: &mut dyn Any = self;
+        any.downcast_mut()
+    }
+}
+
+/// Widget > WidgetClone (Lets boxed widgets be cloned, implemented for every `Clone` widget).
+pub trait WidgetClone {
+    fn box_clone(&self) -> Box<dyn WidgetImpl>;
+}
+
+impl<T: WidgetImpl + Clone> WidgetClone for T {
+    fn box_clone(&self) -> Box<dyn WidgetImpl> {
+        Box::new(self.clone())
+    }
+}
+
+impl Clone for Box<dyn WidgetImpl> {
+    fn clone(&self) -> Self {
+        self.box_clone()
+    }
+}
+
+/// Widget > CustomWidget (A user-defined `WidgetImpl`, as stored in a window or row).
+#[derive(Clone, Debug)]
+pub struct CustomWidget {
+    pub widget: Box<dyn WidgetImpl>,
+    pub rect: Rect,
+    pub uuid: String,
+    pub id: u64,
+}
+
+impl CustomWidget {
+    pub fn new(widget: impl WidgetImpl, uuid: Option<&str>) -> Self {
+        Self {
+            widget: Box::new(widget),
+            rect: Rect::new(0., 0., 0., 0.),
+            uuid: uuid.unwrap_or_default().to_owned(),
+            id: 0,
+        }
+    }
+
+    pub fn set_uuid(&mut self, uuid: impl Into<String>) -> &mut Self {
+        self.uuid = uuid.into();
+        self
+    }
+}
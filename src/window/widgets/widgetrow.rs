@@ -1,45 +1,279 @@
+use std::cell::Cell;
+use std::collections::HashMap;
+
 use macroquad::prelude::*;
 
 use super::super::*;
 
-/// Widget > WidgetRow (Horizontally places widgets).
+/// Widget > WidgetRow (Places widgets horizontally, or vertically as a column).
 /// # WidgetRow
 ///
-/// Allows for placing widgets into horizontally, as opposed to only in rows.
-/// **You cannot have WidgetRows inside of WidgetRows**.
+/// Allows for placing widgets horizontally, as opposed to only in rows.
+/// Rows and columns can be nested in each other, and report their combined size to the parent's layout:
+/// ```no_run
+/// # use amberwindow::*;
+/// # use macroquad::prelude::*;
+/// # let mut windows = WindowManager::new();
+/// # let win = windows.begin("window").unwrap();
+/// let form = win.WidgetRow().unwrap();
+/// let labels = form.WidgetColumn().unwrap();
+/// labels.Text("Name", None);
+/// labels.Text("Volume", None);
+/// let controls = form.WidgetColumn().unwrap();
+/// controls.Button("Player 1");
+/// controls.Slider_float(0., 100., None, vec2(100., 15.));
+/// ```
 #[derive(Clone, Debug)]
 pub struct WidgetRow {
     pub widgets: Vec<Widget>,
     pub rect: Rect,
-    pub uuid: &'static str,
+    pub uuid: String,
+    pub id: u64,
     pub frame_pushed: Vec<Widget>,
     pub font: Option<Font>,
     pub remaining: f32,
     pub window_width: f32,
+    id_stack: Vec<u64>,
+    sizes: Vec<Vec2>,
+    /// Space between the row's widgets.
+    pub spacing: f32,
+    /// Alignment of the row's widgets (vertical in a row, horizontal in a column).
+    pub align: Align,
+    pub direction: Direction,
+    /// Size of a scroll area: set, the row keeps this size and scrolls its widgets inside it.
+    pub area: Option<Vec2>,
+    pub scroll: Scroll,
+    pub scrollbar_style: ScrollbarStyle,
+    /// Header of a collapsing header or tree node: set, the row's widgets are only shown while it is open.
+    pub header: Option<Header>,
+    /// Tabs of a tab bar: set, the row's widgets are the selected tab's page, shown under the tabs.
+    pub tabs: Option<TabStrip>,
+    /// Widgets of a tab bar's other pages (by the page's ID scope), kept with their state while another tab is selected.
+    pages: HashMap<u64, Vec<Widget>>,
+    /// ID scope of the page the row's widgets belong to.
+    page: Option<u64>,
+    /// Whether the row's widgets were laid out this frame (not while its header is closed).
+    shown: bool,
 }
 
 // BASE IMPL
 impl WidgetRow {
-    pub fn new(font: Option<Font>, uuid: Option<&'static str>, window_width: f32) -> Self {
+    pub fn new(font: Option<Font>, uuid: Option<&str>, window_width: f32) -> Self {
         Self {
             font,
             frame_pushed: vec![],
             widgets: vec![],
             rect: Rect::new(0., 0., 0., 0.),
-            uuid: uuid.unwrap_or(""),
+            uuid: uuid.unwrap_or_default().to_owned(),
+            id: 0,
             remaining: 0f32,
-            window_width
+            window_width,
+            id_stack: vec![],
+            sizes: vec![],
+            spacing: 5.0,
+            align: Align::Center,
+            direction: Direction::Horizontal,
+            area: None,
+            scroll: Scroll::default(),
+            scrollbar_style: ScrollbarStyle::default(),
+            header: None,
+            tabs: None,
+            pages: HashMap::new(),
+            page: None,
+            shown: true,
+        }
+    }
+
+    /// Create a column (a `WidgetRow` placing its widgets vertically).
+    pub fn column(font: Option<Font>, uuid: Option<&str>, window_width: f32) -> Self {
+        Self {
+            align: Align::Start,
+            direction: Direction::Vertical,
+            ..Self::new(font, uuid, window_width)
+        }
+    }
+
+    /// Create a scroll area (a column of `size`, scrolling widgets that don't fit).
+    pub fn scroll_area(font: Option<Font>, uuid: Option<&str>, window_width: f32, size: Vec2) -> Self {
+        Self {
+            area: Some(size),
+            ..Self::column(font, uuid, window_width)
+        }
+    }
+
+    /// Create a collapsing header (a column of widgets under a bar that opens and closes it).
+    pub fn collapsing_header(label: &str, font: Option<Font>, uuid: Option<&str>, window_width: f32) -> Self {
+        Self {
+            header: Some(Header::new(label, HeaderKind::CollapsingHeader, font.clone())),
+            ..Self::column(font, uuid, window_width)
+        }
+    }
+
+    /// Create a tree node (a column of widgets, indented under a line that opens and closes it).
+    pub fn tree_node(label: &str, font: Option<Font>, uuid: Option<&str>, window_width: f32) -> Self {
+        Self {
+            header: Some(Header::new(label, HeaderKind::TreeNode, font.clone())),
+            ..Self::column(font, uuid, window_width)
+        }
+    }
+
+    /// Create a tab bar (a column showing the selected tab's widgets under a row of tabs).
+    pub fn tab_bar(label: &str, font: Option<Font>, uuid: Option<&str>, window_width: f32) -> Self {
+        Self {
+            tabs: Some(TabStrip::new(label, font.clone())),
+            ..Self::column(font, uuid, window_width)
+        }
+    }
+
+    /// Whether the row's widgets are shown (always, unless it has a closed header).
+    pub fn is_open(&self) -> bool {
+        self.header.as_ref().is_none_or(|header| header.open)
+    }
+
+    /// Open or close the row's header.
+    pub fn set_open(&mut self, open: bool) -> &mut Self {
+        if let Some(header) = self.header.as_mut() {
+            header.set_open(open);
+        }
+        self
+    }
+
+    /// Whether the header starts out open (the first frame it is pushed).
+    pub fn default_open(&mut self, open: bool) -> &mut Self {
+        if let Some(header) = self.header.as_mut() {
+            header.default_open(open);
+        }
+        self
+    }
+
+    /// Whether the header was opened or closed this frame.
+    pub fn toggled(&self) -> bool {
+        self.header.as_ref().is_some_and(|header| header.toggled)
+    }
+
+    /// Set how far the widgets under the header are indented.
+    pub fn indent(&mut self, indent: f32) -> &mut Self {
+        if let Some(header) = self.header.as_mut() {
+            header.indent = indent;
+        }
+        self
+    }
+
+    /// The row to push widgets to if it is open, `None` while its header is closed.
+    pub fn body(&mut self) -> Option<&mut Self> {
+        match self.is_open() {
+            true => Some(self),
+            false => None,
+        }
+    }
+
+    /// Add a tab to a tab bar. Returns the row to push the tab's widgets to if it is the selected tab, `None` otherwise
+    /// (or if this isn't a tab bar).
+    pub fn Tab(&mut self, label: &str) -> Option<&mut Self> {
+        let selected = self.tabs.as_mut()?.add(label, false);
+        self.page(label, selected)
+    }
+
+    /// Add a tab with a close button to a tab bar (see `Tab`).
+    /// It is only shown while `open`, which is cleared when the tab is closed.
+    pub fn TabClosable(&mut self, label: &str, open: &mut bool) -> Option<&mut Self> {
+        let tabs = self.tabs.as_mut()?;
+        if tabs.take_closed(label) {
+            *open = false;
+        }
+        if !*open {
+            return None;
+        }
+        let selected = tabs.add(label, true);
+        self.page(label, selected)
+    }
+
+    /// The selected tab's page, its widgets' IDs scoped by its label.
+    fn page(&mut self, label: &str, selected: bool) -> Option<&mut Self> {
+        if !selected {
+            return None;
+        }
+        self.push_id(label);
+        let page = self.id_stack.last().copied();
+        // Put the last selected page's widgets away and bring back this page's.
+        if page != self.page {
+            let widgets = page.and_then(|page| self.pages.remove(&page)).unwrap_or_default();
+            let previous = std::mem::replace(&mut self.widgets, widgets);
+            if let Some(previous_page) = self.page {
+                self.pages.insert(previous_page, previous);
+            }
+            self.page = page;
+        }
+        Some(self)
+    }
+
+    /// Select a tab of a tab bar.
+    pub fn select_tab(&mut self, label: &str) -> &mut Self {
+        if let Some(tabs) = self.tabs.as_mut() {
+            tabs.select(label);
+        }
+        self
+    }
+
+    /// The selected tab of a tab bar.
+    pub fn selected_tab(&self) -> Option<&str> {
+        self.tabs.as_ref()?.selected.as_deref()
+    }
+
+    /// Whether a tab bar's selected tab changed this frame.
+    pub fn tab_changed(&self) -> bool {
+        self.tabs.as_ref().is_some_and(|tabs| tabs.changed)
+    }
+
+    /// Scroll a scroll area's content to `y` (clamped to the content).
+    pub fn set_scroll_y(&mut self, y: f32) -> &mut Self {
+        self.scroll.offset.y = y;
+        self
+    }
+
+    /// Scroll to the bottom of a scroll area's content.
+    pub fn scroll_to_bottom(&mut self) -> &mut Self {
+        self.scroll.offset.y = f32::INFINITY;
+        self
+    }
+
+    /// Height and indent of the header or tabs on top of the row's widgets, if it has any.
+    fn top_line(&self) -> Option<(f32, f32)> {
+        match (self.header.as_ref(), self.tabs.as_ref()) {
+            (Some(header), _) => Some((header.rect.h, header.indent)),
+            (_, Some(tabs)) => Some((tabs.rect.h, 0.0)),
+            _ => None,
         }
     }
 
-    pub fn set_uuid(&mut self, uuid: &'static str) -> &mut Self {
-        self.uuid = uuid;
+    /// Where the row's widgets go: below (and indented from) the header or tabs, if it has any.
+    fn body_rect(&self) -> Rect {
+        match self.top_line() {
+            Some((height, indent)) => {
+                let top = height + self.spacing;
+                Rect::new(
+                    self.rect.x + indent,
+                    self.rect.y + top,
+                    (self.rect.w - indent).max(0.0),
+                    (self.rect.h - top).max(0.0),
+                )
+            }
+            None => self.rect,
+        }
+    }
+
+    fn vertical(&self) -> bool {
+        self.direction == Direction::Vertical
+    }
+
+    pub fn set_uuid(&mut self, uuid: impl Into<String>) -> &mut Self {
+        self.uuid = uuid.into();
         self
     }
 
     /// Push multiple widgets to the window.
-    pub fn push_widgets(&mut self, widgets: &mut Vec<Widget>) -> &mut Self {
-        if widgets.len() < 1 {
+    pub fn push_widgets(&mut self, widgets: &mut [Widget]) -> &mut Self {
+        if widgets.is_empty() {
             return self;
         }
 
@@ -60,168 +294,291 @@ impl WidgetRow {
         &mut self.widgets[idx]
     }
 
-    // Push a single widget to the window.
-    pub fn push(&mut self, widget: &mut Widget) -> usize {
-        let mut idx = self.frame_pushed.len();
-
-        if self.widgets.len() < 1 || self.widgets.len() - 1 < idx {
-            self.widgets.push(widget.clone());
-        } else if matches!(widget.clone(), self_Clone) && widget.equate(&mut self.get_widget(idx)) {
-            if let Widget::Text(ref mut widget) = widget {
-                self.get_widget(idx).as_text().text = widget.text.clone();
-            } else if let Widget::Button(ref mut widget) = widget {
-                let obj = self.get_widget(idx).as_button();
-                widget.pressed = obj.pressed;
-                widget.hovering = obj.hovering;
-                widget.is_just_pressed = obj.is_just_pressed;
-            } else if let Widget::Slider(ref mut widget) = widget {
-                let obj = self.get_widget(idx).as_slider();
-                widget.pressed = obj.pressed;
-                widget.hovering = obj.hovering;
-                widget.value = obj.value;
-            } else if let Widget::Checkbox(ref mut widget) = widget {
-                let obj = self.get_widget(idx).as_checkbox();
-                widget.pressed = obj.pressed;
-                widget.hovering = obj.hovering;
-                widget.value = obj.value;
-                widget.is_just_pressed = obj.is_just_pressed;
-            }
+    /// Get a widget in the row using its uuid (str).
+    pub fn get_widget_from_uuid(&mut self, uuid: &str) -> Option<&mut Widget> {
+        find_uuid(&mut self.widgets, uuid)
+    }
 
-            self.widgets[idx] = widget.clone();
-        } else {
-            self.widgets.push(widget.clone());
-        }
-        self.frame_pushed.push(widget.clone());
+    /// Get a `Text` using its uuid (`None` if there is no such widget, or it isn't a `Text`).
+    pub fn get_text(&mut self, uuid: &str) -> Option<&mut Text> {
+        self.get_widget_from_uuid(uuid)?.try_as_text()
+    }
 
-        self.update(true, vec2(-100.,-100.), false);
-        self.remaining = self.window_width - self.rect.w - 5.;
+    /// Get a `Button` using its uuid (`None` if there is no such widget, or it isn't a `Button`).
+    pub fn get_button(&mut self, uuid: &str) -> Option<&mut Button> {
+        self.get_widget_from_uuid(uuid)?.try_as_button()
+    }
 
-        idx
+    /// Get a `Slider` using its uuid (`None` if there is no such widget, or it isn't a `Slider`).
+    pub fn get_slider(&mut self, uuid: &str) -> Option<&mut Slider> {
+        self.get_widget_from_uuid(uuid)?.try_as_slider()
     }
 
-    pub fn update(&mut self, selected: bool, mouse_position: Vec2, mouse_released: bool) {
-        let mut max_height = 5.0;
+    /// Get a `DisplayImage` using its uuid (`None` if there is no such widget, or it isn't a `DisplayImage`).
+    pub fn get_image(&mut self, uuid: &str) -> Option<&mut DisplayImage> {
+        self.get_widget_from_uuid(uuid)?.try_as_image()
+    }
 
-        let mut last_y = 0.0;
-        let padding = 5.0;
-        let padding_left = 0.0;
+    /// Get a `Checkbox` using its uuid (`None` if there is no such widget, or it isn't a `Checkbox`).
+    pub fn get_checkbox(&mut self, uuid: &str) -> Option<&mut Checkbox> {
+        self.get_widget_from_uuid(uuid)?.try_as_checkbox()
+    }
 
-        let mut button_amount = 0.0;
+    /// Get a `WidgetRow` using its uuid (`None` if there is no such widget, or it isn't a `WidgetRow`).
+    pub fn get_widget_row(&mut self, uuid: &str) -> Option<&mut WidgetRow> {
+        self.get_widget_from_uuid(uuid)?.try_as_widget_row()
+    }
 
-        for i in self.widgets.iter_mut() {
-            if let Widget::Text(i) = i {
-                i.rect.x = self.rect.x + padding_left + last_y;
-                i.update(selected);
+    /// Push a single widget to the row (state is matched to last frame's by ID, like `Window::push`).
+    pub fn push(&mut self, widget: &mut Widget) -> usize {
+        let seed = self.id_stack.last().copied().unwrap_or(self.id);
+        let idx = push_with_id(&mut self.widgets, &self.frame_pushed, seed, widget);
+        self.frame_pushed.push(widget.clone());
 
-                if i.rect.h > max_height {
-                    max_height = i.rect.h;
-                }
-                i.rect.y = self.rect.y + self.rect.h / 4.0;
+        self.remaining = self.remaining_width() - 5.;
 
-                last_y += i.rect.w + padding;
-            } else if let Widget::Button(i) = i {
-                i.rect.x = self.rect.x + padding_left + last_y;
-                i.rect.y = self.rect.y;
-                i.update(selected, mouse_position, mouse_released);
+        idx
+    }
 
-                if i.button_rect.h > max_height {
-                    max_height = i.button_rect.h;
-                }
+    /// Width left for the next widget.
+    fn remaining_width(&self) -> f32 {
+        match self.direction {
+            Direction::Horizontal => self.window_width - self.used_width(),
+            Direction::Vertical => self.window_width,
+        }
+    }
 
-                button_amount += 1.0;
+    /// Push an ID scope for the row's widgets (see `Window::push_id`).
+    pub fn push_id<T: std::hash::Hash + ?Sized>(&mut self, id: &T) -> &mut Self {
+        let seed = hash_id(self.id_stack.last().copied().unwrap_or(self.id), id);
+        self.id_stack.push(seed);
+        self
+    }
 
-                last_y += i.button_rect.w + 2.0 + padding;
-            } else if let Widget::Slider(i) = i {
-                i.rect.x = self.rect.x + last_y + padding_left;
-                i.rect.y = self.rect.y - i.rect.h / 2.8;
-                i.update(selected, &mouse_position, mouse_released);
+    /// Pop the ID scope pushed by the last `push_id()`.
+    pub fn pop_id(&mut self) -> &mut Self {
+        self.id_stack.pop();
+        self
+    }
 
-                last_y += i.rect.w;
-            } else if let Widget::Checkbox(i) = i {
-                i.rect.x = self.rect.x + padding_left;
-                i.rect.y = self.rect.y + last_y;
-                i.update(selected, mouse_position, mouse_released);
+    /// Width taken up by the widgets pushed so far this frame (as of their last update).
+    fn used_width(&self) -> f32 {
+        let widths = self.widgets.iter().take(self.frame_pushed.len()).map(|i| i.rect().w);
 
-                last_y += i.rect.w + padding + 4.0;
-                if i.rect.h > max_height {
-                    max_height = i.rect.h;
-                }
-            }
+        match self.direction {
+            Direction::Horizontal => widths.map(|w| w + self.spacing).sum(),
+            Direction::Vertical => widths.fold(0.0, f32::max),
         }
+    }
 
-        self.rect.h = max_height;
-        if last_y > self.rect.w {
-            self.rect.w = last_y + 5.0;
+    /// Set the row's buttons' styles.
+    pub fn button_style(&mut self, style: &ButtonStyle) {
+        for i in self.widgets.iter_mut() {
+            i.as_dyn_mut().set_button_style(style);
         }
-        //self.rect.h = max_height + 4.0;
     }
 
-    pub fn render(&mut self) {
-        let mut max_height = 0.0;
+    /// Set the row's sliders' styles.
+    pub fn slider_style(&mut self, style: &SliderStyle) -> &mut Self {
+        for i in self.widgets.iter_mut() {
+            i.as_dyn_mut().set_slider_style(style);
+        }
+        self
+    }
+}
+
+impl WidgetImpl for WidgetRow {
+    fn kind(&self) -> &'static str {
+        if let Some(header) = self.header.as_ref() {
+            return match header.kind {
+                HeaderKind::CollapsingHeader => "CollapsingHeader",
+                HeaderKind::TreeNode => "TreeNode",
+            };
+        }
+        if self.tabs.is_some() {
+            return "TabBar";
+        }
+        match (self.area, self.direction) {
+            (Some(_), _) => "ScrollArea",
+            (None, Direction::Horizontal) => "WidgetRow",
+            (None, Direction::Vertical) => "WidgetColumn",
+        }
+    }
 
-        let mut last_y = 0.0;
-        let padding = -2.0;
-        let padding_left = 0.0;
+    fn label(&self) -> String {
+        match (self.header.as_ref(), self.tabs.as_ref()) {
+            (Some(header), _) => header.label.clone(),
+            (_, Some(tabs)) => tabs.label.clone(),
+            _ => String::new(),
+        }
+    }
 
-        for i in self.widgets.iter_mut() {
-            if let Widget::Text(i) = i {
-                i.rect.x = self.rect.x + padding_left + last_y;
-                i.rect.y = self.rect.y + i.rect.h/2.;
-                i.render();
-                i.rect.y -= i.rect.h/2.;
-
-                if i.rect.h > max_height {
-                    max_height = i.rect.h;
-                    self.rect.h = max_height + 4.0;
-                }
+    fn measure(&mut self, ctx: &WidgetContext) -> Vec2 {
+        let header = self.header.as_mut().map(|header| header.measure(ctx));
+        // The tabs fit in the window (they scroll if they don't).
+        let window_width = self.window_width;
+        let tabs = self.tabs.as_mut().map(|tabs| {
+            let size = tabs.measure(ctx);
+            vec2(size.x.min(window_width), size.y)
+        });
+
+        // A closed header keeps its children (and their state) without laying them out.
+        if !self.is_open() {
+            self.sizes.clear();
+            return header.unwrap_or_default();
+        }
 
-                last_y += i.rect.w + padding;
-            } else if let Widget::Button(i) = i {
-                i.rect.x = self.rect.x + padding_left + last_y;
-                //i.rect.y = self.rect.y;
-                i.render();
+        // Children not pushed again this frame are gone.
+        self.widgets.truncate(self.frame_pushed.len());
+
+        self.sizes = self.widgets.iter_mut().map(|i| i.measure(ctx)).collect();
+        let content = content_size(&self.sizes, self.spacing, self.vertical());
+        match (header, &self.header) {
+            (Some(size), _) if self.sizes.is_empty() => size,
+            (Some(size), Some(header)) => vec2(
+                size.x.max(header.indent + content.x),
+                size.y + self.spacing + content.y,
+            ),
+            _ => match tabs {
+                Some(size) if self.sizes.is_empty() => size,
+                Some(size) => vec2(size.x.max(content.x), size.y + self.spacing + content.y),
+                None => self.area.unwrap_or(content),
+            },
+        }
+    }
 
-                if i.rect.h > max_height {
-                    max_height = i.rect.h;
-                    self.rect.h = max_height + 4.0;
-                }
+    fn update(&mut self, rect: Rect, ctx: &WidgetContext) {
+        self.rect = rect;
+
+        // The header takes the top line, the children go (indented) below it.
+        if let Some(header) = self.header.as_mut() {
+            let height = header.height();
+            header.update(Rect::new(rect.x, rect.y, rect.w, height), ctx);
+        }
+        if let Some(tabs) = self.tabs.as_mut() {
+            let height = tabs.height();
+            tabs.update(Rect::new(rect.x, rect.y, rect.w, height), ctx);
+        }
+        let rect = self.body_rect();
+        self.shown = self.is_open() && self.sizes.len() == self.widgets.len();
+        if !self.shown {
+            return;
+        }
 
-                last_y += i.button_rect.w + padding;
-            } else if let Widget::Slider(i) = i {
-                i.rect.x = self.rect.x + last_y + padding_left;
-                i.rect.y = self.rect.y - i.rect.h / 2.8;
-                i.rect.w += 3.0;
-                i.render();
-                i.rect.w -= 3.0;
-
-                last_y += i.rect.w + padding;
-            } else if let Widget::Checkbox(i) = i {
-                i.rect.x = self.rect.x + padding_left;
-                i.rect.y = self.rect.y + last_y - 10.0;
-                i.render();
-
-                last_y += i.rect.w + padding;
+        let content = content_size(&self.sizes, self.spacing, self.vertical());
+        let mut mouse_position = ctx.mouse_position;
+        let (viewport, offset) = match self.area {
+            Some(_) => {
+                self.scroll.layout(rect, content, 0.0, &self.scrollbar_style);
+                let holding = self.widgets.iter().any(|i| i.as_dyn().is_active());
+                // (The scrollbars don't see the mouse over a popup, the widget owning it does.)
+                let bar_mouse = if ctx.over_popup() { NOWHERE } else { mouse_position };
+                let in_view = self.scroll.viewport.contains(mouse_position) || ctx.over_popup();
+                if self.scroll.update(bar_mouse, ctx.backend) || !(holding || in_view) {
+                    mouse_position = NOWHERE;
+                }
+                (self.scroll.viewport, self.scroll.offset)
             }
+            None => (rect, Vec2::ZERO),
+        };
+
+        let cross = match self.direction {
+            Direction::Horizontal => viewport.h.max(content.y),
+            Direction::Vertical => viewport.w.max(content.x),
+        };
+        let rects = stack(
+            viewport.point() - offset,
+            &self.sizes,
+            self.align,
+            self.spacing,
+            cross,
+            self.vertical(),
+        );
+
+        let scroll_delta = Cell::new(ctx.scroll_delta.take());
+        let child_ctx = WidgetContext {
+            mouse_position,
+            scroll_delta: &scroll_delta,
+            ..*ctx
+        };
+        for (i, rect) in self.widgets.iter_mut().zip(rects) {
+            i.update(rect, &child_ctx);
+        }
+
+        // Scroll with what the children left of the wheel, if the mouse is over the area.
+        let mut scroll_delta = scroll_delta.take();
+        if self.area.is_some() && viewport.contains(ctx.mouse_position) {
+            scroll_delta = self.scroll.scroll_by(scroll_delta);
         }
-        self.rect.h = max_height + 4.0;
+        ctx.scroll_delta.set(scroll_delta);
     }
 
-    pub fn button_style(&mut self, style: &ButtonStyle) {
+    fn render(&mut self, draw: &mut DrawList) {
+        if let Some(header) = self.header.as_ref() {
+            header.render(draw);
+        }
+        if let Some(tabs) = self.tabs.as_ref() {
+            tabs.render(draw);
+        }
+        if !self.shown {
+            return;
+        }
+
+        let viewport = match self.area {
+            Some(_) => self.scroll.viewport,
+            None => self.body_rect(),
+        };
+        draw.push_clip(viewport);
         for i in self.widgets.iter_mut() {
-            if let Widget::Button(i) = i {
-                i.style = style.clone();
+            if i.rect().overlaps(&viewport) {
+                i.render(draw);
             }
         }
+        draw.pop_clip();
+
+        if self.area.is_some() {
+            self.scroll.render(draw, &self.scrollbar_style);
+        }
     }
 
-    /// Set the window's slider' styles.
-    pub fn slider_style(&mut self, style: &SliderStyle) -> &mut Self {
-        for i in self.widgets.iter_mut() {
-            if let Widget::Slider(i) = i {
-                i.style = style.clone();
+    fn is_active(&self) -> bool {
+        let header = self.header.as_ref().is_some_and(|header| header.pressed)
+            || self.tabs.as_ref().is_some_and(|tabs| tabs.pressed.is_some());
+        let children = self.shown && self.widgets.iter().any(|i| i.as_dyn().is_active());
+        header || self.scroll.is_dragging() || children
+    }
+
+    fn wants_keyboard(&self) -> bool {
+        self.shown && self.widgets.iter().any(|i| i.as_dyn().wants_keyboard())
+    }
+
+    fn focusable(&self) -> bool {
+        self.header.is_some() || self.tabs.is_some()
+    }
+
+    fn transfer_state(&mut self, previous: &mut dyn WidgetImpl) {
+        if let Some(previous) = previous.downcast_mut::<WidgetRow>() {
+            self.rect = previous.rect;
+            self.widgets = std::mem::take(&mut previous.widgets);
+            self.scroll = std::mem::take(&mut previous.scroll);
+            self.pages = std::mem::take(&mut previous.pages);
+            self.page = previous.page;
+            self.shown = previous.shown;
+            if let (Some(header), Some(previous)) = (self.header.as_mut(), previous.header.as_ref()) {
+                header.transfer_state(previous);
+            }
+            if let (Some(tabs), Some(previous)) = (self.tabs.as_mut(), previous.tabs.as_mut()) {
+                tabs.transfer_state(previous);
             }
         }
-        self
+    }
+
+    fn set_button_style(&mut self, style: &ButtonStyle) {
+        self.button_style(style);
+    }
+
+    fn set_slider_style(&mut self, style: &SliderStyle) {
+        self.slider_style(style);
     }
 }
 
@@ -231,16 +588,16 @@ impl WidgetRow {
     pub fn Text(&mut self, text: &str, color: Option<Color>) -> (usize, Text) {
         let mut x = Widget::Text(Text::new(text, self.font.clone(), color, None));
 
-        self.push(&mut x.clone());
-        (self.widgets.len() - 1, x.as_text().clone())
+        let idx = self.push(&mut x.clone());
+        (idx, self.get(idx).as_text().clone())
     }
 
     /// Push a `Button` widget to a window. Returns the index and a CLONE of the object.
     pub fn Button(&mut self, text: &str) -> (usize, Button) {
         let mut x = Widget::Button(Button::new(text, self.font.clone(), None, None));
 
-        self.push(&mut x.clone());
-        (self.widgets.len() - 1, x.as_button().clone())
+        let idx = self.push(&mut x.clone());
+        (idx, self.get(idx).as_button().clone())
     }
 
     
@@ -267,11 +624,8 @@ impl WidgetRow {
 
     /// Push a `Slider_int` widget to the window. Returns the index and a CLONE of the object.
     pub fn Slider_int(&mut self, min: i32, max: i32, default: Option<i32>, size: Vec2) -> (i32, f32) {
-        let mut default_value: Option<f32> = None;
-        if default_value.is_some() {
-            default_value = Some(default_value.unwrap_or(0.0) as f32);
-        }
-        
+        let default_value = default.map(|default| default as f32);
+
         let mut x = Widget::Slider(Slider::new(
             self.font.clone(),
             min as f32,
@@ -299,8 +653,8 @@ impl WidgetRow {
     ) -> (usize, DisplayImage) {
         let mut x = Widget::DisplayImage(DisplayImage::new(texture, size, None, None));
 
-        self.push(&mut x.clone());
-        (self.widgets.len() - 1, x.as_image().clone())
+        let idx = self.push(&mut x.clone());
+        (idx, self.get(idx).as_image().clone())
     }
 
     /// Push a `Checkbox` widget to a window. Returns the index and a CLONE of the object.
@@ -313,7 +667,250 @@ impl WidgetRow {
             None,
         ));
 
-        self.push(&mut x.clone());
-        (self.widgets.len() - 1, x.as_checkbox().clone())
+        let idx = self.push(&mut x.clone());
+        (idx, self.get(idx).as_checkbox().clone())
+    }
+
+    /// Push a `RadioButton` to the row, setting `value` to `option` when picked.
+    pub fn RadioButton<T: PartialEq + Clone>(&mut self, text: &str, value: &mut T, option: T) -> &mut RadioButton {
+        let idx = self.push_radio_button(text, value, &option);
+        self.get(idx).as_radio_button()
+    }
+
+    /// Push a `RadioButton` per `(label, option)` pair to the row, all binding `value`. Returns whether the choice changed.
+    pub fn RadioGroup<T: PartialEq + Clone>(&mut self, value: &mut T, options: &[(impl AsRef<str>, T)]) -> bool {
+        let idxs: Vec<usize> = options
+            .iter()
+            .map(|(text, option)| self.push_radio_button(text.as_ref(), value, option))
+            .collect();
+
+        // The button picked may come after others that showed the old value.
+        let changed = idxs.iter().any(|idx| self.get(*idx).as_radio_button().changed);
+        if changed {
+            for (idx, (_, option)) in idxs.into_iter().zip(options) {
+                self.get(idx).as_radio_button().selected = *value == *option;
+            }
+        }
+        changed
+    }
+
+    fn push_radio_button<T: PartialEq + Clone>(&mut self, text: &str, value: &mut T, option: &T) -> usize {
+        let mut x = Widget::RadioButton(RadioButton::new(text, self.font.clone(), *value == *option, None, None));
+
+        let idx = self.push(&mut x);
+        self.get(idx).as_radio_button().sync(value, option);
+        idx
+    }
+
+    /// Push a `TextInput` widget to the row, editing `text`. `hint` is shown while it is empty.
+    pub fn TextInput(&mut self, hint: &str, text: &mut String, width: f32) -> &mut TextInput {
+        let mut x = Widget::TextInput(TextInput::new(hint, text, self.font.clone(), width, None));
+
+        let idx = self.push(&mut x);
+        let input = self.get(idx).as_text_input();
+        input.sync(text);
+        input
+    }
+
+    /// Push a multi-line `TextEdit` widget of `size` to the row, editing `text`. `hint` is shown while it is empty.
+    pub fn TextEdit(&mut self, hint: &str, text: &mut String, size: Vec2) -> &mut TextEdit {
+        let mut x = Widget::TextEdit(TextEdit::new(hint, text, self.font.clone(), size, None));
+
+        let idx = self.push(&mut x);
+        let edit = self.get(idx).as_text_edit();
+        edit.sync(text);
+        edit
+    }
+
+    /// Push a `NumberField` to the row, changing `value` by dragging (or typing, after a double-click).
+    pub fn DragFloat(&mut self, label: &str, value: &mut f32, width: f32) -> &mut NumberField {
+        let field = NumberField::new(label, *value as f64, self.font.clone(), width, None);
+
+        let field = self.push_number_field(field);
+        *value = field.sync(*value as f64) as f32;
+        field
+    }
+
+    /// Push a `NumberField` to the row, changing a whole `value` by dragging (or typing, after a double-click).
+    pub fn DragInt(&mut self, label: &str, value: &mut i32, width: f32) -> &mut NumberField {
+        let mut field = NumberField::new(label, *value as f64, self.font.clone(), width, None);
+        field.integer(true).speed(1.0);
+
+        let field = self.push_number_field(field);
+        *value = field.sync(*value as f64) as i32;
+        field
+    }
+
+    /// Push a `NumberField` to the row, typing `value` in (with -/+ buttons).
+    pub fn InputFloat(&mut self, label: &str, value: &mut f32, width: f32) -> &mut NumberField {
+        let mut field = NumberField::new(label, *value as f64, self.font.clone(), width, None);
+        field.speed(0.0).step(0.1);
+
+        let field = self.push_number_field(field);
+        *value = field.sync(*value as f64) as f32;
+        field
+    }
+
+    /// Push a `NumberField` to the row, typing a whole `value` in (with -/+ buttons).
+    pub fn InputInt(&mut self, label: &str, value: &mut i32, width: f32) -> &mut NumberField {
+        let mut field = NumberField::new(label, *value as f64, self.font.clone(), width, None);
+        field.integer(true).speed(0.0).step(1.0);
+
+        let field = self.push_number_field(field);
+        *value = field.sync(*value as f64) as i32;
+        field
+    }
+
+    /// Push a `Combo` of `width` to the row, picking `selected` out of `items`.
+    pub fn Combo(&mut self, label: &str, selected: &mut usize, items: &[impl AsRef<str>], width: f32) -> &mut Combo {
+        let mut x = Widget::Combo(Combo::new(label, items, *selected, self.font.clone(), width, None));
+
+        let idx = self.push(&mut x);
+        let combo = self.get(idx).as_combo();
+        combo.sync(selected);
+        combo
+    }
+
+    /// Push a `ColorEdit` of `width` to the row, editing `color`.
+    pub fn ColorEdit(&mut self, label: &str, color: &mut Color, width: f32) -> &mut ColorEdit {
+        let mut x = Widget::ColorEdit(ColorEdit::new(label, *color, self.font.clone(), width, None));
+
+        let idx = self.push(&mut x);
+        let edit = self.get(idx).as_color_edit();
+        edit.sync(color);
+        edit
+    }
+
+    /// Push a `ListBox` of `size` to the row, showing `items` and selecting into `selection`
+    /// (`Option<usize>` for one item, `Vec<usize>` for several).
+    pub fn ListBox<S: ListSelection>(
+        &mut self,
+        label: &str,
+        selection: &mut S,
+        items: &[impl AsRef<str>],
+        size: Vec2,
+    ) -> &mut ListBox {
+        let items = items.iter().map(|item| item.as_ref().to_owned()).collect();
+        self.push_list_box(label, selection, items, size)
+    }
+
+    /// Push a `ListBox` of `size` to the row, with `count` items labelled by `item(index)`.
+    pub fn ListBox_with<S: ListSelection>(
+        &mut self,
+        label: &str,
+        selection: &mut S,
+        count: usize,
+        size: Vec2,
+        item: impl FnMut(usize) -> String,
+    ) -> &mut ListBox {
+        let items = (0..count).map(item).collect();
+        self.push_list_box(label, selection, items, size)
+    }
+
+    fn push_list_box<S: ListSelection>(
+        &mut self,
+        label: &str,
+        selection: &mut S,
+        items: Vec<String>,
+        size: Vec2,
+    ) -> &mut ListBox {
+        let mut x = Widget::ListBox(ListBox::new(label, items, S::MULTIPLE, self.font.clone(), size, None));
+
+        let idx = self.push(&mut x);
+        let list = self.get(idx).as_list_box();
+        list.sync(selection);
+        list
+    }
+
+    /// Push a `ProgressBar` of `size` to the row, filled to `fraction` (0 to 1). A width of 0 fills the rest of the row.
+    pub fn ProgressBar(&mut self, fraction: f32, size: Vec2) -> &mut ProgressBar {
+        let size = self.fill_width(size);
+        let mut x = Widget::ProgressBar(ProgressBar::new(fraction, self.font.clone(), size, None));
+
+        let idx = self.push(&mut x);
+        self.get(idx).as_progress_bar()
+    }
+
+    /// Push a `Spinner` of `size` to the row. A width of 0 fills the rest of the row (the spinner is centred in it).
+    pub fn Spinner(&mut self, size: Vec2) -> &mut Spinner {
+        let mut x = Widget::Spinner(Spinner::new(self.fill_width(size), None));
+
+        let idx = self.push(&mut x);
+        self.get(idx).as_spinner()
+    }
+
+    fn fill_width(&self, size: Vec2) -> Vec2 {
+        match size.x <= 0.0 {
+            true => vec2(self.remaining_width(), size.y),
+            false => size,
+        }
+    }
+
+    fn push_number_field(&mut self, field: NumberField) -> &mut NumberField {
+        let mut x = Widget::NumberField(field);
+
+        let idx = self.push(&mut x);
+        self.get(idx).as_number_field()
+    }
+
+    /// Push a custom widget (anything implementing `WidgetImpl`) to the row.
+    pub fn Custom<W: WidgetImpl>(&mut self, widget: W) -> &mut W {
+        let mut x = Widget::Custom(CustomWidget::new(widget, None));
+
+        let idx = self.push(&mut x);
+        self.get(idx).as_custom()
+    }
+
+    /// Push a nested `WidgetRow` to the row.
+    pub fn WidgetRow(&mut self) -> Option<&mut WidgetRow> {
+        let mut row = WidgetRow::new(self.font.clone(), None, self.remaining_width());
+        row.spacing = self.spacing;
+
+        let idx = self.push(&mut Widget::WidgetRow(row));
+        Some(self.get(idx).as_widget_row())
+    }
+
+    /// Push a nested column (a vertical `WidgetRow`) to the row.
+    pub fn WidgetColumn(&mut self) -> Option<&mut WidgetRow> {
+        let mut column = WidgetRow::column(self.font.clone(), None, self.remaining_width());
+        column.spacing = self.spacing;
+
+        let idx = self.push(&mut Widget::WidgetRow(column));
+        Some(self.get(idx).as_widget_row())
+    }
+
+    /// Push a nested scroll area of `size` to the row.
+    pub fn ScrollArea(&mut self, size: Vec2) -> Option<&mut WidgetRow> {
+        let mut area = WidgetRow::scroll_area(self.font.clone(), None, size.x, size);
+        area.spacing = self.spacing;
+        area.scrollbar_style = self.scrollbar_style.clone();
+
+        let idx = self.push(&mut Widget::WidgetRow(area));
+        Some(self.get(idx).as_widget_row())
+    }
+
+    /// Push a nested `CollapsingHeader` to the row (see `Window::CollapsingHeader`).
+    pub fn CollapsingHeader(&mut self, label: &str) -> &mut WidgetRow {
+        let header = WidgetRow::collapsing_header(label, self.font.clone(), None, self.remaining_width());
+        self.push_header(header)
+    }
+
+    /// Push a nested `TreeNode` to the row (see `Window::TreeNode`).
+    pub fn TreeNode(&mut self, label: &str) -> &mut WidgetRow {
+        let node = WidgetRow::tree_node(label, self.font.clone(), None, self.remaining_width());
+        self.push_header(node)
+    }
+
+    /// Push a nested `TabBar` to the row (see `Window::TabBar`).
+    pub fn TabBar(&mut self, label: &str) -> &mut WidgetRow {
+        let bar = WidgetRow::tab_bar(label, self.font.clone(), None, self.remaining_width());
+        self.push_header(bar)
+    }
+
+    fn push_header(&mut self, mut header: WidgetRow) -> &mut WidgetRow {
+        header.spacing = self.spacing;
+
+        let idx = self.push(&mut Widget::WidgetRow(header));
+        self.get(idx).as_widget_row()
     }
 }
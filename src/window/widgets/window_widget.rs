@@ -4,6 +4,13 @@ use super::super::*;
 pub struct WindowWidget {
     pub font: Option<Font>,
 }
+
+impl Default for WindowWidget {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl WindowWidget {
     pub fn new() -> Self {
         Self { font: None }
@@ -19,16 +26,16 @@ impl WindowWidget {
     pub fn Text(&self, win: &mut Window, text: &str, color: Option<Color>) -> (usize, Text) {
         let mut x = Widget::Text(Text::new(text, self.font.clone(), color, None));
 
-        win.push(&mut x.clone());
-        (win.widgets.len() - 1, x.as_text().clone())
+        let idx = win.push(&mut x);
+        (idx, win.get(idx).as_text().clone())
     }
 
     /// Push a `Button` widget to a window. Returns the index and a CLONE of the object.
     pub fn Button(&self, win: &mut Window, text: &str) -> (usize, Button) {
         let mut x = Widget::Button(Button::new(text, self.font.clone(), None, None));
 
-        win.push(&mut x);
-        (win.widgets.len() - 1, x.as_button().clone())
+        let idx = win.push(&mut x);
+        (idx, win.get(idx).as_button().clone())
     }
 
     /// Push a `Slider` widget to a window. Returns the index and a CLONE of the object.
@@ -50,8 +57,8 @@ impl WindowWidget {
             None,
         ));
 
-        let idx = win.push(&mut x.clone());
-        (win.widgets.len() - 1, win.get(idx).as_slider().clone())
+        let idx = win.push(&mut x);
+        (idx, win.get(idx).as_slider().clone())
     }
 
     /// Push a `DisplayImage` widget to a window. Returns the index and a CLONE of the object.
@@ -63,16 +70,54 @@ impl WindowWidget {
     ) -> (usize, DisplayImage) {
         let mut x = Widget::DisplayImage(DisplayImage::new(texture, size, None, None));
 
-        win.push(&mut x.clone());
-        (win.widgets.len() - 1, x.as_image().clone())
+        let idx = win.push(&mut x);
+        (idx, win.get(idx).as_image().clone())
+    }
+
+    /// Push a `WidgetRow` widget to a window. Returns the row (the one in the window) to push its widgets to.
+    pub fn WidgetRow<'a>(&self, win: &'a mut Window) -> &'a mut WidgetRow {
+        let row = WidgetRow::new(self.font.clone(), None, win.rect.w);
+        self.push_row(win, row)
+    }
+
+    /// Push a column (a vertical `WidgetRow`) to a window. Returns the column to push its widgets to.
+    pub fn WidgetColumn<'a>(&self, win: &'a mut Window) -> &'a mut WidgetRow {
+        let column = WidgetRow::column(self.font.clone(), None, win.rect.w);
+        self.push_row(win, column)
+    }
+
+    /// Push a scroll area of `size` to a window. Returns the area to push its widgets to.
+    pub fn ScrollArea<'a>(&self, win: &'a mut Window, size: Vec2) -> &'a mut WidgetRow {
+        let mut area = WidgetRow::scroll_area(self.font.clone(), None, size.x, size);
+        area.scrollbar_style = win.style.scrollbar.clone();
+        self.push_row(win, area)
     }
 
-    /// Push a `WidgetRow` widget to a window. Returns the index and a CLONE of the object.
-    pub fn WidgetRow(&self, win: &mut Window) -> (usize, Option<WidgetRow>) {
-        let mut x = Widget::WidgetRow(WidgetRow::new(self.font.clone(), None, win.rect.w));
+    /// Push `row` with the window's item spacing, like `Window::WidgetRow` does.
+    fn push_row<'a>(&self, win: &'a mut Window, mut row: WidgetRow) -> &'a mut WidgetRow {
+        row.spacing = win.layout.item_spacing;
+        let mut x = Widget::WidgetRow(row);
 
-        win.push(&mut x.clone());
-        (win.widgets.len() - 1, Some(x.as_widget_row().clone()))
+        let idx = win.push(&mut x);
+        win.get(idx).as_widget_row()
+    }
+
+    /// Push a `CollapsingHeader` to a window. Returns the header; push its widgets to `body()`.
+    pub fn CollapsingHeader<'a>(&self, win: &'a mut Window, label: &str) -> &'a mut WidgetRow {
+        let header = WidgetRow::collapsing_header(label, self.font.clone(), None, win.rect.w);
+        self.push_row(win, header)
+    }
+
+    /// Push a `TreeNode` to a window. Returns the node; push its widgets to `body()`.
+    pub fn TreeNode<'a>(&self, win: &'a mut Window, label: &str) -> &'a mut WidgetRow {
+        let node = WidgetRow::tree_node(label, self.font.clone(), None, win.rect.w);
+        self.push_row(win, node)
+    }
+
+    /// Push a `TabBar` to a window. Returns the bar; add tabs with `Tab` / `TabClosable` and push their pages' widgets.
+    pub fn TabBar<'a>(&self, win: &'a mut Window, label: &str) -> &'a mut WidgetRow {
+        let bar = WidgetRow::tab_bar(label, self.font.clone(), None, win.content_width());
+        self.push_row(win, bar)
     }
 
     /// Push a `Checkbox` widget to a window. Returns the index and a CLONE of the object.
@@ -85,7 +130,208 @@ impl WindowWidget {
             None,
         ));
 
-        win.push(&mut x);
-        (win.widgets.len() - 1, x.as_checkbox().clone())
+        let idx = win.push(&mut x);
+        (idx, win.get(idx).as_checkbox().clone())
+    }
+
+    /// Push a `RadioButton` to a window, setting `value` to `option` when picked. Returns the index and a CLONE of the object.
+    pub fn RadioButton<T: PartialEq + Clone>(
+        &self,
+        win: &mut Window,
+        text: &str,
+        value: &mut T,
+        option: T,
+    ) -> (usize, RadioButton) {
+        let mut x = Widget::RadioButton(RadioButton::new(text, self.font.clone(), *value == option, None, None));
+
+        let idx = win.push(&mut x);
+        let radio = win.get(idx).as_radio_button();
+        radio.sync(value, &option);
+        (idx, radio.clone())
+    }
+
+    /// Push a `RadioButton` per `(label, option)` pair to a window, all binding `value`.
+    /// Returns the buttons' indexes and whether the choice changed.
+    pub fn RadioGroup<T: PartialEq + Clone>(
+        &self,
+        win: &mut Window,
+        value: &mut T,
+        options: &[(impl AsRef<str>, T)],
+    ) -> (Vec<usize>, bool) {
+        let idxs: Vec<usize> = options
+            .iter()
+            .map(|(text, option)| self.RadioButton(win, text.as_ref(), value, option.clone()).0)
+            .collect();
+
+        // The button picked may come after others that showed the old value.
+        let changed = idxs.iter().any(|idx| win.get(*idx).as_radio_button().changed);
+        if changed {
+            for (idx, (_, option)) in idxs.iter().zip(options) {
+                win.get(*idx).as_radio_button().selected = *value == *option;
+            }
+        }
+        (idxs, changed)
+    }
+
+    /// Push a `TextInput` widget to a window, editing `text`. Returns the index and a CLONE of the object.
+    pub fn TextInput(&self, win: &mut Window, hint: &str, text: &mut String, width: f32) -> (usize, TextInput) {
+        let mut x = Widget::TextInput(TextInput::new(hint, text, self.font.clone(), width, None));
+
+        let idx = win.push(&mut x);
+        let input = win.get(idx).as_text_input();
+        input.sync(text);
+        (idx, input.clone())
+    }
+
+    /// Push a multi-line `TextEdit` widget to a window, editing `text`. Returns the index and a CLONE of the object.
+    pub fn TextEdit(&self, win: &mut Window, hint: &str, text: &mut String, size: Vec2) -> (usize, TextEdit) {
+        let mut x = Widget::TextEdit(TextEdit::new(hint, text, self.font.clone(), size, None));
+
+        let idx = win.push(&mut x);
+        let edit = win.get(idx).as_text_edit();
+        edit.sync(text);
+        (idx, edit.clone())
+    }
+
+    /// Push a `NumberField` (dragged, or typed in after a double-click) to a window, editing `value`. Returns the index and a CLONE of the object.
+    pub fn DragFloat(&self, win: &mut Window, label: &str, value: &mut f32, width: f32) -> (usize, NumberField) {
+        let mut x = Widget::NumberField(NumberField::new(label, *value as f64, self.font.clone(), width, None));
+
+        let idx = win.push(&mut x);
+        let field = win.get(idx).as_number_field();
+        *value = field.sync(*value as f64) as f32;
+        (idx, field.clone())
+    }
+
+    /// Push a `NumberField` (dragged, or typed in after a double-click) to a window, editing a whole `value`. Returns the index and a CLONE of the object.
+    pub fn DragInt(&self, win: &mut Window, label: &str, value: &mut i32, width: f32) -> (usize, NumberField) {
+        let mut field = NumberField::new(label, *value as f64, self.font.clone(), width, None);
+        field.integer(true).speed(1.0);
+        let mut x = Widget::NumberField(field);
+
+        let idx = win.push(&mut x);
+        let field = win.get(idx).as_number_field();
+        *value = field.sync(*value as f64) as i32;
+        (idx, field.clone())
+    }
+
+    /// Push a `NumberField` (typed in, with -/+ buttons) to a window, editing `value`. Returns the index and a CLONE of the object.
+    pub fn InputFloat(&self, win: &mut Window, label: &str, value: &mut f32, width: f32) -> (usize, NumberField) {
+        let mut field = NumberField::new(label, *value as f64, self.font.clone(), width, None);
+        field.speed(0.0).step(0.1);
+        let mut x = Widget::NumberField(field);
+
+        let idx = win.push(&mut x);
+        let field = win.get(idx).as_number_field();
+        *value = field.sync(*value as f64) as f32;
+        (idx, field.clone())
+    }
+
+    /// Push a `NumberField` (typed in, with -/+ buttons) to a window, editing a whole `value`. Returns the index and a CLONE of the object.
+    pub fn InputInt(&self, win: &mut Window, label: &str, value: &mut i32, width: f32) -> (usize, NumberField) {
+        let mut field = NumberField::new(label, *value as f64, self.font.clone(), width, None);
+        field.integer(true).speed(0.0).step(1.0);
+        let mut x = Widget::NumberField(field);
+
+        let idx = win.push(&mut x);
+        let field = win.get(idx).as_number_field();
+        *value = field.sync(*value as f64) as i32;
+        (idx, field.clone())
+    }
+
+    /// Push a `Combo` to a window, picking `selected` out of `items`. Returns the index and a CLONE of the object.
+    pub fn Combo(
+        &self,
+        win: &mut Window,
+        label: &str,
+        selected: &mut usize,
+        items: &[impl AsRef<str>],
+        width: f32,
+    ) -> (usize, Combo) {
+        let mut x = Widget::Combo(Combo::new(label, items, *selected, self.font.clone(), width, None));
+
+        let idx = win.push(&mut x);
+        let combo = win.get(idx).as_combo();
+        combo.sync(selected);
+        (idx, combo.clone())
+    }
+
+    /// Push a `ColorEdit` to a window, editing `color`. Returns the index and a CLONE of the object.
+    pub fn ColorEdit(&self, win: &mut Window, label: &str, color: &mut Color, width: f32) -> (usize, ColorEdit) {
+        let mut x = Widget::ColorEdit(ColorEdit::new(label, *color, self.font.clone(), width, None));
+
+        let idx = win.push(&mut x);
+        let edit = win.get(idx).as_color_edit();
+        edit.sync(color);
+        (idx, edit.clone())
+    }
+
+    /// Push a `ListBox` to a window, showing `items` and selecting into `selection`. Returns the index and a CLONE of the object.
+    pub fn ListBox<S: ListSelection>(
+        &self,
+        win: &mut Window,
+        label: &str,
+        selection: &mut S,
+        items: &[impl AsRef<str>],
+        size: Vec2,
+    ) -> (usize, ListBox) {
+        let items = items.iter().map(|item| item.as_ref().to_owned()).collect();
+        self.push_list_box(win, label, selection, items, size)
+    }
+
+    /// Push a `ListBox` to a window, with `count` items labelled by `item(index)`. Returns the index and a CLONE of the object.
+    pub fn ListBox_with<S: ListSelection>(
+        &self,
+        win: &mut Window,
+        label: &str,
+        selection: &mut S,
+        count: usize,
+        size: Vec2,
+        item: impl FnMut(usize) -> String,
+    ) -> (usize, ListBox) {
+        let items = (0..count).map(item).collect();
+        self.push_list_box(win, label, selection, items, size)
+    }
+
+    fn push_list_box<S: ListSelection>(
+        &self,
+        win: &mut Window,
+        label: &str,
+        selection: &mut S,
+        items: Vec<String>,
+        size: Vec2,
+    ) -> (usize, ListBox) {
+        let mut x = Widget::ListBox(ListBox::new(label, items, S::MULTIPLE, self.font.clone(), size, None));
+
+        let idx = win.push(&mut x);
+        let list = win.get(idx).as_list_box();
+        list.sync(selection);
+        (idx, list.clone())
+    }
+
+    /// Push a `ProgressBar` of `size` (a width of 0 fills the window) to a window. Returns the index and a CLONE of the object.
+    pub fn ProgressBar(&self, win: &mut Window, fraction: f32, size: Vec2) -> (usize, ProgressBar) {
+        let size = if size.x <= 0.0 { vec2(win.content_width(), size.y) } else { size };
+        let mut x = Widget::ProgressBar(ProgressBar::new(fraction, self.font.clone(), size, None));
+
+        let idx = win.push(&mut x);
+        (idx, win.get(idx).as_progress_bar().clone())
+    }
+
+    /// Push a `Spinner` of `size` (a width of 0 fills the window) to a window. Returns the index and a CLONE of the object.
+    pub fn Spinner(&self, win: &mut Window, size: Vec2) -> (usize, Spinner) {
+        let size = if size.x <= 0.0 { vec2(win.content_width(), size.y) } else { size };
+        let mut x = Widget::Spinner(Spinner::new(size, None));
+
+        let idx = win.push(&mut x);
+        (idx, win.get(idx).as_spinner().clone())
+    }
+
+    /// Push a custom widget (anything implementing `WidgetImpl`) to a window. Returns the index and a CLONE of the object.
+    pub fn Custom<W: WidgetImpl + Clone>(&self, win: &mut Window, widget: W) -> (usize, W) {
+        let mut x = Widget::Custom(CustomWidget::new(widget, None));
+
+        let idx = win.push(&mut x);
+        (idx, win.get(idx).as_custom::<W>().clone())
     }
 }
@@ -9,37 +9,65 @@ use super::*;
 /// 
 /// ### Creating and updating windows
 /// 
-/// ```
-/// let windows = WindowManager::new(); // Create new window manager
+/// ```no_run
+/// # use amberwindow::*;
+/// let mut windows = WindowManager::new(); // Create new window manager
 /// 
 /// loop {
-///     windows.begin("window"); // returns `Option<Window>`
+///     windows.begin("window"); // returns `Option<&mut Window>`
 ///     windows.update_windows();       // Update + Render all windows.
 /// }
 /// ```
 /// 
 /// ### Getting window index from id
 /// 
-/// ```
+/// ```no_run
+/// # use amberwindow::*;
+/// # let mut windows = WindowManager::new();
 /// // Get window with id = "window"
-/// let idx = windows.get_window_index("window");
-/// 
-/// windows[idx].queue_free(); // Kill the window
+/// if let Some(idx) = windows.get_window_index("window") {
+///     windows.windows[idx].queue_free(); // Kill the window
+/// }
 /// ```
-pub struct WindowManager {
+///
+/// ### Running on a custom backend
+///
+/// ```no_run
+/// # use amberwindow::*;
+/// // Anything implementing `InputSource` + `Painter`, like the `HeadlessBackend` used for tests
+/// let mut windows = WindowManager::with_backend(HeadlessBackend::new(800., 600.));
+/// ```
+pub struct WindowManager<B: Backend = MacroquadBackend> {
+    /// All windows, front to back.
     pub windows: Vec<Window>,
     pub frame_pushed: Vec<String>,
     pub freed: Vec<String>,
     pub font: Option<Font>,
+    pub backend: B,
 }
+
 impl WindowManager {
-    /// Create a new WindowManager
+    /// Create a new WindowManager (using macroquad for input and rendering).
     pub fn new() -> Self {
+        Self::with_backend(MacroquadBackend::default())
+    }
+}
+
+impl Default for WindowManager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<B: Backend> WindowManager<B> {
+    /// Create a new WindowManager that reads input from and renders to `backend`.
+    pub fn with_backend(backend: B) -> Self {
         Self {
             windows: vec![],
             frame_pushed: vec![],
             freed: vec![],
             font: None,
+            backend,
         }
     }
 
@@ -63,9 +91,12 @@ impl WindowManager {
     /// Returns an Option<&mut Window> which can have methods called to change itself.
     /// # Example
     /// 
-    /// ```
+    /// ```no_run
+    /// # use amberwindow::*;
+    /// # let mut windows = WindowManager::new();
+    /// # let widget = WindowWidget::new();
     /// if let Some(win) = windows.begin("my_window") {
-    ///     widget.Text(win, "hello");
+    ///     widget.Text(win, "hello", None);
     /// }
     /// ```
     pub fn begin(&mut self, id: &str) -> Option<&mut Window> {
@@ -73,7 +104,7 @@ impl WindowManager {
 
         if let Some(idx) = self.get_window_index(id) {
             if !self.check_freed(id) {
-                self.windows[idx].frame_pushed.clear();
+                self.windows[idx].begin_frame();
         
                 return Some(&mut self.windows[idx]);
             }
@@ -97,7 +128,7 @@ impl WindowManager {
     /// Check if the a window with `id` has been freed.
     pub fn check_freed(&mut self, id: &str) -> bool {
         for i in self.windows.iter() {
-            if i.id != id.to_owned() { continue }
+            if i.id != id { continue }
             if i.queue_free {
                 self.freed.push(i.uuid.clone());
                 return true;
@@ -111,41 +142,43 @@ impl WindowManager {
     /// Updates (ONLY) all windows.
     /// *(After calling begin and changing them)*
     pub fn update_windows(&mut self) {
-        let mouse_position = vec2(mouse_position().0, mouse_position().1);
+        self.backend.begin_frame();
 
-        let mut win_idx: usize = 0;
         let mut selected: Option<usize> = None;
 
         let mut windows = &mut self.windows;
 
         windows.retain(|x| self.frame_pushed.contains(&x.id));
+        sort_layers(windows);
+
+        // Only one window gets the mouse: the one holding it, else the one with a popup under it
+        // (popups are above every window), else the frontmost one under it.
+        let mouse_position = self.backend.mouse_position();
+        let live = |win: &&Window| !self.freed.contains(&win.uuid);
+        let hovered = windows
+            .iter()
+            .filter(live)
+            .find(|win| win.is_capturing_mouse())
+            .or_else(|| windows.iter().filter(live).find(|win| win.popup_contains(mouse_position)))
+            .or_else(|| windows.iter().filter(live).find(|win| win.contains(mouse_position)))
+            .map(|win| win.uuid.clone());
+
+        for (win_idx, win) in windows.iter_mut().enumerate() {
+            win.hovered = hovered.as_ref() == Some(&win.uuid);
 
-        for win in windows.iter_mut() {
             if self.freed.contains(&win.uuid) { continue }
 
-            let mut idx = 0;
-            win.widgets.retain(|_| {
-                if idx > win.frame_pushed.len()-1 {
-                    return false;
-                }
-                idx += 1;
-                true
-            });
-            win.update(selected, &mouse_position);
+            win.widgets.truncate(win.frame_pushed.len());
+            win.update(selected, &self.backend);
 
             if win.selected {
                 selected = Some(win_idx);
             }
-
-            win_idx += 1;
         }
 
         if let Some(idx) = selected {
-            if windows.len() > 0 {
-                let clone = windows[idx].clone();
-                windows.remove(idx);
-                windows.insert(0, clone);
-            }
+            windows[..=idx].rotate_right(1);
+            sort_layers(windows);
         }
 
         self.frame_pushed.clear();
@@ -153,79 +186,98 @@ impl WindowManager {
 
     /// Renders (ONLY) all windows.
     /// *(After calling `update_windows()``)*
+    /// Each window records into its `draw_list`, which is then submitted to the backend (back to front).
+    /// Overlays (popups) are submitted last, above every window.
     pub fn render_windows(&mut self) {
-        let mut reversed = self.windows.clone();
-        reversed.reverse();
-        for win in reversed.iter_mut() {
+        for win in self.windows.iter_mut().rev() {
             if self.freed.contains(&win.uuid) { continue }
 
             win.render();
+            win.draw_list.submit(&mut self.backend);
+        }
+        for win in self.windows.iter().rev() {
+            if self.freed.contains(&win.uuid) { continue }
+
+            if let Some(overlay) = win.draw_list.overlay_list() {
+                overlay.submit(&mut self.backend);
+            }
         }
 
         self.frame_pushed.clear();
     }
 
+    /// The draw lists recorded by the last `render_windows()`, back to front (then their overlays), as submitted.
+    pub fn draw_lists(&self) -> impl Iterator<Item = &DrawList> {
+        let live = || self.windows.iter().rev().filter(|win| !self.freed.contains(&win.uuid));
+        live()
+            .map(|win| &win.draw_list)
+            .chain(live().filter_map(|win| win.draw_list.overlay_list()))
+    }
+
     /// Updates and renders all windows.
     /// ## MUST BE CALLED AT THE END
     /// *(After calling begin and changing them)*
     pub fn end_windows(&mut self) {
-        let mouse_position = vec2(mouse_position().0, mouse_position().1);
-
-        let mut win_idx: usize = 0;
-        let mut selected: Option<usize> = None;
-
-        let mut windows = &mut self.windows;
-
-        windows.retain(|x| self.frame_pushed.contains(&x.id));
-
-        for win in windows.iter_mut() {
-            if self.freed.contains(&win.uuid) { continue }
+        self.update_windows();
+        self.render_windows();
+    }
 
-            let mut idx = 0;
-            win.widgets.retain(|_| {
-                if idx > win.frame_pushed.len()-1 {
-                    return false;
-                }
-                idx += 1;
-                true
-            });
-            win.update(selected, &mouse_position);
+    /// Whether `point` is over any window.
+    pub fn is_point_over_ui(&self, point: Vec2) -> bool {
+        self.live_windows().any(|win| win.contains(point))
+    }
 
-            if win.selected {
-                selected = Some(win_idx);
-            }
+    /// Whether the UI is using the mouse: it is over a window, or a window is holding it
+    /// (being dragged or scaled, or a widget is being pressed).
+    /// When this is `true` the game should ignore mouse input.
+    pub fn wants_mouse_input(&self) -> bool {
+        self.is_point_over_ui(self.backend.mouse_position())
+            || self.live_windows().any(|win| win.is_capturing_mouse())
+    }
 
-            win_idx += 1;
-        }
+    /// Whether the UI is using the keyboard (a widget of the selected window is taking key presses).
+    /// When this is `true` the game should ignore keyboard input.
+    pub fn wants_keyboard_input(&self) -> bool {
+        self.live_windows().any(|win| win.wants_keyboard_input())
+    }
 
-        if let Some(idx) = selected {
-            if windows.len() > 0 {
-                let clone = windows[idx].clone();
-                windows.remove(idx);
-                windows.insert(0, clone);
-            }
+    /// The backend's input minus what the UI used this frame: mouse buttons and the wheel read as
+    /// idle while `wants_mouse_input()`, keys while `wants_keyboard_input()`.
+    pub fn unconsumed_input(&self) -> UnconsumedInput<'_> {
+        UnconsumedInput {
+            input: &self.backend,
+            mouse: !self.wants_mouse_input(),
+            keyboard: !self.wants_keyboard_input(),
         }
+    }
 
-        let mut reversed = windows.clone();
-        reversed.reverse();
-        for win in reversed.iter_mut() {
-            if self.freed.contains(&win.uuid) { continue }
+    fn live_windows(&self) -> impl Iterator<Item = &Window> {
+        self.windows.iter().filter(|win| !self.freed.contains(&win.uuid))
+    }
 
-            win.render();
+    /// Move the window with `id` in front of the other windows of its layer.
+    pub fn bring_to_front(&mut self, id: &str) {
+        if let Some(idx) = self.get_window_index(id) {
+            self.windows[..=idx].rotate_right(1);
+            sort_layers(&mut self.windows);
         }
+    }
 
-        self.frame_pushed.clear();
+    /// Move the window with `id` behind the other windows of its layer.
+    pub fn send_to_back(&mut self, id: &str) {
+        if let Some(idx) = self.get_window_index(id) {
+            self.windows[idx..].rotate_left(1);
+            sort_layers(&mut self.windows);
+        }
     }
 
     /// Get the index of a window using its `id`.
     pub fn get_window_index(&mut self, id: &str) -> Option<usize> {
-        let mut idx = 0;
-        for i in self.windows.iter() {
-            if i.id == id.to_owned() {
-                return Some(idx);
-            }
-            idx += 1;
-        }
-        None
+        self.windows.iter().position(|i| i.id == id)
     }
 }
+
+/// Order `windows` front to back by layer, keeping their order within each layer.
+fn sort_layers(windows: &mut [Window]) {
+    windows.sort_by_key(|win| std::cmp::Reverse(win.layer));
+}
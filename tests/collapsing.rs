@@ -0,0 +1,169 @@
+use std::cell::Cell;
+
+use amberwindow::*;
+use macroquad::prelude::*;
+
+mod common;
+use common::*;
+
+fn section(windows: &mut Windows, idx: usize) -> &mut WidgetRow {
+    windows.windows[0].widgets[idx].as_widget_row()
+}
+
+fn header_rect(windows: &mut Windows, idx: usize) -> Rect {
+    section(windows, idx).header.as_ref().unwrap().rect
+}
+
+#[test]
+fn clicking_the_header_shows_its_widgets() {
+    let mut windows = windows();
+    let mut ui = |win: &mut Window| {
+        if let Some(physics) = win.CollapsingHeader("Physics").body() {
+            physics.Text("Gravity", None);
+        }
+        win.Text("Below", None);
+    };
+    frame(&mut windows, &mut ui);
+    assert!(!section(&mut windows, 0).is_open());
+    assert!(!windows.windows[0].draw_list.to_string().contains("\"Gravity\""));
+    let closed_below = windows.windows[0].widgets[1].rect().y;
+
+    let header = header_rect(&mut windows, 0);
+    click(&mut windows, header.center(), &mut ui);
+    frame(&mut windows, &mut ui);
+    assert!(section(&mut windows, 0).is_open());
+    assert!(windows.windows[0].draw_list.to_string().contains("\"Gravity\""));
+
+    // The widgets are indented under the header, and push the rest down.
+    let gravity = section(&mut windows, 0).widgets[0].rect();
+    assert_eq!(gravity.x, header.x + 8.);
+    assert!(gravity.y >= header.bottom());
+    assert!(windows.windows[0].widgets[1].rect().y > closed_below);
+
+    click(&mut windows, header.center(), &mut ui);
+    frame(&mut windows, &mut ui);
+    assert!(!windows.windows[0].draw_list.to_string().contains("\"Gravity\""));
+}
+
+#[test]
+fn window_widget_headers_take_children() {
+    let mut windows = windows();
+    let widget = WindowWidget::new();
+    let ui = |win: &mut Window| {
+        if let Some(physics) = widget.CollapsingHeader(win, "Physics").default_open(true).body() {
+            physics.Text("Gravity", None);
+        }
+        if let Some(bodies) = widget.TreeNode(win, "Bodies").default_open(true).body() {
+            bodies.Checkbox("Ball", false);
+        }
+    };
+    frame(&mut windows, ui);
+    frame(&mut windows, ui);
+
+    assert_eq!(section(&mut windows, 0).widgets[0].as_text().text, "Gravity");
+    assert_eq!(section(&mut windows, 1).widgets[0].as_checkbox().text, "Ball");
+    assert!(windows.windows[0].draw_list.to_string().contains("\"Gravity\""));
+}
+
+#[test]
+fn open_state_is_kept_per_id() {
+    let mut windows = windows();
+    let close_audio = Cell::new(false);
+    let mut ui = |win: &mut Window| {
+        if let Some(video) = win.CollapsingHeader("Video").body() {
+            video.Text("Resolution", None);
+        }
+        let audio = win.CollapsingHeader("Audio").default_open(true);
+        if close_audio.take() {
+            audio.set_open(false);
+        }
+        if let Some(audio) = audio.body() {
+            audio.Text("Volume", None);
+        }
+    };
+    frame(&mut windows, &mut ui);
+    assert!(!section(&mut windows, 0).is_open());
+    assert!(section(&mut windows, 1).is_open());
+
+    // `default_open` only applies to the first frame.
+    let audio = header_rect(&mut windows, 1);
+    click(&mut windows, audio.center(), &mut ui);
+    frame(&mut windows, &mut ui);
+    assert!(!section(&mut windows, 1).is_open());
+
+    let video = header_rect(&mut windows, 0);
+    click(&mut windows, video.center(), &mut ui);
+    frame(&mut windows, &mut ui);
+    let audio = header_rect(&mut windows, 1);
+    click(&mut windows, audio.center(), &mut ui);
+    assert!(section(&mut windows, 0).is_open() && section(&mut windows, 1).is_open());
+
+    close_audio.set(true);
+    frame(&mut windows, &mut ui);
+    assert!(section(&mut windows, 0).is_open());
+    assert!(!section(&mut windows, 1).is_open());
+}
+
+#[test]
+fn tree_nodes_nest_and_keep_children_state_while_closed() {
+    let mut windows = windows();
+    let mut ui = |win: &mut Window| {
+        if let Some(world) = win.TreeNode("World").body() {
+            if let Some(player) = world.TreeNode("Player").body() {
+                player.Text("Health", None);
+            }
+        }
+    };
+    frame(&mut windows, &mut ui);
+    let world = header_rect(&mut windows, 0);
+    click(&mut windows, world.center(), &mut ui);
+    frame(&mut windows, &mut ui);
+    let player = section(&mut windows, 0).widgets[0].as_widget_row().header.as_ref().unwrap().rect;
+    assert_eq!(player.x, world.x + 16.);
+    click(&mut windows, player.center(), &mut ui);
+    frame(&mut windows, &mut ui);
+    let health = section(&mut windows, 0).widgets[0].as_widget_row().widgets[0].rect();
+    assert_eq!(health.x, world.x + 32.);
+
+    // Closing the parent hides the child, which is still open when the parent opens again.
+    click(&mut windows, world.center(), &mut ui);
+    frame(&mut windows, &mut ui);
+    assert!(!windows.windows[0].draw_list.to_string().contains("\"Health\""));
+    click(&mut windows, world.center(), &mut ui);
+    frame(&mut windows, &mut ui);
+    assert!(section(&mut windows, 0).widgets[0].as_widget_row().is_open());
+    assert!(windows.windows[0].draw_list.to_string().contains("\"Health\""));
+}
+
+#[test]
+fn keyboard_opens_and_closes_and_skips_hidden_widgets() {
+    let mut windows = windows();
+    let mut ui = |win: &mut Window| {
+        if let Some(node) = win.TreeNode("Node").body() {
+            node.Button("Hidden");
+        }
+        win.Button("After")
+    };
+    frame(&mut windows, &mut ui);
+    click(&mut windows, vec2(100., 180.), &mut ui);
+    let node = windows.windows[0].widgets[0].id();
+    let after = windows.windows[0].widgets[1].id();
+
+    tap(&mut windows, KeyCode::Tab, &mut ui);
+    assert_eq!(windows.windows[0].focus, Some(node));
+    tap(&mut windows, KeyCode::Tab, &mut ui);
+    assert_eq!(windows.windows[0].focus, Some(after));
+
+    tap(&mut windows, KeyCode::Tab, &mut ui);
+    tap(&mut windows, KeyCode::Right, &mut ui);
+    assert!(section(&mut windows, 0).is_open());
+    tap(&mut windows, KeyCode::Tab, &mut ui);
+    let hidden = section(&mut windows, 0).widgets[0].id();
+    assert_eq!(windows.windows[0].focus, Some(hidden));
+
+    windows.backend.press_key(KeyCode::LeftShift);
+    tap(&mut windows, KeyCode::Tab, &mut ui);
+    windows.backend.release_key(KeyCode::LeftShift);
+    tap(&mut windows, KeyCode::Left, &mut ui);
+    assert!(!section(&mut windows, 0).is_open());
+}
@@ -0,0 +1,179 @@
+use std::cell::Cell;
+
+use amberwindow::*;
+use macroquad::prelude::*;
+
+mod common;
+use common::*;
+
+fn edit(windows: &mut Windows) -> &mut ColorEdit {
+    windows.windows[0].widgets[0].as_color_edit()
+}
+
+fn swatch(windows: &mut Windows) -> Vec2 {
+    let rect = edit(windows).rect;
+    vec2(rect.x + rect.h / 2., rect.center().y)
+}
+
+fn drag(windows: &mut Windows, from: Vec2, to: Vec2, ui: &mut impl FnMut(&mut Window)) {
+    windows.backend.move_mouse(from);
+    windows.backend.press_mouse(MouseButton::Left);
+    frame(windows, &mut *ui);
+    windows.backend.move_mouse(to);
+    frame(windows, &mut *ui);
+    windows.backend.release_mouse(MouseButton::Left);
+    frame(windows, &mut *ui);
+}
+
+fn close(a: Color, b: Color) -> bool {
+    (a.to_vec() - b.to_vec()).abs().max_element() < 0.01
+}
+
+#[test]
+fn dragging_a_channel_field_changes_the_color() {
+    let mut windows = windows();
+    let color = Cell::new(Color::new(0.5, 0.2, 0.2, 1.0));
+    let mut ui = |win: &mut Window| {
+        let mut c = color.get();
+        win.ColorEdit("Tint", &mut c, 180.);
+        color.set(c);
+    };
+    frame(&mut windows, &mut ui);
+    let rect = edit(&mut windows).rect;
+    let red = vec2(rect.x + rect.h + 10., rect.center().y);
+
+    drag(&mut windows, red, red + vec2(10., 0.), &mut ui);
+    frame(&mut windows, &mut ui);
+    assert!(close(color.get(), Color::new(138. / 255., 0.2, 0.2, 1.0)), "{:?}", color.get());
+    assert!(!edit(&mut windows).open);
+
+    // Changes made by the owner show up in the widget.
+    color.set(Color::from_hex(0x336699));
+    frame(&mut windows, &mut ui);
+    assert_eq!(edit(&mut windows).color, Color::from_hex(0x336699));
+    assert_eq!(edit(&mut windows).hex(), "#336699");
+}
+
+#[test]
+fn picking_in_the_square_and_hue_bar() {
+    let mut windows = windows();
+    let color = Cell::new(Color::new(0.8, 0.4, 0.4, 1.0));
+    let mut ui = |win: &mut Window| {
+        let mut c = color.get();
+        win.ColorEdit("Tint", &mut c, 180.);
+        color.set(c);
+    };
+    frame(&mut windows, &mut ui);
+    let at = swatch(&mut windows);
+    click(&mut windows, at, &mut ui);
+    assert!(edit(&mut windows).open);
+    assert!(windows.windows[0].draw_list.overlay_list().is_some());
+
+    // Top right of the square is the hue at full saturation and value.
+    let (square, hue, ..) = edit(&mut windows).popup_parts();
+    drag(&mut windows, square.center(), vec2(square.right() + 20., square.y - 20.), &mut ui);
+    frame(&mut windows, &mut ui);
+    assert!(close(color.get(), Color::new(1., 0., 0., 1.)), "{:?}", color.get());
+
+    // Halfway down the hue bar is cyan.
+    click(&mut windows, hue.center(), &mut ui);
+    frame(&mut windows, &mut ui);
+    assert!(close(color.get(), Color::new(0., 1., 1., 1.)), "{:?}", color.get());
+    assert!(edit(&mut windows).open);
+
+    // Clicking elsewhere closes the popup.
+    click(&mut windows, vec2(700., 500.), &mut ui);
+    assert!(!edit(&mut windows).open);
+}
+
+#[test]
+fn typing_hex_sets_the_color() {
+    let mut windows = windows();
+    let color = Cell::new(Color::new(0.8, 0.4, 0.4, 0.5));
+    let mut ui = |win: &mut Window| {
+        let mut c = color.get();
+        win.ColorEdit("Tint", &mut c, 180.);
+        color.set(c);
+    };
+    frame(&mut windows, &mut ui);
+    let at = swatch(&mut windows);
+    click(&mut windows, at, &mut ui);
+    let (.., hex, _) = edit(&mut windows).popup_parts();
+    click(&mut windows, hex.center(), &mut ui);
+
+    windows.backend.press_key(KeyCode::LeftControl).press_key(KeyCode::A);
+    frame(&mut windows, &mut ui);
+    windows.backend.release_key(KeyCode::A).release_key(KeyCode::LeftControl);
+    windows.backend.type_text("#00FF00");
+    frame(&mut windows, &mut ui);
+    frame(&mut windows, &mut ui);
+    // Six digits keep the alpha, eight set it too.
+    assert!(close(color.get(), Color::new(0., 1., 0., 0.5)), "{:?}", color.get());
+
+    windows.backend.press_key(KeyCode::LeftControl).press_key(KeyCode::A);
+    frame(&mut windows, &mut ui);
+    windows.backend.release_key(KeyCode::A).release_key(KeyCode::LeftControl);
+    windows.backend.type_text("336699FF");
+    frame(&mut windows, &mut ui);
+    frame(&mut windows, &mut ui);
+    assert_eq!(color.get(), Color::from_hex(0x336699));
+
+    // A sign isn't a hex digit, even if `from_str_radix` takes it.
+    windows.backend.press_key(KeyCode::LeftControl).press_key(KeyCode::A);
+    frame(&mut windows, &mut ui);
+    windows.backend.release_key(KeyCode::A).release_key(KeyCode::LeftControl);
+    windows.backend.type_text("+12345");
+    frame(&mut windows, &mut ui);
+    frame(&mut windows, &mut ui);
+    assert_eq!(color.get(), Color::from_hex(0x336699));
+}
+
+#[test]
+fn popup_stays_on_screen() {
+    let mut windows = windows();
+    let color = Cell::new(WHITE);
+    let mut ui = |win: &mut Window| {
+        let mut c = color.get();
+        win.position(vec2(600., 400.)).ColorEdit("Tint", &mut c, 180.);
+        color.set(c);
+    };
+    frame(&mut windows, &mut ui);
+    let at = swatch(&mut windows);
+    click(&mut windows, at, &mut ui);
+    assert!(edit(&mut windows).open);
+
+    let (square, _, alpha, hex, _) = edit(&mut windows).popup_parts();
+    assert!(square.x >= 0. && square.y >= 0., "{:?}", square);
+    assert!(alpha.right() <= 800., "{:?}", alpha);
+    assert!(hex.bottom() <= 600., "{:?}", hex);
+}
+
+#[test]
+fn picked_colors_are_kept_in_the_recent_palette() {
+    let mut windows = windows();
+    let color = Cell::new(WHITE);
+    let mut ui = |win: &mut Window| {
+        let mut c = color.get();
+        win.ColorEdit("Tint", &mut c, 180.);
+        color.set(c);
+    };
+    frame(&mut windows, &mut ui);
+    let at = swatch(&mut windows);
+    click(&mut windows, at, &mut ui);
+    let (_, _, alpha, ..) = edit(&mut windows).popup_parts();
+    click(&mut windows, vec2(alpha.center().x, alpha.bottom() - 1.), &mut ui);
+    let picked = color.get();
+    assert!(picked.a < 0.05);
+
+    // Closing the popup (here with the swatch) remembers the colour.
+    click(&mut windows, at, &mut ui);
+    assert_eq!(edit(&mut windows).recent, [picked]);
+
+    color.set(BLACK);
+    frame(&mut windows, &mut ui);
+    click(&mut windows, at, &mut ui);
+    let (.., palette) = edit(&mut windows).popup_parts();
+    click(&mut windows, palette.center(), &mut ui);
+    frame(&mut windows, &mut ui);
+    assert_eq!(color.get(), picked);
+}
@@ -0,0 +1,167 @@
+use amberwindow::*;
+use macroquad::prelude::*;
+
+mod common;
+use common::*;
+
+const ITEMS: [&str; 9] = ["Easy", "Normal", "Hard", "Brutal", "Nightmare", "Hell", "Inferno", "Abyss", "Void"];
+
+fn combo(windows: &mut Windows) -> &mut Combo {
+    windows.windows[0].widgets[0].as_combo()
+}
+
+/// Center of the `row`th item of the open list.
+fn item(windows: &mut Windows, row: usize) -> Vec2 {
+    let field = combo(windows).rect;
+    vec2(field.x + 20., field.bottom() + 2. + 4. + 20. * row as f32 + 10.)
+}
+
+#[test]
+fn clicking_an_item_picks_it() {
+    let mut windows = windows();
+    let mut difficulty = 1;
+    let mut ui = |win: &mut Window| win.Combo("Difficulty", &mut difficulty, &ITEMS[..3], 100.).changed;
+    frame(&mut windows, &mut ui);
+    let field = combo(&mut windows).rect;
+    assert!(windows.windows[0].draw_list.to_string().contains("\"Normal\""));
+    assert!(windows.windows[0].draw_list.overlay_list().is_none());
+
+    click(&mut windows, field.center() - vec2(60., 0.), &mut ui);
+    assert!(combo(&mut windows).open);
+    let overlay = windows.windows[0].draw_list.overlay_list().unwrap().to_string();
+    assert!(ITEMS[..3].iter().all(|item| overlay.contains(&format!("{:?}", item))), "{}", overlay);
+
+    // Submitted after (so drawn above) the window itself.
+    assert!(windows.draw_lists().last().unwrap().to_string().contains("\"Hard\""));
+
+    let hard = item(&mut windows, 2);
+    windows.backend.move_mouse(hard);
+    windows.backend.press_mouse(MouseButton::Left);
+    frame(&mut windows, &mut ui);
+    windows.backend.release_mouse(MouseButton::Left);
+    assert!(frame(&mut windows, &mut ui));
+    assert!(!combo(&mut windows).open);
+    assert_eq!(difficulty, 2);
+}
+
+#[test]
+fn out_of_range_selection_is_written_back() {
+    let mut windows = windows();
+    let mut difficulty = 7;
+    let mut ui = |win: &mut Window| {
+        win.Combo("Difficulty", &mut difficulty, &ITEMS[..3], 100.);
+    };
+    frame(&mut windows, &mut ui);
+    assert_eq!(combo(&mut windows).selected, 2);
+    frame(&mut windows, &mut ui);
+    assert_eq!(difficulty, 2);
+}
+
+#[test]
+fn keys_move_the_highlight_and_pick() {
+    let mut windows = windows();
+    let mut difficulty = 0;
+    let mut ui = |win: &mut Window| {
+        win.Combo("Difficulty", &mut difficulty, &ITEMS[..3], 100.);
+    };
+    frame(&mut windows, &mut ui);
+    let field = combo(&mut windows).rect;
+
+    click(&mut windows, field.center() - vec2(60., 0.), &mut ui);
+    assert!(windows.wants_keyboard_input());
+    tap(&mut windows, KeyCode::Down, &mut ui);
+    tap(&mut windows, KeyCode::Down, &mut ui);
+    tap(&mut windows, KeyCode::Enter, &mut ui);
+    assert!(!combo(&mut windows).open);
+    assert_eq!(combo(&mut windows).selected, 2);
+
+    // Escape closes the list without picking.
+    tap(&mut windows, KeyCode::Space, &mut ui);
+    assert!(combo(&mut windows).open);
+    tap(&mut windows, KeyCode::Up, &mut ui);
+    tap(&mut windows, KeyCode::Escape, &mut ui);
+    assert!(!combo(&mut windows).open);
+    frame(&mut windows, &mut ui);
+    assert_eq!(difficulty, 2);
+}
+
+#[test]
+fn list_takes_the_mouse_outside_its_window_and_scrolls() {
+    let mut windows = windows();
+    let mut difficulty = 0;
+    let mut ui = |win: &mut Window| {
+        win.Combo("Difficulty", &mut difficulty, &ITEMS, 100.).max_items(5);
+    };
+    frame(&mut windows, &mut ui);
+    let field = combo(&mut windows).rect;
+    click(&mut windows, field.center() - vec2(60., 0.), &mut ui);
+
+    // The list is cut to 5 items; the wheel scrolls it.
+    let overlay = windows.windows[0].draw_list.overlay_list().unwrap().to_string();
+    assert!(!overlay.contains("\"Void\""));
+    let hell = item(&mut windows, 2);
+    windows.backend.move_mouse(hell);
+    windows.backend.scroll(vec2(0., -200.));
+    frame(&mut windows, &mut ui);
+    frame(&mut windows, &mut ui);
+    assert_eq!(windows.windows[0].scroll.offset.y, 0.0);
+    let overlay = windows.windows[0].draw_list.overlay_list().unwrap().to_string();
+    assert!(overlay.contains("\"Void\""), "{}", overlay);
+
+    // Another window in front, under the list: the list still gets the click.
+    let mut other = |windows: &mut Windows| {
+        windows.begin("window").map(&mut ui);
+        if let Some(win) = windows.begin("other") {
+            win.rect = Rect::new(0., 60., 200., 200.);
+            win.Button("Behind the list");
+        }
+        windows.end_windows();
+        windows.backend.next_frame();
+    };
+    other(&mut windows);
+    windows.bring_to_front("other");
+    other(&mut windows);
+    assert!(combo_of(&mut windows).open);
+
+    let void = item_of(&mut windows, 4);
+    windows.backend.move_mouse(void);
+    windows.backend.press_mouse(MouseButton::Left);
+    other(&mut windows);
+    windows.backend.release_mouse(MouseButton::Left);
+    other(&mut windows);
+    other(&mut windows);
+    assert!(!combo_of(&mut windows).open);
+    assert_eq!(combo_of(&mut windows).selected, 8);
+}
+
+fn combo_of(windows: &mut Windows) -> &mut Combo {
+    let idx = windows.get_window_index("window").unwrap();
+    windows.windows[idx].widgets[0].as_combo()
+}
+
+fn item_of(windows: &mut Windows, row: usize) -> Vec2 {
+    let field = combo_of(windows).rect;
+    vec2(field.x + 20., field.bottom() + 2. + 4. + 20. * row as f32 + 10.)
+}
+
+#[test]
+fn typing_filters_the_list() {
+    let mut windows = windows();
+    let mut difficulty = 0;
+    let mut ui = |win: &mut Window| {
+        win.Combo("Difficulty", &mut difficulty, &ITEMS, 100.).filter(true);
+    };
+    frame(&mut windows, &mut ui);
+    let field = combo(&mut windows).rect;
+    click(&mut windows, field.center() - vec2(60., 0.), &mut ui);
+
+    windows.backend.type_text("ell");
+    frame(&mut windows, &mut ui);
+    frame(&mut windows, &mut ui);
+    let overlay = windows.windows[0].draw_list.overlay_list().unwrap().to_string();
+    assert!(overlay.contains("\"Hell\"") && !overlay.contains("\"Easy\""), "{}", overlay);
+
+    tap(&mut windows, KeyCode::Enter, &mut ui);
+    frame(&mut windows, &mut ui);
+    assert_eq!(difficulty, 5);
+}
@@ -0,0 +1,52 @@
+#![allow(dead_code)]
+
+use amberwindow::*;
+use macroquad::prelude::*;
+
+pub type Windows = WindowManager<HeadlessBackend>;
+
+pub fn windows() -> Windows {
+    WindowManager::with_backend(HeadlessBackend::new(800., 600.))
+}
+
+/// Run one frame with a single window, building its widgets with `ui` (returns what `ui` returned).
+pub fn frame<R: Default>(windows: &mut Windows, mut ui: impl FnMut(&mut Window) -> R) -> R {
+    let out = windows.begin("window").map(&mut ui).unwrap_or_default();
+    windows.end_windows();
+    windows.backend.next_frame();
+    out
+}
+
+/// Press and release the left mouse button at `position` over two frames.
+pub fn click<R: Default>(windows: &mut Windows, position: Vec2, mut ui: impl FnMut(&mut Window) -> R) -> R {
+    windows.backend.move_mouse(position);
+    windows.backend.press_mouse(MouseButton::Left);
+    frame(windows, &mut ui);
+    windows.backend.release_mouse(MouseButton::Left);
+    frame(windows, &mut ui)
+}
+
+/// Press and release `key` over two frames (returns what `ui` returned after the release).
+pub fn tap<R: Default>(windows: &mut Windows, key: KeyCode, mut ui: impl FnMut(&mut Window) -> R) -> R {
+    windows.backend.press_key(key);
+    frame(windows, &mut ui);
+    windows.backend.release_key(key);
+    frame(windows, &mut ui)
+}
+
+/// Like `tap`, holding `modifier` down over both frames.
+pub fn tap_with<R: Default>(windows: &mut Windows, modifier: KeyCode, key: KeyCode, ui: impl FnMut(&mut Window) -> R) -> R {
+    windows.backend.press_key(modifier);
+    let out = tap(windows, key, ui);
+    windows.backend.release_key(modifier);
+    out
+}
+
+/// Click into the window's first widget and type `text` (it is taken in the frame after the click).
+pub fn type_text<R: Default>(windows: &mut Windows, text: &str, mut ui: impl FnMut(&mut Window) -> R) {
+    let field = windows.windows[0].widgets[0].rect();
+    click(windows, field.center(), &mut ui);
+    windows.backend.type_text(text);
+    frame(windows, &mut ui);
+    frame(windows, &mut ui);
+}
@@ -0,0 +1,130 @@
+use amberwindow::*;
+use macroquad::prelude::*;
+
+mod common;
+use common::*;
+
+/// Counts clicks, and takes on the window's button colors.
+#[derive(Clone, Debug)]
+struct ClickCounter {
+    name: String,
+    clicks: u32,
+    pressed: bool,
+    rect: Rect,
+    color: Color,
+}
+
+impl ClickCounter {
+    fn new(name: &str) -> Self {
+        Self {
+            name: name.to_owned(),
+            clicks: 0,
+            pressed: false,
+            rect: Rect::default(),
+            color: WHITE,
+        }
+    }
+}
+
+impl WidgetImpl for ClickCounter {
+    fn label(&self) -> String {
+        self.name.clone()
+    }
+
+    fn measure(&mut self, _ctx: &WidgetContext) -> Vec2 {
+        vec2(60., 20.)
+    }
+
+    fn update(&mut self, rect: Rect, ctx: &WidgetContext) {
+        self.rect = rect;
+        if ctx.mouse_released && self.pressed && self.rect.contains(ctx.mouse_position) {
+            self.clicks += 1;
+        }
+        if ctx.backend.is_mouse_button_pressed(MouseButton::Left) && self.rect.contains(ctx.mouse_position) {
+            self.pressed = true;
+        }
+        if !ctx.backend.is_mouse_button_down(MouseButton::Left) {
+            self.pressed = false;
+        }
+    }
+
+    fn render(&mut self, draw: &mut DrawList) {
+        draw.draw_rectangle(self.rect.x, self.rect.y, self.rect.w, self.rect.h, self.color);
+    }
+
+    fn transfer_state(&mut self, previous: &mut dyn WidgetImpl) {
+        if let Some(previous) = previous.downcast_mut::<ClickCounter>() {
+            self.clicks = previous.clicks;
+            self.pressed = previous.pressed;
+        }
+    }
+
+    fn set_button_style(&mut self, style: &ButtonStyle) {
+        self.color = style.bg_color;
+    }
+}
+
+#[test]
+fn custom_widget_is_laid_out_and_rendered() {
+    let mut windows = windows();
+    frame(&mut windows, |win| {
+        win.Button("Above");
+        win.Custom(ClickCounter::new("counter"));
+    });
+
+    let button = windows.windows[0].widgets[0].rect();
+    let counter = windows.windows[0].widgets[1].rect();
+    assert_eq!(counter.size(), vec2(60., 20.));
+    assert_eq!(counter.x, button.x);
+    assert!(counter.y >= button.bottom());
+
+    let expected = format!("rect {} {} 60 20 #ffffffff", counter.x, counter.y);
+    let drawn = windows.windows[0].draw_list.to_string();
+    assert!(drawn.lines().any(|line| line == expected), "{}", drawn);
+}
+
+#[test]
+fn custom_widget_keeps_state_across_frames() {
+    let mut windows = windows();
+    let ui = |win: &mut Window| win.Custom(ClickCounter::new("counter")).clicks;
+    frame(&mut windows, ui);
+
+    let center = windows.windows[0].widgets[0].rect().center();
+    click(&mut windows, center, ui);
+    assert_eq!(frame(&mut windows, ui), 1);
+
+    // The count follows the widget's ID, not its index.
+    let clicks = frame(&mut windows, |win| {
+        win.Text("Now showing", None);
+        win.Custom(ClickCounter::new("counter")).clicks
+    });
+    assert_eq!(clicks, 1);
+}
+
+#[test]
+fn custom_widget_takes_window_styles() {
+    let mut windows = windows();
+    frame(&mut windows, |win| {
+        win.Custom(ClickCounter::new("counter"));
+        win.button_style(ButtonStyle {
+            bg_color: RED,
+            ..Default::default()
+        });
+    });
+
+    let counter = windows.windows[0].widgets[0].as_custom::<ClickCounter>();
+    assert_eq!(counter.color, RED);
+}
+
+#[test]
+fn custom_widget_lookup_checks_type() {
+    let mut windows = windows();
+    frame(&mut windows, |win| {
+        win.Custom(ClickCounter::new("counter"));
+    });
+
+    let widget = &mut windows.windows[0].widgets[0];
+    assert!(widget.try_as_custom::<Text>().is_none());
+    assert!(widget.try_as_button().is_none());
+    assert_eq!(widget.as_custom::<ClickCounter>().name, "counter");
+}
@@ -0,0 +1,118 @@
+use std::{env, fs, path::Path};
+
+use amberwindow::*;
+use macroquad::prelude::*;
+
+/// Compare `actual` with `tests/snapshots/<name>.txt` (set `UPDATE_SNAPSHOTS=1` to rewrite it).
+fn assert_snapshot(name: &str, actual: &str) {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/snapshots")
+        .join(format!("{}.txt", name));
+
+    if env::var_os("UPDATE_SNAPSHOTS").is_some() {
+        fs::write(&path, actual).unwrap();
+    }
+
+    let expected = fs::read_to_string(&path).unwrap_or_default();
+    assert!(
+        expected == actual,
+        "snapshot `{}` changed (rerun with UPDATE_SNAPSHOTS=1 to accept):\n{}",
+        name,
+        actual
+    );
+}
+
+fn frame(windows: &mut WindowManager<HeadlessBackend>) {
+    if let Some(win) = windows.begin("widgets") {
+        win.name("Widgets");
+        win.Text("Hello, world", None);
+        win.Button("Save");
+        win.Slider_float(0., 100., Some(25.), vec2(100., 15.));
+        win.Checkbox("Auto update", true);
+    }
+    if let Some(win) = windows.begin("empty") {
+        win.name("Empty").position(vec2(300., 100.));
+    }
+    windows.end_windows();
+}
+
+#[test]
+fn widgets_frame_snapshot() {
+    let mut windows = WindowManager::with_backend(HeadlessBackend::new(800., 600.));
+    frame(&mut windows);
+    windows.backend.next_frame();
+    frame(&mut windows);
+
+    let lists: Vec<String> = windows.draw_lists().map(|list| list.to_string()).collect();
+    assert_snapshot("widgets_frame", &lists.join("--\n"));
+}
+
+#[test]
+fn submitted_commands_match_draw_lists() {
+    let mut windows = WindowManager::with_backend(HeadlessBackend::new(800., 600.));
+    frame(&mut windows);
+
+    let recorded: String = windows.draw_lists().map(|list| list.to_string()).collect();
+    assert_eq!(windows.backend.drawn.to_string(), recorded);
+}
+
+#[test]
+fn draw_list_replays_into_painter() {
+    let mut list = DrawList::new();
+    list.clip(Some(Rect::new(0., 0., 50., 50.)));
+    list.draw_rectangle(1., 2., 3., 4., RED);
+    list.draw_line(0., 0., 10., 10., 2., WHITE);
+    list.clip(None);
+
+    let mut painter = HeadlessBackend::new(100., 100.);
+    list.submit(&mut painter);
+
+    assert_eq!(
+        painter.drawn.to_string(),
+        "clip 0 0 50 50\nrect 1 2 3 4 #e52838ff\nline 0 0 10 10 2 #ffffffff\nclip none\n"
+    );
+}
+
+#[test]
+fn nested_clips_stay_within_their_parent() {
+    let mut list = DrawList::new();
+    list.push_clip(Rect::new(0., 0., 50., 50.));
+    list.push_clip(Rect::new(40., 10., 30., 30.));
+    list.pop_clip();
+    list.pop_clip();
+
+    assert_eq!(
+        list.to_string(),
+        "clip 0 0 50 50\nclip 40 10 10 30\nclip 0 0 50 50\nclip none\n"
+    );
+}
+
+#[test]
+fn widgets_are_clipped_to_the_window_body() {
+    let mut windows = WindowManager::with_backend(HeadlessBackend::new(800., 600.));
+    if let Some(win) = windows.begin("narrow") {
+        win.size(vec2(100., 100.)).layout(Layout {
+            auto_size: AutoSize::Off,
+            ..Default::default()
+        });
+        win.Text("A label much wider than its window", None);
+        win.WidgetRow().unwrap().Text("Row", None);
+    }
+    windows.end_windows();
+
+    // The body below the title bar, minus the scrollbar the label needs.
+    let window = windows.windows[0].rect;
+    let body = windows.windows[0].scroll.viewport;
+    assert_eq!((body.x, body.y), (window.x, window.y + 20.));
+    let body = format!("clip {} {} {} {}", body.x, body.y, body.w, body.h);
+    let row = windows.windows[0].widgets[1].rect();
+    let row = format!("clip {} {} {} {}", row.x, row.y, row.w, row.h);
+    let drawn = windows.backend.drawn.to_string();
+    let lines: Vec<&str> = drawn.lines().collect();
+
+    let position = |prefix: &str| lines.iter().position(|line| line.starts_with(prefix)).unwrap();
+    assert!(position(&body) < position("text \"A label"));
+    assert!(position(&row) < position("text \"Row\""));
+    assert_eq!(lines.iter().filter(|line| **line == body).count(), 2, "{}", drawn);
+    assert!(position("text \"Row\"") < position("clip none"));
+}
@@ -0,0 +1,131 @@
+use amberwindow::*;
+use macroquad::prelude::*;
+
+mod common;
+use common::*;
+
+/// A button, a checkbox and a slider (returns whether the button was pressed).
+fn form(win: &mut Window) -> bool {
+    win.Text("Settings", None);
+    let pressed = win.Button("Apply");
+    win.Checkbox("Fullscreen", false);
+    win.Slider_float(0., 10., Some(5.), vec2(100., 15.));
+    pressed
+}
+
+/// Select the window by clicking an empty part of it.
+fn select<R: Default>(windows: &mut Windows, ui: impl FnMut(&mut Window) -> R) {
+    click(windows, vec2(100., 180.), ui);
+    assert!(windows.windows[0].selected);
+}
+
+fn focused(windows: &mut Windows) -> Option<u64> {
+    windows.windows[0].focus
+}
+
+#[test]
+fn tab_cycles_through_focusable_widgets() {
+    let mut windows = windows();
+    frame(&mut windows, form);
+    select(&mut windows, form);
+    assert_eq!(focused(&mut windows), None);
+    assert!(!windows.wants_keyboard_input());
+
+    let ids: Vec<u64> = windows.windows[0].widgets[1..].iter().map(|i| i.id()).collect();
+
+    tap(&mut windows, KeyCode::Tab, form);
+    assert_eq!(focused(&mut windows), Some(ids[0]));
+    assert!(windows.wants_keyboard_input());
+    tap(&mut windows, KeyCode::Tab, form);
+    assert_eq!(focused(&mut windows), Some(ids[1]));
+    tap(&mut windows, KeyCode::Tab, form);
+    tap(&mut windows, KeyCode::Tab, form);
+    assert_eq!(focused(&mut windows), Some(ids[0]));
+
+    windows.backend.press_key(KeyCode::LeftShift);
+    tap(&mut windows, KeyCode::Tab, form);
+    windows.backend.release_key(KeyCode::LeftShift);
+    assert_eq!(focused(&mut windows), Some(ids[2]));
+
+    tap(&mut windows, KeyCode::Escape, form);
+    assert_eq!(focused(&mut windows), None);
+}
+
+#[test]
+fn keys_operate_the_focused_widget() {
+    let mut windows = windows();
+    frame(&mut windows, form);
+    select(&mut windows, form);
+
+    tap(&mut windows, KeyCode::Tab, form);
+    assert!(tap(&mut windows, KeyCode::Space, form));
+    assert!(tap(&mut windows, KeyCode::Enter, form));
+
+    tap(&mut windows, KeyCode::Tab, form);
+    tap(&mut windows, KeyCode::Space, form);
+    assert!(windows.windows[0].widgets[2].as_checkbox().value);
+
+    tap(&mut windows, KeyCode::Tab, form);
+    tap(&mut windows, KeyCode::Right, form);
+    assert_eq!(windows.windows[0].widgets[3].as_slider().value, 5.1);
+    tap(&mut windows, KeyCode::Left, form);
+    tap(&mut windows, KeyCode::Left, form);
+    assert_eq!(windows.windows[0].widgets[3].as_slider().value, 4.9);
+
+    // Keys don't reach widgets without focus.
+    tap(&mut windows, KeyCode::Escape, form);
+    assert!(!tap(&mut windows, KeyCode::Space, form));
+    assert!(windows.windows[0].widgets[2].as_checkbox().value);
+}
+
+#[test]
+fn focus_ring_follows_window_selection() {
+    let mut windows = windows();
+    let ui = |win: &mut Window| {
+        win.set_keyboard_focus_here();
+        win.Button("Apply");
+    };
+    frame(&mut windows, ui);
+    let has_ring = |windows: &Windows| {
+        let style = &windows.windows[0].style;
+        let [r, g, b, a]: [u8; 4] = style.focus_color.into();
+        let color = format!("#{:02x}{:02x}{:02x}{:02x}", r, g, b, a);
+        windows.windows[0]
+            .draw_list
+            .to_string()
+            .lines()
+            .any(|line| line.starts_with("rect_lines") && line.ends_with(&color))
+    };
+
+    let button = windows.windows[0].widgets[0].id();
+    assert_eq!(windows.windows[0].focus, Some(button));
+    assert!(!has_ring(&windows));
+    assert!(!windows.wants_keyboard_input());
+
+    select(&mut windows, ui);
+    assert!(has_ring(&windows));
+    assert!(windows.wants_keyboard_input());
+
+    click(&mut windows, vec2(500., 400.), ui);
+    assert!(!windows.windows[0].selected);
+    assert!(!has_ring(&windows));
+    assert_eq!(windows.windows[0].focus, Some(button));
+}
+
+#[test]
+fn widgets_in_rows_can_be_focused() {
+    let mut windows = windows();
+    let ui = |win: &mut Window| {
+        let row = win.WidgetRow().unwrap();
+        let ok = row.Button("Ok").1.is_just_pressed;
+        row.Button("Cancel");
+        ok
+    };
+    frame(&mut windows, ui);
+    select(&mut windows, ui);
+
+    tap(&mut windows, KeyCode::Tab, ui);
+    let ok = windows.windows[0].widgets[0].as_widget_row().widgets[0].id();
+    assert_eq!(windows.windows[0].focus, Some(ok));
+    assert!(tap(&mut windows, KeyCode::Enter, ui));
+}
@@ -0,0 +1,115 @@
+use amberwindow::*;
+use macroquad::prelude::*;
+
+mod common;
+use common::*;
+
+#[test]
+fn dragging_title_bar_moves_window() {
+    let mut windows = windows();
+    frame(&mut windows, |_| {});
+
+    windows.backend.move_mouse(vec2(100., 10.));
+    windows.backend.press_mouse(MouseButton::Left);
+    frame(&mut windows, |_| {});
+    frame(&mut windows, |_| {});
+    windows.backend.move_mouse(vec2(130., 50.));
+    frame(&mut windows, |_| {});
+
+    let rect = windows.windows[0].rect;
+    assert_eq!((rect.x, rect.y), (30., 40.));
+    assert!(windows.windows[0].dragging);
+    assert!(windows.windows[0].selected);
+}
+
+#[test]
+fn dragging_scale_triangle_resizes_window() {
+    let mut windows = windows();
+    frame(&mut windows, |_| {});
+
+    windows.backend.move_mouse(vec2(195., 195.));
+    windows.backend.press_mouse(MouseButton::Left);
+    frame(&mut windows, |_| {});
+    windows.backend.move_mouse(vec2(245., 295.));
+    frame(&mut windows, |_| {});
+
+    let rect = windows.windows[0].rect;
+    assert_eq!((rect.w, rect.h), (250., 300.));
+}
+
+#[test]
+fn clicking_button_sets_is_just_pressed() {
+    let mut windows = windows();
+    let ui = |win: &mut Window| win.Button("Save");
+    frame(&mut windows, ui);
+
+    let center = windows.windows[0].widgets[0].as_button().button_rect.center();
+    assert!(!click(&mut windows, center, ui));
+
+    // `is_just_pressed` is set on release, and read back when the button is pushed next frame.
+    assert!(frame(&mut windows, ui));
+    assert!(!frame(&mut windows, ui));
+}
+
+#[test]
+fn button_ignores_release_outside() {
+    let mut windows = windows();
+    let ui = |win: &mut Window| win.Button("Save");
+    frame(&mut windows, ui);
+
+    let center = windows.windows[0].widgets[0].as_button().button_rect.center();
+    windows.backend.move_mouse(center);
+    windows.backend.press_mouse(MouseButton::Left);
+    frame(&mut windows, ui);
+    windows.backend.move_mouse(vec2(150., 150.));
+    windows.backend.release_mouse(MouseButton::Left);
+    frame(&mut windows, ui);
+
+    assert!(!frame(&mut windows, ui));
+}
+
+#[test]
+fn dragging_slider_changes_value() {
+    let mut windows = windows();
+    let ui = |win: &mut Window| win.Slider_float(0., 100., None, vec2(100., 20.)).0;
+    frame(&mut windows, ui);
+
+    let rect = windows.windows[0].widgets[0].as_slider().rect;
+    windows.backend.move_mouse(vec2(rect.x + 10., rect.center().y));
+    windows.backend.press_mouse(MouseButton::Left);
+    frame(&mut windows, ui);
+    windows.backend.move_mouse(vec2(rect.x + 75., rect.center().y));
+    frame(&mut windows, ui);
+    windows.backend.release_mouse(MouseButton::Left);
+    frame(&mut windows, ui);
+
+    assert_eq!(frame(&mut windows, ui), 75.);
+}
+
+#[test]
+fn clicking_checkbox_toggles_it() {
+    let mut windows = windows();
+    let ui = |win: &mut Window| win.Checkbox("Auto update", false).value;
+    frame(&mut windows, ui);
+
+    let center = windows.windows[0].widgets[0].as_checkbox().rect.center();
+    click(&mut windows, center, ui);
+    assert!(frame(&mut windows, ui));
+
+    click(&mut windows, center, ui);
+    assert!(!frame(&mut windows, ui));
+}
+
+#[test]
+fn ctrl_q_closes_selected_window() {
+    let mut windows = windows();
+    frame(&mut windows, |_| {});
+    click(&mut windows, vec2(100., 100.), |_| {});
+    assert!(windows.windows[0].selected);
+
+    windows.backend.press_key(KeyCode::LeftControl);
+    windows.backend.press_key(KeyCode::Q);
+    frame(&mut windows, |_| {});
+
+    assert!(windows.begin("window").is_none());
+}
@@ -0,0 +1,134 @@
+use amberwindow::*;
+use macroquad::prelude::*;
+
+mod common;
+use common::*;
+
+/// Takes keyboard input, like a key-binding capture.
+#[derive(Clone, Debug)]
+struct KeyCapture;
+
+impl WidgetImpl for KeyCapture {
+    fn measure(&mut self, _ctx: &WidgetContext) -> Vec2 {
+        vec2(50., 20.)
+    }
+
+    fn update(&mut self, _rect: Rect, _ctx: &WidgetContext) {}
+
+    fn render(&mut self, _draw: &mut DrawList) {}
+
+    fn wants_keyboard(&self) -> bool {
+        true
+    }
+}
+
+#[test]
+fn pointer_over_window_is_over_ui() {
+    let mut windows = windows();
+    frame(&mut windows, |_| {});
+
+    assert!(windows.is_point_over_ui(vec2(100., 100.)));
+    assert!(windows.is_point_over_ui(vec2(100., 10.)));
+    assert!(!windows.is_point_over_ui(vec2(400., 400.)));
+
+    windows.backend.move_mouse(vec2(400., 400.));
+    assert!(!windows.wants_mouse_input());
+    windows.backend.move_mouse(vec2(195., 195.));
+    assert!(windows.wants_mouse_input());
+}
+
+#[test]
+fn dragging_slider_off_window_keeps_mouse() {
+    let mut windows = windows();
+    let ui = |win: &mut Window| win.Slider_float(0., 100., None, vec2(100., 20.)).0;
+    frame(&mut windows, ui);
+
+    let rect = windows.windows[0].widgets[0].as_slider().rect;
+    windows.backend.move_mouse(rect.center());
+    windows.backend.press_mouse(MouseButton::Left);
+    frame(&mut windows, ui);
+    windows.backend.move_mouse(vec2(400., 400.));
+    frame(&mut windows, ui);
+
+    assert!(!windows.is_point_over_ui(vec2(400., 400.)));
+    assert!(windows.wants_mouse_input());
+    assert_eq!(windows.windows[0].widgets[0].as_slider().value, 100.);
+    assert!(!windows.unconsumed_input().is_mouse_button_down(MouseButton::Left));
+
+    windows.backend.release_mouse(MouseButton::Left);
+    frame(&mut windows, ui);
+    assert!(!windows.wants_mouse_input());
+}
+
+#[test]
+fn clicks_on_ui_are_not_passed_to_the_game() {
+    let mut windows = windows();
+    let ui = |win: &mut Window| win.Button("Fire");
+    frame(&mut windows, ui);
+
+    let button = windows.windows[0].widgets[0].as_button().button_rect.center();
+    windows.backend.move_mouse(button);
+    windows.backend.press_mouse(MouseButton::Left);
+    windows.begin("window").map(ui);
+    windows.end_windows();
+    assert!(!windows.unconsumed_input().is_mouse_button_pressed(MouseButton::Left));
+    windows.backend.next_frame();
+    windows.backend.release_mouse(MouseButton::Left);
+    frame(&mut windows, ui);
+
+    windows.backend.move_mouse(vec2(500., 300.));
+    windows.backend.press_mouse(MouseButton::Left);
+    windows.begin("window").map(ui);
+    windows.end_windows();
+    assert!(windows.unconsumed_input().is_mouse_button_pressed(MouseButton::Left));
+}
+
+#[test]
+fn keyboard_widget_takes_keys_while_window_selected() {
+    let mut windows = windows();
+    let ui = |win: &mut Window| {
+        win.Custom(KeyCapture);
+    };
+    frame(&mut windows, ui);
+    assert!(!windows.wants_keyboard_input());
+
+    click(&mut windows, vec2(100., 100.), ui);
+    windows.backend.press_key(KeyCode::W);
+    assert!(windows.wants_keyboard_input());
+    assert!(!windows.unconsumed_input().is_key_down(KeyCode::W));
+
+    click(&mut windows, vec2(500., 300.), ui);
+    assert!(!windows.wants_keyboard_input());
+    assert!(windows.unconsumed_input().is_key_down(KeyCode::W));
+}
+
+/// Two windows on top of each other, each with a "Save" button (returns whether each was pressed).
+fn stacked_frame(windows: &mut Windows) -> (bool, bool) {
+    let front = windows.begin("front").map(|win| win.Button("Save")).unwrap_or_default();
+    let back = windows.begin("back").map(|win| win.Button("Save")).unwrap_or_default();
+    windows.end_windows();
+    windows.backend.next_frame();
+    (front, back)
+}
+
+#[test]
+fn only_frontmost_window_gets_the_mouse() {
+    let mut windows = windows();
+    stacked_frame(&mut windows);
+    assert_eq!(windows.windows[0].id, "front");
+
+    let button = windows.windows[0].widgets[0].as_button().button_rect.center();
+    windows.backend.move_mouse(button);
+    stacked_frame(&mut windows);
+    assert!(windows.windows[0].widgets[0].as_button().hovering);
+    assert!(!windows.windows[1].widgets[0].as_button().hovering);
+
+    windows.backend.press_mouse(MouseButton::Left);
+    stacked_frame(&mut windows);
+    windows.backend.release_mouse(MouseButton::Left);
+    stacked_frame(&mut windows);
+    assert_eq!(stacked_frame(&mut windows), (true, false));
+
+    assert!(windows.windows[0].selected);
+    assert!(!windows.windows[1].selected);
+}
@@ -0,0 +1,232 @@
+use amberwindow::*;
+use macroquad::prelude::*;
+
+mod common;
+use common::*;
+
+#[test]
+fn widgets_are_drawn_where_they_take_input() {
+    let mut windows = windows();
+    let ui = |win: &mut Window| {
+        win.Text("Title", None);
+        win.Slider_float(0., 1., None, vec2(80., 15.));
+        win.Button("Save")
+    };
+    frame(&mut windows, ui);
+
+    let button = windows.windows[0].widgets[2].as_button().button_rect;
+    let expected = format!("rect {} {} {} {} ", button.x, button.y, button.w, button.h);
+    let drawn = windows.windows[0].draw_list.to_string();
+    assert!(drawn.lines().any(|line| line.starts_with(&expected)), "{}", drawn);
+
+    click(&mut windows, button.center(), ui);
+    assert!(frame(&mut windows, ui));
+}
+
+#[test]
+fn item_spacing_and_padding_are_configurable() {
+    let mut windows = windows();
+    frame(&mut windows, |win| {
+        win.layout(Layout {
+            item_spacing: 12.,
+            padding: vec2(20., 10.),
+            ..Default::default()
+        });
+        win.Text("First", None);
+        win.Text("Second", None);
+    });
+
+    let window = windows.windows[0].rect;
+    let first = windows.windows[0].widgets[0].rect();
+    let second = windows.windows[0].widgets[1].rect();
+    assert_eq!((first.x, first.y), (window.x + 20., window.y + 20. + 10.));
+    assert_eq!(second.y, first.bottom() + 12.);
+}
+
+#[test]
+fn align_places_the_next_widget() {
+    let mut windows = windows();
+    frame(&mut windows, |win| {
+        win.size(vec2(300., 200.));
+        win.align(Align::End).Text("Right", None);
+        win.align(Align::Center).Text("Middle", None);
+        win.Text("Left", None);
+    });
+
+    let window = windows.windows[0].rect;
+    let right = windows.windows[0].widgets[0].rect();
+    let middle = windows.windows[0].widgets[1].rect();
+    let left = windows.windows[0].widgets[2].rect();
+    assert_eq!(right.right(), window.right() - 7.);
+    assert_eq!(middle.center().x, window.center().x);
+    assert_eq!(left.x, window.x + 7.);
+}
+
+#[test]
+fn fit_shrinks_window_to_content() {
+    let mut windows = windows();
+    let layout = |auto_size| Layout {
+        auto_size,
+        ..Default::default()
+    };
+
+    frame(&mut windows, |win| {
+        win.size(vec2(400., 400.)).layout(layout(AutoSize::Grow));
+        win.Button("Save");
+    });
+    assert_eq!(windows.windows[0].rect.size(), vec2(400., 400.));
+
+    frame(&mut windows, |win| {
+        win.layout(layout(AutoSize::Fit));
+        win.Button("Save");
+        win.Button("Load");
+    });
+    let window = windows.windows[0].rect;
+    let last = windows.windows[0].widgets[1].rect();
+    assert_eq!(window.bottom(), last.bottom() + 5.);
+    assert!(window.w < 400.);
+}
+
+#[test]
+fn nested_rows_and_columns_report_combined_size() {
+    let mut windows = windows();
+    let ui = |win: &mut Window| {
+        let form = win.WidgetRow().unwrap();
+        let labels = form.WidgetColumn().unwrap();
+        labels.Text("Name", None);
+        labels.Text("Volume", None);
+        let controls = form.WidgetColumn().unwrap();
+        let pressed = controls.Button("Player 1").1.is_just_pressed;
+        let buttons = controls.WidgetRow().unwrap();
+        buttons.Button("Ok");
+        buttons.Button("Cancel");
+        pressed
+    };
+    frame(&mut windows, ui);
+
+    let form = windows.windows[0].widgets[0].as_widget_row();
+    let form_rect = form.rect;
+    let labels = form.widgets[0].as_widget_row();
+    assert_eq!(labels.direction, Direction::Vertical);
+    assert_eq!(labels.widgets.len(), 2);
+    assert!(labels.widgets[1].rect().y > labels.widgets[0].rect().bottom());
+    let labels_rect = labels.rect;
+
+    let controls = form.widgets[1].as_widget_row();
+    assert_eq!(controls.rect.x, labels_rect.right() + 5.);
+    assert_eq!(form_rect.w, labels_rect.w + 5. + controls.rect.w);
+    assert_eq!(form_rect.h, labels_rect.h.max(controls.rect.h));
+
+    let player = controls.widgets[0].rect();
+    let buttons = controls.widgets[1].as_widget_row();
+    assert_eq!(buttons.widgets.len(), 2);
+    assert_eq!(buttons.rect.y, player.bottom() + 5.);
+
+    let player = player.center();
+    click(&mut windows, player, ui);
+    assert!(frame(&mut windows, ui));
+}
+
+#[test]
+fn window_widget_rows_are_the_ones_in_the_window() {
+    let mut windows = windows();
+    let widget = WindowWidget::new();
+    let ui = |win: &mut Window| {
+        win.layout.item_spacing = 8.;
+        win.style.scrollbar.width = 4.;
+        let row = widget.WidgetRow(win);
+        row.Button("Ok");
+        row.Button("Cancel");
+        widget.ScrollArea(win, vec2(100., 50.)).Text("Log", None);
+    };
+    frame(&mut windows, ui);
+
+    let row = windows.windows[0].widgets[0].as_widget_row();
+    assert_eq!(row.widgets.len(), 2);
+    assert_eq!(row.spacing, 8.);
+    assert_eq!(row.widgets[1].rect().x, row.widgets[0].rect().right() + 8.);
+    let area = windows.windows[0].widgets[1].as_widget_row();
+    assert_eq!(area.widgets.len(), 1);
+    assert_eq!(area.scrollbar_style.width, 4.);
+}
+
+#[test]
+fn same_line_puts_widgets_side_by_side() {
+    let mut windows = windows();
+    frame(&mut windows, |win| {
+        win.Text("Volume", None);
+        win.same_line();
+        win.Button("Mute");
+        win.same_line().spacing(10.);
+        win.Button("Solo");
+        win.Text("Next line", None);
+    });
+
+    let widgets = &mut windows.windows[0].widgets;
+    let volume = widgets[0].rect();
+    let mute = widgets[1].rect();
+    let solo = widgets[2].rect();
+    let next = widgets[3].rect();
+    assert_eq!((mute.x, mute.y), (volume.right() + 5., volume.y));
+    assert_eq!((solo.x, solo.y), (mute.right() + 15., volume.y));
+    assert_eq!(next.x, volume.x);
+    assert_eq!(next.y, volume.y + volume.h.max(mute.h) + 5.);
+}
+
+#[test]
+fn spacing_and_dummy_leave_room() {
+    let mut windows = windows();
+    frame(&mut windows, |win| {
+        win.Text("First", None);
+        win.spacing(20.);
+        win.Text("Second", None);
+        win.dummy(vec2(50., 30.));
+        win.Text("Third", None);
+    });
+
+    let widgets = &mut windows.windows[0].widgets;
+    let (first, second, dummy, third) = (widgets[0].rect(), widgets[1].rect(), widgets[2].rect(), widgets[3].rect());
+    assert_eq!(second.y, first.bottom() + 5. + 20.);
+    assert_eq!(dummy.size(), vec2(50., 30.));
+    assert_eq!(third.y, second.bottom() + 5. + 30. + 5.);
+    let drawn = format!("{} {} 50 30", dummy.x, dummy.y);
+    assert!(!windows.windows[0].draw_list.to_string().contains(&drawn));
+}
+
+#[test]
+fn indent_moves_widgets_right_until_unindent() {
+    let mut windows = windows();
+    frame(&mut windows, |win| {
+        win.Text("Top", None);
+        win.indent();
+        win.Text("One in", None);
+        win.indent();
+        win.Text("Two in", None);
+        win.unindent().unindent().unindent();
+        win.Text("Back", None);
+    });
+
+    let x = |idx: usize, windows: &mut Windows| windows.windows[0].widgets[idx].rect().x;
+    let top = x(0, &mut windows);
+    assert_eq!(x(1, &mut windows), top + 16.);
+    assert_eq!(x(2, &mut windows), top + 32.);
+    assert_eq!(x(3, &mut windows), top);
+}
+
+#[test]
+fn separator_spans_the_window() {
+    let mut windows = windows();
+    frame(&mut windows, |win| {
+        win.Text("Video", None);
+        win.separator(None);
+        win.indent();
+        win.separator(Some("Audio"));
+    });
+
+    let window = windows.windows[0].rect;
+    let line = windows.windows[0].widgets[1].rect();
+    assert_eq!((line.x, line.w, line.h), (window.x + 7., window.w - 14., 1.));
+    let labelled = windows.windows[0].widgets[2].rect();
+    assert_eq!((labelled.x, labelled.right()), (line.x + 16., line.right()));
+    assert!(windows.windows[0].draw_list.to_string().contains("\"Audio\""));
+}
@@ -0,0 +1,149 @@
+use amberwindow::*;
+use macroquad::prelude::*;
+
+mod common;
+use common::*;
+
+const ITEMS: [&str; 6] = ["grass.png", "stone.png", "water.png", "sand.png", "lava.png", "snow.png"];
+
+/// Click at `position` while holding `modifier`.
+fn click_with<R: Default>(windows: &mut Windows, modifier: KeyCode, position: Vec2, ui: impl FnMut(&mut Window) -> R) {
+    windows.backend.press_key(modifier);
+    click(windows, position, ui);
+    windows.backend.release_key(modifier);
+}
+
+fn list(windows: &mut Windows) -> &mut ListBox {
+    windows.windows[0].widgets[0].as_list_box()
+}
+
+/// Center of the `row`th item (with the list scrolled to the top).
+fn item(windows: &mut Windows, row: usize) -> Vec2 {
+    let rect = list(windows).rect;
+    vec2(rect.x + 20., rect.y + 20. * row as f32 + 10.)
+}
+
+#[test]
+fn clicking_selects_one_item() {
+    let mut windows = windows();
+    let mut picked = None;
+    let mut ui = |win: &mut Window| {
+        win.ListBox("tiles", &mut picked, &ITEMS, vec2(150., 200.));
+    };
+    frame(&mut windows, &mut ui);
+    assert!(windows.windows[0].draw_list.to_string().contains("\"lava.png\""));
+
+    let stone = item(&mut windows, 1);
+    click(&mut windows, stone, &mut ui);
+    assert_eq!(list(&mut windows).selected, vec![1]);
+
+    // Ctrl/Shift don't add to a single selection.
+    let sand = item(&mut windows, 3);
+    click_with(&mut windows, KeyCode::LeftControl, sand, &mut ui);
+    frame(&mut windows, &mut ui);
+    assert_eq!(picked, Some(3));
+}
+
+#[test]
+fn ctrl_toggles_and_shift_selects_ranges() {
+    let mut windows = windows();
+    let mut picked: Vec<usize> = vec![];
+    let mut ui = |win: &mut Window| {
+        win.ListBox("tiles", &mut picked, &ITEMS, vec2(150., 200.));
+    };
+    frame(&mut windows, &mut ui);
+    let rows: Vec<Vec2> = (0..ITEMS.len()).map(|row| item(&mut windows, row)).collect();
+
+    click(&mut windows, rows[1], &mut ui);
+    click_with(&mut windows, KeyCode::LeftControl, rows[4], &mut ui);
+    assert_eq!(list(&mut windows).selected, vec![1, 4]);
+    click_with(&mut windows, KeyCode::LeftControl, rows[1], &mut ui);
+    assert_eq!(list(&mut windows).selected, vec![4]);
+
+    // Shift selects from the last clicked item (even a Ctrl-click), replacing the selection.
+    click_with(&mut windows, KeyCode::LeftShift, rows[3], &mut ui);
+    assert_eq!(list(&mut windows).selected, vec![1, 2, 3]);
+    click_with(&mut windows, KeyCode::LeftShift, rows[0], &mut ui);
+    assert_eq!(list(&mut windows).selected, vec![0, 1]);
+
+    // A plain click starts over.
+    click(&mut windows, rows[0], &mut ui);
+    frame(&mut windows, &mut ui);
+    assert_eq!(picked, vec![0]);
+}
+
+#[test]
+fn double_click_activates_rows_from_a_closure() {
+    let mut windows = windows();
+    let mut picked = None;
+    let mut activated = None;
+    let mut ui = |win: &mut Window| {
+        let list = win.ListBox_with("entities", &mut picked, 100, vec2(150., 100.), |i| format!("entity #{}", i));
+        activated = activated.or(list.activated);
+    };
+    frame(&mut windows, &mut ui);
+    let draw = windows.windows[0].draw_list.to_string();
+    assert!(draw.contains("\"entity #0\"") && !draw.contains("\"entity #50\""));
+
+    let second = item(&mut windows, 2);
+    click(&mut windows, second, &mut ui);
+    assert_eq!(list(&mut windows).activated, None);
+    click(&mut windows, second, &mut ui);
+    frame(&mut windows, &mut ui);
+    assert_eq!(activated, Some(2));
+}
+
+#[test]
+fn keys_and_wheel_move_through_the_list() {
+    let mut windows = windows();
+    let mut picked: Vec<usize> = vec![];
+    let mut ui = |win: &mut Window| {
+        win.ListBox_with("entities", &mut picked, 100, vec2(150., 100.), |i| format!("entity #{}", i));
+    };
+    frame(&mut windows, &mut ui);
+    let first = item(&mut windows, 0);
+    click(&mut windows, first, &mut ui);
+    assert!(windows.wants_keyboard_input());
+
+    tap(&mut windows, KeyCode::Down, &mut ui);
+    assert_eq!(list(&mut windows).selected, vec![1]);
+    windows.backend.press_key(KeyCode::LeftShift);
+    tap(&mut windows, KeyCode::Down, &mut ui);
+    tap(&mut windows, KeyCode::Down, &mut ui);
+    windows.backend.release_key(KeyCode::LeftShift);
+    assert_eq!(list(&mut windows).selected, vec![1, 2, 3]);
+
+    // End scrolls the last item into view.
+    tap(&mut windows, KeyCode::End, &mut ui);
+    assert_eq!(list(&mut windows).selected, vec![99]);
+    assert!(windows.windows[0].draw_list.to_string().contains("\"entity #99\""));
+
+    // The wheel scrolls the list, not the window.
+    windows.backend.move_mouse(first);
+    windows.backend.scroll(vec2(0., 5.));
+    frame(&mut windows, &mut ui);
+    frame(&mut windows, &mut ui);
+    assert_eq!(list(&mut windows).scroll.offset.y, 100. * 20. - 100. - 5. * 20.);
+    assert_eq!(windows.windows[0].scroll.offset.y, 0.0);
+
+    windows.backend.press_key(KeyCode::LeftControl);
+    tap(&mut windows, KeyCode::A, &mut ui);
+    windows.backend.release_key(KeyCode::LeftControl);
+    frame(&mut windows, &mut ui);
+    assert_eq!(picked.len(), 100);
+}
+
+#[test]
+fn window_widget_pushes_list_boxes_from_a_closure() {
+    let mut windows = windows();
+    let widget = WindowWidget::new();
+    let mut picked = Some(1);
+    let mut pushed = None;
+    frame(&mut windows, |win| {
+        pushed = Some(widget.ListBox_with(win, "levels", &mut picked, 3, vec2(150., 100.), |i| format!("level {}", i)));
+    });
+    let (idx, list) = pushed.unwrap();
+    assert_eq!(idx, 0);
+    assert_eq!(list.items, ["level 0", "level 1", "level 2"]);
+    assert!(windows.windows[0].draw_list.to_string().contains("\"level 2\""));
+}
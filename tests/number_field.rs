@@ -0,0 +1,166 @@
+use amberwindow::*;
+use macroquad::prelude::*;
+
+mod common;
+use common::*;
+
+/// Press at `from`, drag `by` and release, over three frames.
+fn drag<R: Default>(windows: &mut Windows, from: Vec2, by: Vec2, mut ui: impl FnMut(&mut Window) -> R) {
+    windows.backend.move_mouse(from);
+    windows.backend.press_mouse(MouseButton::Left);
+    frame(windows, &mut ui);
+    windows.backend.move_mouse(from + by);
+    frame(windows, &mut ui);
+    windows.backend.release_mouse(MouseButton::Left);
+    frame(windows, &mut ui);
+}
+
+fn field(windows: &mut Windows) -> &mut NumberField {
+    windows.windows[0].widgets[0].as_number_field()
+}
+
+#[test]
+fn dragging_changes_the_value_by_speed() {
+    let mut windows = windows();
+    let mut x = 0.0;
+    let mut ui = |win: &mut Window| {
+        win.DragFloat("X", &mut x, 80.).speed(0.5).precision(1).suffix(" m");
+    };
+    frame(&mut windows, &mut ui);
+    let rect = windows.windows[0].widgets[0].rect();
+    assert!(windows.windows[0].draw_list.to_string().contains("\"0.0 m\""));
+
+    drag(&mut windows, rect.center(), vec2(40., 0.), &mut ui);
+    frame(&mut windows, &mut ui);
+    assert_eq!(field(&mut windows).value, 20.0);
+    assert!(windows.windows[0].draw_list.to_string().contains("\"20.0 m\""));
+
+    // No range: it goes below zero too.
+    drag(&mut windows, rect.center(), vec2(-100., 0.), &mut ui);
+    frame(&mut windows, &mut ui);
+    assert_eq!(x, -30.0);
+}
+
+#[test]
+fn ranges_clamp_and_ints_round() {
+    let mut windows = windows();
+    let mut lives = 3;
+    let mut ui = |win: &mut Window| {
+        win.DragInt("Lives", &mut lives, 80.).speed(0.25).range(0., 9.);
+    };
+    frame(&mut windows, &mut ui);
+    let rect = windows.windows[0].widgets[0].rect();
+
+    drag(&mut windows, rect.center(), vec2(10., 0.), &mut ui);
+    frame(&mut windows, &mut ui);
+    assert_eq!(field(&mut windows).value, 6.0);
+
+    drag(&mut windows, rect.center(), vec2(200., 0.), &mut ui);
+    frame(&mut windows, &mut ui);
+    assert_eq!(lives, 9);
+}
+
+#[test]
+fn reversed_ranges_are_swapped() {
+    let mut field = NumberField::new("Lives", 12., None, 80., None);
+    field.range(9., 0.);
+    assert_eq!((field.min, field.max), (0., 9.));
+    assert_eq!(field.value, 9.);
+}
+
+#[test]
+fn out_of_range_values_are_clamped_and_written_back() {
+    let mut windows = windows();
+    let mut lives = 42;
+    let mut ui = |win: &mut Window| {
+        win.DragInt("Lives", &mut lives, 80.).range(0., 9.);
+    };
+    frame(&mut windows, &mut ui);
+    assert_eq!(field(&mut windows).value, 9.);
+    assert!(field(&mut windows).changed);
+    frame(&mut windows, &mut ui);
+    assert_eq!(lives, 9);
+}
+
+#[test]
+fn window_widget_pushes_int_fields() {
+    let mut windows = windows();
+    let widget = WindowWidget::new();
+    let mut lives = 3;
+    let mut pushed = None;
+    frame(&mut windows, |win| pushed = Some(widget.DragInt(win, "Lives", &mut lives, 80.)));
+    let (idx, field) = pushed.take().unwrap();
+    assert_eq!((idx, field.value), (0, 3.0));
+    assert!(field.integer);
+
+    frame(&mut windows, |win| pushed = Some(widget.InputInt(win, "Lives", &mut lives, 80.)));
+    let (idx, field) = pushed.take().unwrap();
+    assert_eq!((idx, field.step), (0, Some(1.0)));
+}
+
+#[test]
+fn double_click_types_a_value_in() {
+    let mut windows = windows();
+    let mut x = 1.5;
+    let mut ui = |win: &mut Window| {
+        win.DragFloat("X", &mut x, 80.);
+    };
+    frame(&mut windows, &mut ui);
+    let rect = windows.windows[0].widgets[0].rect();
+
+    click(&mut windows, rect.center(), &mut ui);
+    assert!(!field(&mut windows).is_editing());
+    click(&mut windows, rect.center(), &mut ui);
+    assert!(field(&mut windows).is_editing());
+    assert!(windows.wants_keyboard_input());
+
+    windows.backend.type_text("42");
+    frame(&mut windows, &mut ui);
+    tap(&mut windows, KeyCode::Enter, &mut ui);
+    frame(&mut windows, &mut ui);
+    assert_eq!(field(&mut windows).value, 42.0);
+    assert!(!field(&mut windows).is_editing());
+
+    // Escape throws the typed value away.
+    windows.backend.press_key(KeyCode::LeftControl);
+    click(&mut windows, rect.center(), &mut ui);
+    windows.backend.release_key(KeyCode::LeftControl);
+    assert!(field(&mut windows).is_editing());
+    windows.backend.type_text("7");
+    frame(&mut windows, &mut ui);
+    tap(&mut windows, KeyCode::Escape, &mut ui);
+    assert!(!field(&mut windows).is_editing());
+    assert_eq!(x, 42.0);
+}
+
+#[test]
+fn input_fields_have_step_buttons() {
+    let mut windows = windows();
+    let mut count = 0;
+    let mut ui = |win: &mut Window| {
+        win.InputInt("Count", &mut count, 100.).range(0., 10.);
+    };
+    frame(&mut windows, &mut ui);
+    let rect = windows.windows[0].widgets[0].rect();
+    let plus = vec2(rect.x + 100. - 5., rect.center().y);
+    let minus = plus - vec2(rect.h + 2., 0.);
+
+    click(&mut windows, plus, &mut ui);
+    click(&mut windows, plus, &mut ui);
+    frame(&mut windows, &mut ui);
+    assert_eq!(field(&mut windows).value, 2.0);
+    click(&mut windows, minus, &mut ui);
+    click(&mut windows, minus, &mut ui);
+    click(&mut windows, minus, &mut ui);
+    frame(&mut windows, &mut ui);
+    assert_eq!(field(&mut windows).value, 0.0);
+
+    // A single click starts typing.
+    click(&mut windows, vec2(rect.x + 10., rect.center().y), &mut ui);
+    assert!(field(&mut windows).is_editing());
+    windows.backend.type_text("5");
+    frame(&mut windows, &mut ui);
+    tap(&mut windows, KeyCode::Enter, &mut ui);
+    frame(&mut windows, &mut ui);
+    assert_eq!(count, 5);
+}
@@ -0,0 +1,74 @@
+use amberwindow::*;
+use macroquad::prelude::*;
+
+mod common;
+use common::*;
+
+#[test]
+fn progress_bar_fills_to_its_fraction() {
+    let mut windows = windows();
+    frame(&mut windows, |win| {
+        win.ProgressBar(0.25, vec2(100., 15.)).overlay("1/4");
+        win.ProgressBar(1.5, vec2(100., 15.));
+    });
+    let quarter = windows.windows[0].widgets[0].as_progress_bar().clone();
+    assert_eq!(quarter.fill_rect().w, 25.);
+    assert_eq!(quarter.fill_rect().x, quarter.rect.x);
+    assert_eq!(windows.windows[0].widgets[1].as_progress_bar().fill_rect().w, 100.);
+    assert!(windows.windows[0].draw_list.to_string().contains("\"1/4\""));
+}
+
+#[test]
+fn zero_width_fills_the_window() {
+    let mut windows = windows();
+    frame(&mut windows, |win| {
+        win.ProgressBar(0.5, vec2(0., 15.));
+        win.Spinner(vec2(0., 24.));
+    });
+    let window = windows.windows[0].rect;
+    let bar = windows.windows[0].widgets[0].rect();
+    assert_eq!(bar.w, window.w - 14.);
+    assert_eq!(bar.h, 15.);
+    let spinner = windows.windows[0].widgets[1].rect();
+    assert_eq!(spinner.w, window.w - 14.);
+}
+
+#[test]
+fn indeterminate_bar_sweeps_across() {
+    let mut windows = windows();
+    let mut ui = |win: &mut Window| {
+        win.ProgressBar(0., vec2(90., 15.)).indeterminate(true);
+    };
+    frame(&mut windows, &mut ui);
+    let rect = windows.windows[0].widgets[0].rect();
+    let mut last = windows.windows[0].widgets[0].as_progress_bar().fill_rect().x;
+    let mut wrapped = false;
+    for _ in 0..100 {
+        frame(&mut windows, &mut ui);
+        let fill = windows.windows[0].widgets[0].as_progress_bar().fill_rect();
+        assert_eq!(fill.w, 30.);
+        assert!(fill.right() >= rect.x && fill.x <= rect.right());
+        wrapped |= fill.x < last;
+        last = fill.x;
+    }
+    // It goes round every 1.5 seconds.
+    assert!(wrapped);
+}
+
+#[test]
+fn spinner_turns_over_time() {
+    let mut windows = windows();
+    let mut ui = |win: &mut Window| {
+        win.Spinner(vec2(20., 20.)).speed(2.0);
+    };
+    frame(&mut windows, &mut ui);
+    let first = windows.windows[0].widgets[0].as_spinner().head();
+    let circles = windows.windows[0].draw_list.to_string().matches("circle ").count();
+    assert_eq!(circles, 8);
+
+    // Two turns a second: 16 dots a second, so a dot per ~4 frames.
+    for _ in 0..4 {
+        frame(&mut windows, &mut ui);
+    }
+    assert_ne!(windows.windows[0].widgets[0].as_spinner().head(), first);
+}
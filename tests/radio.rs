@@ -0,0 +1,117 @@
+use amberwindow::*;
+use macroquad::prelude::*;
+
+mod common;
+use common::*;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Difficulty {
+    Easy,
+    Normal,
+    Hard,
+}
+
+fn radio(windows: &mut Windows, idx: usize) -> &mut RadioButton {
+    windows.windows[0].widgets[idx].as_radio_button()
+}
+
+#[test]
+fn clicking_picks_the_option() {
+    let mut windows = windows();
+    let mut difficulty = Difficulty::Normal;
+    let mut ui = |win: &mut Window| {
+        win.RadioButton("Easy", &mut difficulty, Difficulty::Easy);
+        win.RadioButton("Normal", &mut difficulty, Difficulty::Normal);
+        win.RadioButton("Hard", &mut difficulty, Difficulty::Hard).changed
+    };
+    frame(&mut windows, &mut ui);
+    assert!(radio(&mut windows, 1).selected);
+    assert!(!radio(&mut windows, 2).selected);
+    assert!(windows.windows[0].draw_list.to_string().contains("circle_lines"));
+
+    let hard = radio(&mut windows, 2).rect.center();
+    click(&mut windows, hard, &mut ui);
+    assert!(frame(&mut windows, &mut ui));
+    // "Normal" was pushed before "Hard" set the value, so it catches up a frame later.
+    frame(&mut windows, &mut ui);
+    assert!(!radio(&mut windows, 1).selected);
+    assert!(radio(&mut windows, 2).selected);
+
+    // Picking the selected one again isn't a change.
+    click(&mut windows, hard, &mut ui);
+    assert!(!frame(&mut windows, &mut ui));
+    assert_eq!(difficulty, Difficulty::Hard);
+}
+
+#[test]
+fn groups_build_from_pairs_and_report_changes() {
+    let mut windows = windows();
+    let mut size = 0usize;
+    let mut ui = |win: &mut Window| win.RadioGroup(&mut size, &[("Small", 0), ("Medium", 1), ("Large", 2)]);
+    frame(&mut windows, &mut ui);
+    let large = radio(&mut windows, 2).rect;
+    assert!(large.y > radio(&mut windows, 0).rect.y);
+
+    click(&mut windows, large.center(), &mut ui);
+    assert!(frame(&mut windows, &mut ui));
+    // Buttons pushed before the picked one already show the new choice.
+    assert!(!radio(&mut windows, 0).selected);
+    assert!(!frame(&mut windows, &mut ui));
+    assert_eq!(size, 2);
+}
+
+#[test]
+fn groups_in_a_row_are_horizontal() {
+    let mut windows = windows();
+    let mut mode = 1usize;
+    let mut ui = |win: &mut Window| {
+        let row = win.WidgetRow().unwrap();
+        row.RadioGroup(&mut mode, &[("Move", 0), ("Rotate", 1), ("Scale", 2)])
+    };
+    frame(&mut windows, &mut ui);
+    let buttons: Vec<Rect> = (0..3)
+        .map(|idx| windows.windows[0].widgets[0].as_widget_row().widgets[idx].rect())
+        .collect();
+    assert!(buttons[0].right() <= buttons[1].x && buttons[1].right() <= buttons[2].x);
+    assert_eq!(buttons[0].y, buttons[2].y);
+
+    click(&mut windows, buttons[0].center(), &mut ui);
+    assert!(frame(&mut windows, &mut ui));
+    assert_eq!(mode, 0);
+}
+
+#[test]
+fn focused_buttons_are_picked_with_space() {
+    let mut windows = windows();
+    let mut choice = 0usize;
+    let mut ui = |win: &mut Window| {
+        win.RadioButton("A", &mut choice, 0);
+        win.RadioButton("B", &mut choice, 1);
+    };
+    frame(&mut windows, &mut ui);
+    click(&mut windows, vec2(100., 180.), &mut ui);
+
+    for key in [KeyCode::Tab, KeyCode::Tab, KeyCode::Space] {
+        windows.backend.press_key(key);
+        frame(&mut windows, &mut ui);
+        windows.backend.release_key(key);
+        frame(&mut windows, &mut ui);
+    }
+    assert_eq!(choice, 1);
+}
+
+#[test]
+fn window_widget_pushes_groups() {
+    let mut windows = windows();
+    let widget = WindowWidget::new();
+    let mut size = 1usize;
+    let mut ui = |win: &mut Window| widget.RadioGroup(win, &mut size, &[("Small", 0), ("Medium", 1), ("Large", 2)]);
+    assert_eq!(frame(&mut windows, &mut ui), (vec![0, 1, 2], false));
+    assert!(radio(&mut windows, 1).selected);
+
+    let small = radio(&mut windows, 0).rect.center();
+    click(&mut windows, small, &mut ui);
+    assert!(frame(&mut windows, &mut ui).1);
+    assert!(!radio(&mut windows, 1).selected);
+    assert_eq!(size, 0);
+}
@@ -0,0 +1,141 @@
+use amberwindow::*;
+use macroquad::prelude::*;
+
+mod common;
+use common::*;
+
+/// A fixed-size window with more lines of text than fit in it.
+fn log(win: &mut Window) {
+    win.size(vec2(200., 150.)).layout(Layout {
+        auto_size: AutoSize::Off,
+        ..Default::default()
+    });
+    for i in 0..30 {
+        win.Text(&format!("Line {}", i), None);
+    }
+}
+
+#[test]
+fn window_keeps_its_size_and_scrolls_with_the_wheel() {
+    let mut windows = windows();
+    frame(&mut windows, log);
+    assert_eq!(windows.windows[0].rect.size(), vec2(200., 150.));
+    assert_eq!(windows.windows[0].scroll.offset, Vec2::ZERO);
+
+    let first = windows.windows[0].widgets[0].rect();
+    windows.backend.move_mouse(windows.windows[0].rect.center());
+    windows.backend.scroll(vec2(0., -1.));
+    frame(&mut windows, log);
+    frame(&mut windows, log);
+
+    assert_eq!(windows.windows[0].scroll.offset.y, 20.);
+    assert_eq!(windows.windows[0].widgets[0].rect().y, first.y - 20.);
+    assert_eq!(windows.windows[0].rect.size(), vec2(200., 150.));
+}
+
+#[test]
+fn scroll_to_bottom_shows_the_last_widget() {
+    let mut windows = windows();
+    frame(&mut windows, log);
+    frame(&mut windows, |win| {
+        log(win);
+        win.scroll_to_bottom();
+    });
+    frame(&mut windows, log);
+
+    let window = &windows.windows[0];
+    assert_eq!(window.scroll.offset, window.scroll.max_offset());
+    assert!(window.scroll.offset.y > 0.);
+    let last = window.widgets[29].rect();
+    assert!(window.scroll.viewport.contains(last.point()));
+
+    let drawn = window.draw_list.to_string();
+    assert!(drawn.contains("\"Line 29\""), "{}", drawn);
+    assert!(!drawn.contains("\"Line 0\""), "{}", drawn);
+}
+
+#[test]
+fn dragging_the_scrollbar_scrolls() {
+    let mut windows = windows();
+    frame(&mut windows, log);
+
+    let window = windows.windows[0].rect;
+    let viewport = windows.windows[0].scroll.viewport;
+    assert!(viewport.w < window.w);
+
+    // Press on the bar's track at the top, then drag it to the bottom.
+    let bar_x = (viewport.right() + window.right()) / 2.;
+    windows.backend.move_mouse(vec2(bar_x, viewport.y + 2.));
+    windows.backend.press_mouse(MouseButton::Left);
+    frame(&mut windows, log);
+    assert!(windows.wants_mouse_input());
+
+    windows.backend.move_mouse(vec2(bar_x, 590.));
+    frame(&mut windows, log);
+    assert_eq!(windows.windows[0].scroll.offset, windows.windows[0].scroll.max_offset());
+    assert_eq!(windows.windows[0].rect, window);
+
+    windows.backend.release_mouse(MouseButton::Left);
+    frame(&mut windows, log);
+    assert!(!windows.windows[0].scroll.is_dragging());
+}
+
+#[test]
+fn widgets_scrolled_out_of_view_are_not_drawn_or_clickable() {
+    let mut windows = windows();
+    let ui = |win: &mut Window| {
+        let pressed = win.Button("Hidden");
+        log(win);
+        pressed
+    };
+    frame(&mut windows, ui);
+    assert!(windows.windows[0].draw_list.to_string().contains("Hidden"));
+
+    // Scroll the button up behind the title bar.
+    frame(&mut windows, |win| {
+        win.set_scroll_y(27.);
+        ui(win)
+    });
+    frame(&mut windows, ui);
+
+    let window = windows.windows[0].rect;
+    let button = windows.windows[0].widgets[0].rect();
+    assert!(!windows.windows[0].scroll.viewport.overlaps(&button));
+    assert!(window.contains(button.center()));
+    assert!(!windows.windows[0].draw_list.to_string().contains("Hidden"));
+
+    assert!(!click(&mut windows, button.center(), ui));
+    assert!(!frame(&mut windows, ui));
+}
+
+#[test]
+fn scroll_area_takes_the_wheel_before_its_window() {
+    let mut windows = windows();
+    let ui = |win: &mut Window| {
+        log(win);
+        let area = win.ScrollArea(vec2(120., 60.)).unwrap();
+        for i in 0..10 {
+            area.Text(&format!("Item {}", i), None);
+        }
+    };
+    frame(&mut windows, ui);
+    frame(&mut windows, |win| {
+        ui(win);
+        win.set_scroll_y(f32::INFINITY);
+    });
+    frame(&mut windows, ui);
+
+    let window_offset = windows.windows[0].scroll.offset;
+    let area = windows.windows[0].widgets[30].as_widget_row();
+    assert_eq!(area.rect.size(), vec2(120., 60.));
+    assert_eq!(area.scroll.offset, Vec2::ZERO);
+
+    windows.backend.move_mouse(area.rect.center());
+    windows.backend.scroll(vec2(0., -1.));
+    frame(&mut windows, ui);
+    frame(&mut windows, ui);
+
+    let area = windows.windows[0].widgets[30].as_widget_row();
+    assert_eq!(area.scroll.offset.y, 20.);
+    assert_eq!(windows.windows[0].scroll.offset, window_offset);
+}
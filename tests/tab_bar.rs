@@ -0,0 +1,185 @@
+use amberwindow::*;
+use macroquad::prelude::*;
+
+mod common;
+use common::*;
+
+fn bar(windows: &mut Windows) -> &mut WidgetRow {
+    windows.windows[0].widgets[0].as_widget_row()
+}
+
+fn tab_rect(windows: &mut Windows, label: &str) -> Rect {
+    let tabs = bar(windows).tabs.as_ref().unwrap();
+    tabs.tabs.iter().find(|tab| tab.label == label).unwrap().rect
+}
+
+#[test]
+fn only_the_selected_page_is_shown() {
+    let mut windows = windows();
+    let mut ui = |win: &mut Window| {
+        let tabs = win.TabBar("Settings");
+        if let Some(general) = tabs.Tab("General") {
+            general.Text("Name", None);
+            general.Button("Reset");
+        }
+        if let Some(audio) = tabs.Tab("Audio") {
+            audio.Text("Volume", None);
+        }
+        win.Text("Below", None);
+    };
+    frame(&mut windows, &mut ui);
+    assert_eq!(bar(&mut windows).selected_tab(), Some("General"));
+    assert_eq!(bar(&mut windows).widgets.len(), 2);
+    assert!(windows.windows[0].draw_list.to_string().contains("\"Name\""));
+    assert!(!windows.windows[0].draw_list.to_string().contains("\"Volume\""));
+
+    let audio = tab_rect(&mut windows, "Audio");
+    click(&mut windows, audio.center(), &mut ui);
+    frame(&mut windows, &mut ui);
+    assert_eq!(bar(&mut windows).selected_tab(), Some("Audio"));
+    assert_eq!(bar(&mut windows).widgets.len(), 1);
+    assert!(windows.windows[0].draw_list.to_string().contains("\"Volume\""));
+    assert!(!windows.windows[0].draw_list.to_string().contains("\"Name\""));
+
+    // The page goes under the tabs, and the window's next widget under the page.
+    let volume = bar(&mut windows).widgets[0].rect();
+    assert!(volume.y >= audio.bottom());
+    assert!(windows.windows[0].widgets[1].rect().y >= volume.bottom());
+}
+
+#[test]
+fn window_widget_tab_bar_takes_pages() {
+    let mut windows = windows();
+    let widget = WindowWidget::new();
+    let ui = |win: &mut Window| {
+        let tabs = widget.TabBar(win, "Settings");
+        if let Some(general) = tabs.Tab("General") {
+            general.Text("Name", None);
+        }
+        tabs.Tab("Audio");
+    };
+    frame(&mut windows, ui);
+    frame(&mut windows, ui);
+    assert_eq!(bar(&mut windows).widgets.len(), 1);
+    assert!(windows.windows[0].draw_list.to_string().contains("\"Name\""));
+}
+
+#[test]
+fn closing_a_tab_clears_its_flag_and_selects_a_neighbour() {
+    let mut windows = windows();
+    let mut log_open = true;
+    let mut ui = |win: &mut Window| {
+        let tabs = win.TabBar("Panels");
+        tabs.Tab("Scene");
+        if let Some(log) = tabs.TabClosable("Log", &mut log_open) {
+            log.Text("Errors", None);
+        }
+        tabs.Tab("Stats");
+    };
+    frame(&mut windows, &mut ui);
+    let log = tab_rect(&mut windows, "Log");
+    click(&mut windows, log.center(), &mut ui);
+    assert_eq!(bar(&mut windows).selected_tab(), Some("Log"));
+
+    // The close button sits at the right end of the tab.
+    click(&mut windows, vec2(log.right() - 10., log.center().y), &mut ui);
+    frame(&mut windows, &mut ui);
+    assert!(!log_open);
+    assert_eq!(bar(&mut windows).selected_tab(), Some("Stats"));
+    let labels: Vec<_> = bar(&mut windows).tabs.as_ref().unwrap().tabs.iter().map(|tab| tab.label.clone()).collect();
+    assert_eq!(labels, ["Scene", "Stats"]);
+}
+
+#[test]
+fn dragging_a_tab_reorders_it() {
+    let mut windows = windows();
+    let mut ui = |win: &mut Window| {
+        let tabs = win.TabBar("Files");
+        tabs.Tab("a.rs");
+        tabs.Tab("b.rs");
+        tabs.Tab("c.rs");
+    };
+    frame(&mut windows, &mut ui);
+    let a = tab_rect(&mut windows, "a.rs");
+    let c = tab_rect(&mut windows, "c.rs");
+
+    windows.backend.move_mouse(a.center());
+    windows.backend.press_mouse(MouseButton::Left);
+    frame(&mut windows, &mut ui);
+    for x in [a.center().x + 20., a.center().x + 40., c.center().x + 5.] {
+        windows.backend.move_mouse(vec2(x, a.center().y));
+        frame(&mut windows, &mut ui);
+    }
+    windows.backend.release_mouse(MouseButton::Left);
+    frame(&mut windows, &mut ui);
+    frame(&mut windows, &mut ui);
+
+    let tabs = bar(&mut windows).tabs.as_ref().unwrap();
+    assert_eq!(tabs.order, ["b.rs", "c.rs", "a.rs"]);
+    let labels: Vec<_> = tabs.tabs.iter().map(|tab| tab.label.as_str()).collect();
+    assert_eq!(labels, ["b.rs", "c.rs", "a.rs"]);
+    assert_eq!(tabs.selected.as_deref(), Some("a.rs"));
+}
+
+#[test]
+fn overflowing_tabs_scroll() {
+    let mut windows = windows();
+    let mut ui = |win: &mut Window| {
+        let tabs = win.TabBar("Documents");
+        for i in 0..6 {
+            tabs.Tab(&format!("Document {i}"));
+        }
+    };
+    frame(&mut windows, &mut ui);
+    let strip = bar(&mut windows).tabs.as_ref().unwrap().rect;
+    assert!(strip.right() <= windows.windows[0].rect.right());
+    assert_eq!(bar(&mut windows).tabs.as_ref().unwrap().scroll, 0.);
+
+    // The arrows at the right end of the strip scroll it.
+    click(&mut windows, vec2(strip.right() - strip.h / 2., strip.center().y), &mut ui);
+    assert!(bar(&mut windows).tabs.as_ref().unwrap().scroll > 0.);
+    click(&mut windows, vec2(strip.right() - strip.h * 1.5, strip.center().y), &mut ui);
+    assert_eq!(bar(&mut windows).tabs.as_ref().unwrap().scroll, 0.);
+
+    // Selecting a tab out of view scrolls to it.
+    bar(&mut windows).select_tab("Document 5");
+    frame(&mut windows, &mut ui);
+    let last = tab_rect(&mut windows, "Document 5");
+    assert!(last.right() <= strip.right() - strip.h * 2. + 0.01);
+    assert!(last.x >= strip.x);
+}
+
+#[test]
+fn switching_tabs_keeps_each_page_state() {
+    let mut windows = windows();
+    let mut ui = |win: &mut Window| {
+        let tabs = win.TabBar("Inspector");
+        if let Some(a) = tabs.Tab("A") {
+            if let Some(details) = a.CollapsingHeader("Details").body() {
+                details.Text("Mass", None);
+            }
+        }
+        if let Some(b) = tabs.Tab("B") {
+            b.Text("Other", None);
+        }
+    };
+    frame(&mut windows, &mut ui);
+    let header = bar(&mut windows).widgets[0].rect();
+    click(&mut windows, header.center(), &mut ui);
+    frame(&mut windows, &mut ui);
+    assert!(bar(&mut windows).widgets[0].as_widget_row().is_open());
+
+    let b = tab_rect(&mut windows, "B");
+    click(&mut windows, b.center(), &mut ui);
+    frame(&mut windows, &mut ui);
+    assert_eq!(bar(&mut windows).selected_tab(), Some("B"));
+    assert_eq!(bar(&mut windows).widgets.len(), 1);
+
+    // Back on A, the header is still open.
+    let a = tab_rect(&mut windows, "A");
+    click(&mut windows, a.center(), &mut ui);
+    frame(&mut windows, &mut ui);
+    assert_eq!(bar(&mut windows).selected_tab(), Some("A"));
+    assert!(bar(&mut windows).widgets[0].as_widget_row().is_open());
+    assert!(windows.windows[0].draw_list.to_string().contains("\"Mass\""));
+}
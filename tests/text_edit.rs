@@ -0,0 +1,200 @@
+use amberwindow::*;
+use macroquad::prelude::*;
+
+mod common;
+use common::*;
+
+/// A window with a script editor.
+fn script_editor(script: &mut String) -> impl FnMut(&mut Window) + '_ {
+    move |win| {
+        win.TextEdit("Script", script, vec2(150., 60.));
+    }
+}
+
+fn editor(windows: &mut Windows) -> &mut TextEdit {
+    windows.windows[0].widgets[0].as_text_edit()
+}
+
+#[test]
+fn enter_starts_new_lines() {
+    let mut windows = windows();
+    let mut script = String::new();
+    frame(&mut windows, script_editor(&mut script));
+    assert!(windows.windows[0].draw_list.to_string().contains("\"Script\""));
+
+    type_text(&mut windows, "let a", script_editor(&mut script));
+    tap(&mut windows, KeyCode::Enter, script_editor(&mut script));
+    windows.backend.type_text("let b");
+    frame(&mut windows, script_editor(&mut script));
+    frame(&mut windows, script_editor(&mut script));
+    assert_eq!(script, "let a\nlet b");
+    assert!(windows.wants_keyboard_input());
+
+    let drawn = windows.windows[0].draw_list.to_string();
+    assert!(drawn.contains("\"let a\"") && drawn.contains("\"let b\""), "{}", drawn);
+}
+
+#[test]
+fn caret_moves_and_selects_across_lines() {
+    let mut windows = windows();
+    let mut script = String::new();
+    frame(&mut windows, script_editor(&mut script));
+    type_text(&mut windows, "one two\nthree", script_editor(&mut script));
+
+    // Up keeps to the column.
+    tap(&mut windows, KeyCode::Up, script_editor(&mut script));
+    assert_eq!(editor(&mut windows).caret(), 5);
+    tap(&mut windows, KeyCode::Down, script_editor(&mut script));
+    assert_eq!(editor(&mut windows).caret(), 13);
+
+    // Words.
+    tap_with(&mut windows, KeyCode::LeftControl, KeyCode::Left, script_editor(&mut script));
+    assert_eq!(editor(&mut windows).caret(), 8);
+    tap_with(&mut windows, KeyCode::LeftControl, KeyCode::Left, script_editor(&mut script));
+    assert_eq!(editor(&mut windows).caret(), 4);
+    tap_with(&mut windows, KeyCode::LeftControl, KeyCode::Right, script_editor(&mut script));
+    assert_eq!(editor(&mut windows).caret(), 7);
+
+    // Shift+Down selects into the next line.
+    windows.backend.press_key(KeyCode::LeftShift);
+    tap(&mut windows, KeyCode::Down, script_editor(&mut script));
+    windows.backend.release_key(KeyCode::LeftShift);
+    assert_eq!(editor(&mut windows).selection(), Some((7, 13)));
+
+    tap_with(&mut windows, KeyCode::LeftControl, KeyCode::Backspace, script_editor(&mut script));
+    assert_eq!(script, "one two");
+}
+
+#[test]
+fn keys_in_the_frame_of_typing_and_altgr_characters() {
+    let mut windows = windows();
+    let mut script = String::new();
+    frame(&mut windows, script_editor(&mut script));
+    type_text(&mut windows, "ab", script_editor(&mut script));
+
+    // Home sees the line typed in the same frame.
+    windows.backend.type_text("\ncd").press_key(KeyCode::Home);
+    frame(&mut windows, script_editor(&mut script));
+    windows.backend.release_key(KeyCode::Home);
+    frame(&mut windows, script_editor(&mut script));
+    assert_eq!(script, "ab\ncd");
+    assert_eq!(editor(&mut windows).caret(), 3);
+
+    // AltGr comes as Ctrl+Alt on Windows.
+    windows.backend.press_key(KeyCode::RightControl).press_key(KeyCode::RightAlt).type_text("{");
+    frame(&mut windows, script_editor(&mut script));
+    windows.backend.release_key(KeyCode::RightAlt).release_key(KeyCode::RightControl);
+    frame(&mut windows, script_editor(&mut script));
+    assert_eq!(script, "ab\n{cd");
+}
+
+#[test]
+fn undo_and_redo() {
+    let mut windows = windows();
+    let mut script = String::new();
+    frame(&mut windows, script_editor(&mut script));
+    type_text(&mut windows, "hello", script_editor(&mut script));
+    tap(&mut windows, KeyCode::Enter, script_editor(&mut script));
+    windows.backend.type_text("world");
+    frame(&mut windows, script_editor(&mut script));
+    frame(&mut windows, script_editor(&mut script));
+    assert_eq!(script, "hello\nworld");
+
+    tap_with(&mut windows, KeyCode::LeftControl, KeyCode::Z, script_editor(&mut script));
+    assert_eq!(script, "hello\n");
+    tap_with(&mut windows, KeyCode::LeftControl, KeyCode::Z, script_editor(&mut script));
+    assert_eq!(script, "hello");
+    tap_with(&mut windows, KeyCode::LeftControl, KeyCode::Y, script_editor(&mut script));
+    assert_eq!(script, "hello\n");
+    assert_eq!(editor(&mut windows).caret(), 6);
+
+    // Text set by the program isn't undone over.
+    script = String::from("reset");
+    frame(&mut windows, script_editor(&mut script));
+    tap_with(&mut windows, KeyCode::LeftControl, KeyCode::Z, script_editor(&mut script));
+    assert_eq!(script, "reset");
+    tap_with(&mut windows, KeyCode::LeftControl, KeyCode::Y, script_editor(&mut script));
+    assert_eq!(script, "reset");
+}
+
+#[test]
+fn tab_is_typed_instead_of_moving_focus() {
+    let mut windows = windows();
+    let mut script = String::new();
+    frame(&mut windows, script_editor(&mut script));
+    type_text(&mut windows, "a", script_editor(&mut script));
+    let id = editor(&mut windows).id;
+
+    tap(&mut windows, KeyCode::Tab, script_editor(&mut script));
+    assert_eq!(script, "a    ");
+    assert_eq!(windows.windows[0].focus, Some(id));
+}
+
+#[test]
+fn read_only_text_can_be_copied_but_not_edited() {
+    let mut windows = windows();
+    let mut log = String::from("started\nloaded");
+    let mut ui = |win: &mut Window| {
+        win.TextEdit("", &mut log, vec2(150., 60.)).read_only(true).line_numbers(true);
+    };
+    frame(&mut windows, &mut ui);
+    let area = windows.windows[0].widgets[0].rect();
+    click(&mut windows, area.center(), &mut ui);
+    windows.backend.type_text("oops");
+    windows.backend.press_key(KeyCode::LeftControl);
+    windows.backend.press_key(KeyCode::A);
+    frame(&mut windows, &mut ui);
+    windows.backend.release_key(KeyCode::A);
+    windows.backend.press_key(KeyCode::X);
+    frame(&mut windows, &mut ui);
+    windows.backend.release_key(KeyCode::X);
+    windows.backend.release_key(KeyCode::LeftControl);
+    frame(&mut windows, &mut ui);
+
+    assert_eq!(windows.backend.clipboard_get().as_deref(), Some("started\nloaded"));
+    assert_eq!(log, "started\nloaded");
+
+    let drawn = windows.windows[0].draw_list.to_string();
+    assert!(drawn.contains("\"1\"") && drawn.contains("\"2\""), "{}", drawn);
+}
+
+#[test]
+fn long_lines_wrap_or_scroll() {
+    let mut windows = windows();
+    let mut text = "word ".repeat(20);
+    let mut ui = |win: &mut Window| {
+        win.TextEdit("", &mut text, vec2(150., 60.)).wrap(true);
+    };
+    frame(&mut windows, &mut ui);
+    frame(&mut windows, &mut ui);
+    let drawn = windows.windows[0].draw_list.to_string();
+    assert!(drawn.contains("\"word word word \""), "{}", drawn);
+    let edit = editor(&mut windows);
+    assert_eq!(edit.scroll.max_offset().x, 0.0);
+    assert!(edit.scroll.max_offset().y > 0.0);
+
+    // Without wrapping it scrolls sideways, following the caret.
+    let mut script = String::new();
+    let mut windows = common::windows();
+    frame(&mut windows, script_editor(&mut script));
+    type_text(&mut windows, &"x".repeat(40), script_editor(&mut script));
+    let edit = editor(&mut windows);
+    assert!(edit.scroll.max_offset().x > 0.0);
+    assert_eq!(edit.scroll.offset.x, edit.scroll.max_offset().x);
+}
+
+#[test]
+fn vertical_scroll_follows_the_caret() {
+    let mut windows = windows();
+    let mut script = String::new();
+    frame(&mut windows, script_editor(&mut script));
+    type_text(&mut windows, &"line\n".repeat(10), script_editor(&mut script));
+    let edit = editor(&mut windows);
+    assert!(edit.scroll.offset.y > 0.0);
+    assert_eq!(edit.scroll.offset.y, edit.scroll.max_offset().y);
+
+    windows.backend.press_key(KeyCode::LeftControl);
+    tap(&mut windows, KeyCode::Home, script_editor(&mut script));
+    windows.backend.release_key(KeyCode::LeftControl);
+    assert_eq!(editor(&mut windows).scroll.offset.y, 0.0);
+}
@@ -0,0 +1,161 @@
+use amberwindow::*;
+use macroquad::prelude::*;
+
+mod common;
+use common::*;
+
+/// A window with a name field (returns whether Enter was pressed in it).
+fn name_field(name: &mut String) -> impl FnMut(&mut Window) -> bool + '_ {
+    move |win| win.TextInput("Name", name, 150.).max_length(12).enter_pressed
+}
+
+fn input(windows: &mut Windows) -> &mut TextInput {
+    windows.windows[0].widgets[0].as_text_input()
+}
+
+#[test]
+fn typing_edits_the_bound_string() {
+    let mut windows = windows();
+    let mut name = String::new();
+    frame(&mut windows, name_field(&mut name));
+    assert!(windows.windows[0].draw_list.to_string().contains("\"Name\""));
+
+    type_text(&mut windows, "Hero", name_field(&mut name));
+    assert_eq!(name, "Hero");
+    assert!(input(&mut windows).focused);
+    assert!(windows.wants_keyboard_input());
+    assert!(!windows.windows[0].draw_list.to_string().contains("\"Name\""));
+
+    assert!(tap(&mut windows, KeyCode::Enter, name_field(&mut name)));
+
+    // Changes made by the program show up in the field.
+    name = String::from("Villain");
+    frame(&mut windows, name_field(&mut name));
+    frame(&mut windows, name_field(&mut name));
+    assert_eq!(input(&mut windows).text, "Villain");
+    assert!(windows.windows[0].draw_list.to_string().contains("\"Villain\""));
+
+    // Clicking elsewhere in the window stops the typing.
+    click(&mut windows, vec2(100., 180.), |win| {
+        win.TextInput("Name", &mut name, 150.);
+    });
+    assert!(!input(&mut windows).focused);
+    assert!(!windows.wants_keyboard_input());
+}
+
+#[test]
+fn caret_keys_and_selection_edit_in_place() {
+    let mut windows = windows();
+    let mut name = String::new();
+    frame(&mut windows, name_field(&mut name));
+    type_text(&mut windows, "Hero", name_field(&mut name));
+
+    tap(&mut windows, KeyCode::Home, name_field(&mut name));
+    tap_with(&mut windows, KeyCode::LeftShift, KeyCode::Right, name_field(&mut name));
+    tap_with(&mut windows, KeyCode::LeftShift, KeyCode::Right, name_field(&mut name));
+    assert_eq!(input(&mut windows).selection(), Some((0, 2)));
+
+    windows.backend.type_text("Ze");
+    frame(&mut windows, name_field(&mut name));
+    frame(&mut windows, name_field(&mut name));
+    assert_eq!(name, "Zero");
+
+    tap(&mut windows, KeyCode::Backspace, name_field(&mut name));
+    tap(&mut windows, KeyCode::Delete, name_field(&mut name));
+    assert_eq!(name, "Zo");
+    tap(&mut windows, KeyCode::End, name_field(&mut name));
+    assert_eq!(input(&mut windows).caret(), 2);
+
+    // Max length.
+    windows.backend.type_text("0123456789ABCDEF");
+    frame(&mut windows, name_field(&mut name));
+    frame(&mut windows, name_field(&mut name));
+    assert_eq!(name, "Zo0123456789");
+}
+
+#[test]
+fn keys_in_the_frame_of_typing_and_altgr_characters() {
+    let mut windows = windows();
+    let mut name = String::new();
+    frame(&mut windows, name_field(&mut name));
+    type_text(&mut windows, "Hero", name_field(&mut name));
+    tap(&mut windows, KeyCode::Home, name_field(&mut name));
+
+    // End sees the text typed in the same frame.
+    windows.backend.type_text("s").press_key(KeyCode::End);
+    frame(&mut windows, name_field(&mut name));
+    windows.backend.release_key(KeyCode::End);
+    frame(&mut windows, name_field(&mut name));
+    assert_eq!(name, "sHero");
+    assert_eq!(input(&mut windows).caret(), 5);
+
+    // AltGr comes as Ctrl+Alt on Windows.
+    windows.backend.press_key(KeyCode::LeftControl).press_key(KeyCode::LeftAlt).type_text("@");
+    frame(&mut windows, name_field(&mut name));
+    windows.backend.release_key(KeyCode::LeftAlt).release_key(KeyCode::LeftControl);
+    frame(&mut windows, name_field(&mut name));
+    assert_eq!(name, "sHero@");
+}
+
+#[test]
+fn clipboard_copy_cut_and_paste() {
+    let mut windows = windows();
+    let mut name = String::new();
+    frame(&mut windows, name_field(&mut name));
+    type_text(&mut windows, "Hero", name_field(&mut name));
+
+    tap_with(&mut windows, KeyCode::LeftControl, KeyCode::A, name_field(&mut name));
+    tap_with(&mut windows, KeyCode::LeftControl, KeyCode::C, name_field(&mut name));
+    assert_eq!(windows.backend.clipboard_get().as_deref(), Some("Hero"));
+
+    tap_with(&mut windows, KeyCode::LeftControl, KeyCode::X, name_field(&mut name));
+    assert_eq!(name, "");
+
+    tap_with(&mut windows, KeyCode::LeftControl, KeyCode::V, name_field(&mut name));
+    tap_with(&mut windows, KeyCode::LeftControl, KeyCode::V, name_field(&mut name));
+    assert_eq!(name, "HeroHero");
+}
+
+#[test]
+fn password_fields_are_masked() {
+    let mut windows = windows();
+    let mut password = String::new();
+    let mut ui = |win: &mut Window| {
+        win.TextInput("Password", &mut password, 150.).password(true);
+    };
+    frame(&mut windows, &mut ui);
+    let field = windows.windows[0].widgets[0].rect();
+    click(&mut windows, field.center(), &mut ui);
+    windows.backend.type_text("secret");
+    frame(&mut windows, &mut ui);
+    frame(&mut windows, &mut ui);
+
+    let drawn = windows.windows[0].draw_list.to_string();
+    assert!(drawn.contains("\"******\""), "{}", drawn);
+    assert!(!drawn.contains("secret"));
+    assert_eq!(input(&mut windows).text, "secret");
+}
+
+#[test]
+fn held_keys_repeat() {
+    let mut windows = windows();
+    let mut name = String::new();
+    frame(&mut windows, name_field(&mut name));
+    type_text(&mut windows, "Heroes of old", name_field(&mut name));
+    assert_eq!(name, "Heroes of ol");
+
+    windows.backend.press_key(KeyCode::Backspace);
+    frame(&mut windows, name_field(&mut name));
+    frame(&mut windows, name_field(&mut name));
+    assert_eq!(name, "Heroes of o");
+
+    // After the delay it repeats every 0.05 s (every third frame at 60 fps).
+    windows.backend.advance_time(0.4);
+    for _ in 0..4 {
+        frame(&mut windows, name_field(&mut name));
+    }
+    windows.backend.release_key(KeyCode::Backspace);
+    frame(&mut windows, name_field(&mut name));
+    frame(&mut windows, name_field(&mut name));
+    assert_eq!(name, "Heroes of");
+}
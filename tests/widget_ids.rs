@@ -0,0 +1,228 @@
+use amberwindow::*;
+use macroquad::prelude::*;
+
+mod common;
+use common::*;
+
+#[test]
+fn slider_keeps_value_when_widget_appears_above_it() {
+    let mut windows = windows();
+    let ui = |show: bool| {
+        move |win: &mut Window| {
+            if show {
+                win.Checkbox("Advanced", false);
+            }
+            win.Slider_float(0., 100., None, vec2(100., 20.)).0
+        }
+    };
+    frame(&mut windows, ui(false));
+    windows.windows[0].widgets[0].as_slider().value = 40.;
+
+    assert_eq!(frame(&mut windows, ui(true)), 40.);
+    assert_eq!(frame(&mut windows, ui(true)), 40.);
+    assert_eq!(frame(&mut windows, ui(false)), 40.);
+}
+
+#[test]
+fn same_range_sliders_are_told_apart_by_push_id() {
+    let mut windows = windows();
+    let ui = |show: bool| {
+        move |win: &mut Window| {
+            if show {
+                win.push_id("gain");
+                win.Slider_float(0., 100., None, vec2(100., 20.));
+                win.pop_id();
+            }
+            win.push_id("volume");
+            let volume = win.Slider_float(0., 100., None, vec2(100., 20.)).0;
+            win.pop_id();
+            volume
+        }
+    };
+    frame(&mut windows, ui(true));
+    windows.windows[0].widgets[0].as_slider().value = 10.;
+    windows.windows[0].widgets[1].as_slider().value = 40.;
+    frame(&mut windows, ui(true));
+
+    // Hiding the first slider doesn't hand its value to the second.
+    assert_eq!(frame(&mut windows, ui(false)), 40.);
+    assert_eq!(frame(&mut windows, ui(true)), 40.);
+}
+
+#[test]
+fn checkbox_state_follows_its_label() {
+    let mut windows = windows();
+    let ui = |labels: &'static [&'static str]| {
+        move |win: &mut Window| {
+            labels
+                .iter()
+                .map(|label| win.Checkbox(label, false).value)
+                .collect::<Vec<bool>>()
+        }
+    };
+    frame(&mut windows, ui(&["A", "B", "C"]));
+    windows.windows[0].widgets[1].as_checkbox().value = true;
+
+    assert_eq!(frame(&mut windows, ui(&["B", "C"])), [true, false]);
+    assert_eq!(frame(&mut windows, ui(&["A", "C", "B"])), [false, false, true]);
+}
+
+#[test]
+fn window_widget_returns_the_matched_widget() {
+    let mut windows = windows();
+    let widget = WindowWidget::new();
+    let ui = |labels: &'static [&'static str]| {
+        let widget = &widget;
+        move |win: &mut Window| labels.iter().map(|label| widget.Checkbox(win, label, false)).collect::<Vec<_>>()
+    };
+    frame(&mut windows, ui(&["A", "B"]));
+    windows.windows[0].widgets[1].as_checkbox().value = true;
+
+    let pushed = frame(&mut windows, ui(&["B"]));
+    let (idx, checkbox) = &pushed[0];
+    assert_eq!(checkbox.text, "B");
+    assert!(checkbox.value);
+    assert_eq!(windows.windows[0].widgets[*idx].as_checkbox().text, "B");
+}
+
+#[test]
+fn push_id_separates_widgets_with_the_same_label() {
+    let mut windows = windows();
+    let ui = |enemies: &'static [u32]| {
+        move |win: &mut Window| {
+            let mut alive = vec![];
+            for enemy in enemies {
+                win.push_id(enemy);
+                alive.push(win.Checkbox("Alive", false).value);
+                win.pop_id();
+            }
+            alive
+        }
+    };
+    frame(&mut windows, ui(&[1, 2, 3]));
+    let ids: Vec<u64> = windows.windows[0].widgets.iter().map(|i| i.id()).collect();
+    assert!(ids[0] != ids[1] && ids[1] != ids[2] && ids[0] != ids[2]);
+
+    windows.windows[0].widgets[2].as_checkbox().value = true;
+    assert_eq!(frame(&mut windows, ui(&[2, 3])), [false, true]);
+}
+
+#[test]
+fn duplicate_labels_get_distinct_ids() {
+    let mut windows = windows();
+    frame(&mut windows, |win| {
+        win.Button("Save");
+        win.Button("Save");
+    });
+
+    let widgets = &windows.windows[0].widgets;
+    assert_eq!(widgets.len(), 2);
+    assert_ne!(widgets[0].id(), widgets[1].id());
+}
+
+#[test]
+fn ids_are_stable_across_frames() {
+    let mut windows = windows();
+    let ui = |win: &mut Window| {
+        win.Text("Hello", None);
+        win.Button("Save");
+    };
+    frame(&mut windows, ui);
+    let first: Vec<u64> = windows.windows[0].widgets.iter().map(|i| i.id()).collect();
+    frame(&mut windows, ui);
+    let second: Vec<u64> = windows.windows[0].widgets.iter().map(|i| i.id()).collect();
+
+    assert_eq!(first, second);
+}
+
+#[test]
+fn widget_row_children_keep_state() {
+    let mut windows = windows();
+    let ui = |win: &mut Window| {
+        let row = win.WidgetRow().unwrap();
+        row.Button("Reset");
+        row.Checkbox("Loop", false).1.value
+    };
+    frame(&mut windows, ui);
+    windows.windows[0].widgets[0].as_widget_row().widgets[1]
+        .as_checkbox()
+        .value = true;
+
+    assert!(frame(&mut windows, ui));
+    assert!(frame(&mut windows, ui));
+}
+
+#[test]
+fn lookup_by_runtime_uuid() {
+    let mut windows = windows();
+    frame(&mut windows, |win| {
+        win.Text("Enemies", None);
+        for i in 0..3 {
+            let uuid = format!("enemy_{}", i);
+            let slider = Slider::new(None, 0., 100., Some(i as f32), vec2(100., 15.), false, Some(&uuid));
+            win.push(&mut Widget::Slider(slider));
+        }
+        win.Checkbox("Loop", false).set_uuid("loop");
+    });
+
+    let win = &mut windows.windows[0];
+    assert_eq!(win.get_slider("enemy_2").map(|i| i.value), Some(2.));
+    assert!(win.get_checkbox("loop").is_some());
+    assert!(win.get_button("enemy_2").is_none());
+    assert!(win.get_widget_from_uuid("missing").is_none());
+}
+
+#[test]
+fn uuid_set_after_push_is_kept() {
+    let mut windows = windows();
+    let ui = |win: &mut Window| {
+        win.Text("Hello", None);
+    };
+    frame(&mut windows, ui);
+    windows.windows[0].get(0).as_text().set_uuid("greeting");
+    frame(&mut windows, ui);
+
+    assert_eq!(windows.windows[0].get_text("greeting").map(|i| i.text.clone()), Some("Hello".to_owned()));
+}
+
+#[test]
+fn lookup_finds_widgets_inside_rows() {
+    let mut windows = windows();
+    frame(&mut windows, |win| {
+        win.Text("Row:", None);
+        let row = win.WidgetRow().unwrap();
+        row.Button("Play");
+        let idx = row.Checkbox("Mute", false).0;
+        row.get(idx).as_checkbox().set_uuid("mute");
+    });
+
+    let win = &mut windows.windows[0];
+    assert_eq!(win.get_checkbox("mute").map(|i| i.text.clone()), Some("Mute".to_owned()));
+    assert!(win.get_widget_row("mute").is_none());
+}
+
+#[test]
+fn widget_accessors_do_not_panic() {
+    let mut widget = Widget::Checkbox(Checkbox::new("Loop", None, None, None, Some("loop")));
+
+    assert!(widget.try_as_text().is_none());
+    assert!(widget.try_as_checkbox().is_some());
+    assert_eq!(widget.uuid(), "loop");
+}
+
+#[test]
+fn equate_compares_variants_without_panicking() {
+    let checkbox = Widget::Checkbox(Checkbox::new("Loop", None, None, None, None));
+    let mut text = Widget::Text(Text::new("Loop", None, None, None));
+    assert!(!checkbox.equate(&mut text));
+    assert!(!text.equate(&mut Widget::Text(Text::new("Loop", None, Some(RED), None))));
+    assert!(text.equate(&mut Widget::Text(Text::new("Loop", None, None, None))));
+
+    let combo = |items: &[&str]| Widget::Combo(Combo::new("Mode", items, 0, None, 100., None));
+    assert!(combo(&["Easy", "Hard"]).equate(&mut combo(&["Easy", "Hard"])));
+    assert!(!combo(&["Easy", "Hard"]).equate(&mut combo(&["Easy"])));
+
+    let input = |hint: &str| Widget::TextInput(TextInput::new(hint, "", None, 100., None));
+    assert!(input("Name").equate(&mut input("Name")));
+    assert!(!input("Name").equate(&mut input("Email")));
+}
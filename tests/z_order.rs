@@ -0,0 +1,101 @@
+use amberwindow::*;
+use macroquad::prelude::*;
+
+mod common;
+use common::*;
+
+fn ids(windows: &Windows) -> Vec<&str> {
+    windows.windows.iter().map(|win| win.id.as_str()).collect()
+}
+
+fn three_windows(windows: &mut Windows, hud_layer: WindowLayer) {
+    windows.begin("hud").unwrap().name("hud").layer(hud_layer);
+    windows.begin("a").unwrap().name("a");
+    windows.begin("b").unwrap().name("b");
+    windows.end_windows();
+    windows.backend.next_frame();
+}
+
+#[test]
+fn bring_to_front_and_send_to_back() {
+    let mut windows = windows();
+    three_windows(&mut windows, WindowLayer::Normal);
+    assert_eq!(ids(&windows), ["hud", "a", "b"]);
+
+    windows.bring_to_front("b");
+    assert_eq!(ids(&windows), ["b", "hud", "a"]);
+
+    windows.send_to_back("hud");
+    assert_eq!(ids(&windows), ["b", "a", "hud"]);
+
+    // Drawn back to front.
+    three_windows(&mut windows, WindowLayer::Normal);
+    let lists: Vec<String> = windows.draw_lists().map(|list| list.to_string()).collect();
+    assert!(lists[0].contains("text \"hud\""));
+    assert!(lists[2].contains("text \"b\""));
+}
+
+#[test]
+fn higher_layers_stay_in_front() {
+    let mut windows = windows();
+    three_windows(&mut windows, WindowLayer::AlwaysOnTop);
+    windows.bring_to_front("b");
+    assert_eq!(ids(&windows), ["hud", "b", "a"]);
+
+    // Selecting a normal window only brings it to the front of its own layer.
+    click(&mut windows, vec2(100., 100.), |_| {});
+    three_windows(&mut windows, WindowLayer::AlwaysOnTop);
+    assert_eq!(windows.windows[0].id, "hud");
+
+    three_windows(&mut windows, WindowLayer::Background);
+    assert_eq!(windows.windows.last().unwrap().id, "hud");
+}
+
+#[test]
+fn selecting_moves_window_to_front_and_keeps_state() {
+    let mut windows = windows();
+    let frame = |windows: &mut Windows| {
+        windows.begin("a").unwrap().Checkbox("A", false);
+        windows.begin("b").unwrap().position(vec2(300., 0.)).Checkbox("B", false);
+        windows.end_windows();
+        windows.backend.next_frame();
+    };
+    frame(&mut windows);
+    assert_eq!(ids(&windows), ["a", "b"]);
+
+    let checkbox = windows.windows[1].widgets[0].as_checkbox().rect.center();
+    windows.backend.move_mouse(checkbox);
+    windows.backend.press_mouse(MouseButton::Left);
+    frame(&mut windows);
+    windows.backend.release_mouse(MouseButton::Left);
+    frame(&mut windows);
+
+    assert_eq!(ids(&windows), ["b", "a"]);
+    assert!(windows.windows[0].widgets[0].as_checkbox().value);
+}
+
+#[test]
+fn selecting_behind_a_freed_window_brings_the_right_one_to_front() {
+    let mut windows = windows();
+    let frame = |windows: &mut Windows| {
+        for (id, x) in [("a", 0.), ("b", 250.), ("c", 500.)] {
+            if let Some(win) = windows.begin(id) {
+                win.position(vec2(x, 0.));
+            }
+        }
+        windows.end_windows();
+        windows.backend.next_frame();
+    };
+    frame(&mut windows);
+    windows.windows[0].queue_free = true;
+    frame(&mut windows);
+
+    windows.backend.move_mouse(vec2(600., 100.));
+    windows.backend.press_mouse(MouseButton::Left);
+    frame(&mut windows);
+    windows.backend.release_mouse(MouseButton::Left);
+    frame(&mut windows);
+
+    let live: Vec<&str> = ids(&windows).into_iter().filter(|id| *id != "a").collect();
+    assert_eq!(live, ["c", "b"]);
+}